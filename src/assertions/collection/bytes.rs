@@ -0,0 +1,469 @@
+use crate::matchers::collection::bytes::{be_valid_utf8, be_zeroed, contain_byte_sequence, equal_bytes, have_checksum_crc32, start_with_bytes};
+use crate::matchers::{Should, ShouldNot};
+
+/// BytesAssertion enables assertions about the contents of byte collections (`Vec<u8>`, `[u8; N]` and `[u8]`).
+///
+/// Failure messages show the bytes in hex, and should_equal_bytes shows a hexdump-style side-by-side diff of the differing rows.
+///
+/// # Example
+/// ```
+/// use clearcheck::assertions::collection::bytes::BytesAssertion;
+///
+/// let payload: Vec<u8> = b"HTTP/1.1 200 OK\r\n".to_vec();
+/// payload
+///     .should_start_with_bytes(b"HTTP/1.1")
+///     .should_contain_byte_sequence(b"\r\n")
+///     .should_be_valid_utf8();
+/// ```
+pub trait BytesAssertion {
+    /// - Asserts that the bytes are equal to the expected bytes.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, with a hexdump-style side-by-side diff of the differing 16-byte rows.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::bytes::BytesAssertion;
+    ///
+    /// let bytes = vec![0xca, 0xfe, 0xba, 0xbe];
+    /// bytes.should_equal_bytes(&[0xca, 0xfe, 0xba, 0xbe]);
+    /// ```
+    fn should_equal_bytes(&self, expected: &[u8]) -> &Self;
+
+    /// - Asserts that the bytes are not equal to the given bytes.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::bytes::BytesAssertion;
+    ///
+    /// let bytes = vec![0xca, 0xfe, 0xba, 0xbe];
+    /// bytes.should_not_equal_bytes(&[0xde, 0xad, 0xbe, 0xef]);
+    /// ```
+    fn should_not_equal_bytes(&self, expected: &[u8]) -> &Self;
+
+    /// - Asserts that the bytes start with the given prefix.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::bytes::BytesAssertion;
+    ///
+    /// let bytes = [0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a];
+    /// bytes.should_start_with_bytes(&[0x89, b'P', b'N', b'G']);
+    /// ```
+    fn should_start_with_bytes(&self, prefix: &[u8]) -> &Self;
+
+    /// - Asserts that the bytes do not start with the given prefix.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::bytes::BytesAssertion;
+    ///
+    /// let bytes = [0xff, 0xd8, 0xff];
+    /// bytes.should_not_start_with_bytes(&[0x89, b'P', b'N', b'G']);
+    /// ```
+    fn should_not_start_with_bytes(&self, prefix: &[u8]) -> &Self;
+
+    /// - Asserts that the bytes contain the given sequence of consecutive bytes.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::bytes::BytesAssertion;
+    ///
+    /// let bytes = b"HTTP/1.1 200 OK\r\n".to_vec();
+    /// bytes.should_contain_byte_sequence(&[0x0d, 0x0a]);
+    /// ```
+    fn should_contain_byte_sequence(&self, sequence: &[u8]) -> &Self;
+
+    /// - Asserts that the bytes do not contain the given sequence of consecutive bytes.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, naming the offset where the sequence was found.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::bytes::BytesAssertion;
+    ///
+    /// let bytes = b"HTTP/1.1 200 OK".to_vec();
+    /// bytes.should_not_contain_byte_sequence(&[0x0d, 0x0a]);
+    /// ```
+    fn should_not_contain_byte_sequence(&self, sequence: &[u8]) -> &Self;
+
+    /// - Asserts that the bytes are valid UTF-8.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, naming the index of the invalid sequence.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::bytes::BytesAssertion;
+    ///
+    /// let bytes = "héllo".as_bytes().to_vec();
+    /// bytes.should_be_valid_utf8();
+    /// ```
+    fn should_be_valid_utf8(&self) -> &Self;
+
+    /// - Asserts that the bytes are not valid UTF-8.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::bytes::BytesAssertion;
+    ///
+    /// let bytes = [b'h', 0xc3, b'l'];
+    /// bytes.should_not_be_valid_utf8();
+    /// ```
+    fn should_not_be_valid_utf8(&self) -> &Self;
+
+    /// - Asserts that the CRC-32 (IEEE, as used by zip, png and ethernet) checksum of the bytes is the given checksum.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::bytes::BytesAssertion;
+    ///
+    /// let bytes = b"123456789".to_vec();
+    /// bytes.should_have_checksum_crc32(0xcbf43926);
+    /// ```
+    fn should_have_checksum_crc32(&self, checksum: u32) -> &Self;
+
+    /// - Asserts that every byte is zero.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, naming the first non-zero byte and its offset.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::bytes::BytesAssertion;
+    ///
+    /// let buffer = vec![0u8; 64];
+    /// buffer.should_be_zeroed();
+    /// ```
+    fn should_be_zeroed(&self) -> &Self;
+
+    /// - Asserts that at least one byte is not zero.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::collection::bytes::BytesAssertion;
+    ///
+    /// let buffer = vec![0, 0, 1, 0];
+    /// buffer.should_not_be_zeroed();
+    /// ```
+    fn should_not_be_zeroed(&self) -> &Self;
+}
+
+impl BytesAssertion for Vec<u8> {
+    fn should_equal_bytes(&self, expected: &[u8]) -> &Self {
+        (self as &[u8]).should_equal_bytes(expected);
+        self
+    }
+
+    fn should_not_equal_bytes(&self, expected: &[u8]) -> &Self {
+        (self as &[u8]).should_not_equal_bytes(expected);
+        self
+    }
+
+    fn should_start_with_bytes(&self, prefix: &[u8]) -> &Self {
+        (self as &[u8]).should_start_with_bytes(prefix);
+        self
+    }
+
+    fn should_not_start_with_bytes(&self, prefix: &[u8]) -> &Self {
+        (self as &[u8]).should_not_start_with_bytes(prefix);
+        self
+    }
+
+    fn should_contain_byte_sequence(&self, sequence: &[u8]) -> &Self {
+        (self as &[u8]).should_contain_byte_sequence(sequence);
+        self
+    }
+
+    fn should_not_contain_byte_sequence(&self, sequence: &[u8]) -> &Self {
+        (self as &[u8]).should_not_contain_byte_sequence(sequence);
+        self
+    }
+
+    fn should_be_valid_utf8(&self) -> &Self {
+        (self as &[u8]).should_be_valid_utf8();
+        self
+    }
+
+    fn should_not_be_valid_utf8(&self) -> &Self {
+        (self as &[u8]).should_not_be_valid_utf8();
+        self
+    }
+
+    fn should_have_checksum_crc32(&self, checksum: u32) -> &Self {
+        (self as &[u8]).should_have_checksum_crc32(checksum);
+        self
+    }
+
+    fn should_be_zeroed(&self) -> &Self {
+        (self as &[u8]).should_be_zeroed();
+        self
+    }
+
+    fn should_not_be_zeroed(&self) -> &Self {
+        (self as &[u8]).should_not_be_zeroed();
+        self
+    }
+}
+
+impl<const N: usize> BytesAssertion for [u8; N] {
+    fn should_equal_bytes(&self, expected: &[u8]) -> &Self {
+        (self as &[u8]).should_equal_bytes(expected);
+        self
+    }
+
+    fn should_not_equal_bytes(&self, expected: &[u8]) -> &Self {
+        (self as &[u8]).should_not_equal_bytes(expected);
+        self
+    }
+
+    fn should_start_with_bytes(&self, prefix: &[u8]) -> &Self {
+        (self as &[u8]).should_start_with_bytes(prefix);
+        self
+    }
+
+    fn should_not_start_with_bytes(&self, prefix: &[u8]) -> &Self {
+        (self as &[u8]).should_not_start_with_bytes(prefix);
+        self
+    }
+
+    fn should_contain_byte_sequence(&self, sequence: &[u8]) -> &Self {
+        (self as &[u8]).should_contain_byte_sequence(sequence);
+        self
+    }
+
+    fn should_not_contain_byte_sequence(&self, sequence: &[u8]) -> &Self {
+        (self as &[u8]).should_not_contain_byte_sequence(sequence);
+        self
+    }
+
+    fn should_be_valid_utf8(&self) -> &Self {
+        (self as &[u8]).should_be_valid_utf8();
+        self
+    }
+
+    fn should_not_be_valid_utf8(&self) -> &Self {
+        (self as &[u8]).should_not_be_valid_utf8();
+        self
+    }
+
+    fn should_have_checksum_crc32(&self, checksum: u32) -> &Self {
+        (self as &[u8]).should_have_checksum_crc32(checksum);
+        self
+    }
+
+    fn should_be_zeroed(&self) -> &Self {
+        (self as &[u8]).should_be_zeroed();
+        self
+    }
+
+    fn should_not_be_zeroed(&self) -> &Self {
+        (self as &[u8]).should_not_be_zeroed();
+        self
+    }
+}
+
+impl BytesAssertion for [u8] {
+    fn should_equal_bytes(&self, expected: &[u8]) -> &Self {
+        self.should(&equal_bytes(expected));
+        self
+    }
+
+    fn should_not_equal_bytes(&self, expected: &[u8]) -> &Self {
+        self.should_not(&equal_bytes(expected));
+        self
+    }
+
+    fn should_start_with_bytes(&self, prefix: &[u8]) -> &Self {
+        self.should(&start_with_bytes(prefix));
+        self
+    }
+
+    fn should_not_start_with_bytes(&self, prefix: &[u8]) -> &Self {
+        self.should_not(&start_with_bytes(prefix));
+        self
+    }
+
+    fn should_contain_byte_sequence(&self, sequence: &[u8]) -> &Self {
+        self.should(&contain_byte_sequence(sequence));
+        self
+    }
+
+    fn should_not_contain_byte_sequence(&self, sequence: &[u8]) -> &Self {
+        self.should_not(&contain_byte_sequence(sequence));
+        self
+    }
+
+    fn should_be_valid_utf8(&self) -> &Self {
+        self.should(&be_valid_utf8());
+        self
+    }
+
+    fn should_not_be_valid_utf8(&self) -> &Self {
+        self.should_not(&be_valid_utf8());
+        self
+    }
+
+    fn should_have_checksum_crc32(&self, checksum: u32) -> &Self {
+        self.should(&have_checksum_crc32(checksum));
+        self
+    }
+
+    fn should_be_zeroed(&self) -> &Self {
+        self.should(&be_zeroed());
+        self
+    }
+
+    fn should_not_be_zeroed(&self) -> &Self {
+        self.should_not(&be_zeroed());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::collection::bytes::BytesAssertion;
+
+    #[test]
+    fn should_equal_bytes() {
+        let bytes = vec![0xca, 0xfe, 0xba, 0xbe];
+        bytes.should_equal_bytes(&[0xca, 0xfe, 0xba, 0xbe]);
+    }
+
+    #[test]
+    #[should_panic(expected = "00000000  ca fe ba be -- -- -- -- -- -- -- -- -- -- -- -- | ca fe ba ba -- -- -- -- -- -- -- -- -- -- -- --")]
+    fn should_equal_bytes_but_did_not() {
+        let bytes = vec![0xca, 0xfe, 0xba, 0xba];
+        bytes.should_equal_bytes(&[0xca, 0xfe, 0xba, 0xbe]);
+    }
+
+    #[test]
+    fn should_not_equal_bytes() {
+        let bytes = [0xca, 0xfe, 0xba, 0xbe];
+        bytes.should_not_equal_bytes(&[0xde, 0xad, 0xbe, 0xef]);
+    }
+
+    #[test]
+    #[should_panic(expected = "[ca fe ba be] should not equal [ca fe ba be]")]
+    fn should_not_equal_bytes_but_did() {
+        let bytes = [0xca, 0xfe, 0xba, 0xbe];
+        bytes.should_not_equal_bytes(&[0xca, 0xfe, 0xba, 0xbe]);
+    }
+
+    #[test]
+    fn should_start_with_bytes() {
+        let bytes: &[u8] = &[0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a];
+        bytes.should_start_with_bytes(&[0x89, b'P', b'N', b'G']);
+    }
+
+    #[test]
+    #[should_panic(expected = "should start with [89 50 4e 47] but started with [ff d8 ff]")]
+    fn should_start_with_bytes_but_did_not() {
+        let bytes = vec![0xff, 0xd8, 0xff];
+        bytes.should_start_with_bytes(&[0x89, b'P', b'N', b'G']);
+    }
+
+    #[test]
+    fn should_not_start_with_bytes() {
+        let bytes = vec![0xff, 0xd8, 0xff];
+        bytes.should_not_start_with_bytes(&[0x89, b'P', b'N', b'G']);
+    }
+
+    #[test]
+    #[should_panic(expected = "should not start with [89 50]")]
+    fn should_not_start_with_bytes_but_did() {
+        let bytes = vec![0x89, b'P', b'N', b'G'];
+        bytes.should_not_start_with_bytes(&[0x89, b'P']);
+    }
+
+    #[test]
+    fn should_contain_byte_sequence() {
+        let bytes = b"HTTP/1.1 200 OK\r\n".to_vec();
+        bytes.should_contain_byte_sequence(&[0x0d, 0x0a]);
+    }
+
+    #[test]
+    #[should_panic(expected = "should contain the byte sequence [0d 0a]")]
+    fn should_contain_byte_sequence_but_did_not() {
+        let bytes = b"HTTP/1.1 200 OK".to_vec();
+        bytes.should_contain_byte_sequence(&[0x0d, 0x0a]);
+    }
+
+    #[test]
+    fn should_not_contain_byte_sequence() {
+        let bytes = b"HTTP/1.1 200 OK".to_vec();
+        bytes.should_not_contain_byte_sequence(&[0x0d, 0x0a]);
+    }
+
+    #[test]
+    #[should_panic(expected = "should not contain the byte sequence [0d 0a] but found it at offset 0xf")]
+    fn should_not_contain_byte_sequence_but_did() {
+        let bytes = b"HTTP/1.1 200 OK\r\n".to_vec();
+        bytes.should_not_contain_byte_sequence(&[0x0d, 0x0a]);
+    }
+
+    #[test]
+    fn should_be_valid_utf8() {
+        let bytes = "héllo".as_bytes().to_vec();
+        bytes.should_be_valid_utf8();
+    }
+
+    #[test]
+    #[should_panic(expected = "should be valid utf-8 but had an invalid utf-8 sequence of 1 bytes from index 1")]
+    fn should_be_valid_utf8_but_was_not() {
+        let bytes = [b'h', 0xc3, b'l'];
+        bytes.should_be_valid_utf8();
+    }
+
+    #[test]
+    fn should_not_be_valid_utf8() {
+        let bytes = [b'h', 0xc3, b'l'];
+        bytes.should_not_be_valid_utf8();
+    }
+
+    #[test]
+    #[should_panic(expected = "should not be valid utf-8")]
+    fn should_not_be_valid_utf8_but_was() {
+        let bytes = b"hello".to_vec();
+        bytes.should_not_be_valid_utf8();
+    }
+
+    #[test]
+    fn should_have_checksum_crc32() {
+        let bytes = b"123456789".to_vec();
+        bytes.should_have_checksum_crc32(0xcbf43926);
+    }
+
+    #[test]
+    #[should_panic(expected = "should have crc32 checksum 0x12345678 but was 0xcbf43926")]
+    fn should_have_checksum_crc32_but_did_not() {
+        let bytes = b"123456789".to_vec();
+        bytes.should_have_checksum_crc32(0x12345678);
+    }
+
+    #[test]
+    fn should_be_zeroed() {
+        let buffer = [0u8; 32];
+        buffer.should_be_zeroed();
+    }
+
+    #[test]
+    #[should_panic(expected = "should be zeroed but found 0xff at offset 0x2")]
+    fn should_be_zeroed_but_was_not() {
+        let buffer = vec![0, 0, 0xff, 0];
+        buffer.should_be_zeroed();
+    }
+
+    #[test]
+    fn should_not_be_zeroed() {
+        let buffer = vec![0, 0, 1, 0];
+        buffer.should_not_be_zeroed();
+    }
+
+    #[test]
+    #[should_panic(expected = "should not be zeroed")]
+    fn should_not_be_zeroed_but_was() {
+        let buffer = vec![0u8; 4];
+        buffer.should_not_be_zeroed();
+    }
+}
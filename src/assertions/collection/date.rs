@@ -0,0 +1,290 @@
+use std::fmt::Debug;
+use std::ops::{RangeInclusive, Sub};
+
+use chrono::{Datelike, TimeDelta};
+
+use crate::matchers::collection::date::{
+    all_fall_within, be_evenly_spaced, have_no_duplicate_days, have_no_gaps_greater_than,
+};
+use crate::matchers::collection::increasing_decreasing::be_monotonically_increasing;
+use crate::matchers::Should;
+
+/// DateCollectionAssertion enables assertions about time series, collections of dates.
+///
+/// It is implemented for collections of chrono types like `NaiveDate`, `NaiveDateTime` and `DateTime<Tz>`.
+///
+/// # Example
+/// ```
+/// use chrono::{NaiveDate, TimeDelta};
+/// use clearcheck::assertions::collection::date::DateCollectionAssertion;
+///
+/// let collection = vec![
+///     NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+///     NaiveDate::from_ymd_opt(2024, 1, 8).unwrap(),
+///     NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(),
+/// ];
+/// collection
+///     .should_be_chronological()
+///     .should_be_evenly_spaced(TimeDelta::weeks(1))
+///     .should_have_no_duplicate_days();
+/// ```
+pub trait DateCollectionAssertion<T> {
+    /// - Asserts that the dates in the collection are in chronological order (allowing consecutive equal dates).
+    /// - An empty collection is considered chronological.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use clearcheck::assertions::collection::date::DateCollectionAssertion;
+    ///
+    /// let collection = vec![
+    ///     NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2024, 1, 10).unwrap(),
+    /// ];
+    /// collection.should_be_chronological();
+    /// ```
+    fn should_be_chronological(&self) -> &Self;
+
+    /// - Asserts that no two consecutive dates in the collection are further apart than the given gap.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::{NaiveDate, TimeDelta};
+    /// use clearcheck::assertions::collection::date::DateCollectionAssertion;
+    ///
+    /// let collection = vec![
+    ///     NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2024, 1, 3).unwrap(),
+    /// ];
+    /// collection.should_have_no_gaps_greater_than(TimeDelta::days(2));
+    /// ```
+    fn should_have_no_gaps_greater_than(&self, gap: TimeDelta) -> &Self;
+
+    /// - Asserts that every two consecutive dates in the collection are exactly step apart.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::{NaiveDate, TimeDelta};
+    /// use clearcheck::assertions::collection::date::DateCollectionAssertion;
+    ///
+    /// let collection = vec![
+    ///     NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2024, 1, 3).unwrap(),
+    /// ];
+    /// collection.should_be_evenly_spaced(TimeDelta::days(1));
+    /// ```
+    fn should_be_evenly_spaced(&self, step: TimeDelta) -> &Self;
+
+    /// - Asserts that all the dates in the collection fall within the given inclusive range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use clearcheck::assertions::collection::date::DateCollectionAssertion;
+    ///
+    /// let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+    ///
+    /// let collection = vec![NaiveDate::from_ymd_opt(2024, 1, 10).unwrap()];
+    /// collection.should_all_fall_within(start..=end);
+    /// ```
+    fn should_all_fall_within(&self, range: RangeInclusive<T>) -> &Self;
+
+    /// - Asserts that no two dates in the collection fall on the same calendar day.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use clearcheck::assertions::collection::date::DateCollectionAssertion;
+    ///
+    /// let collection = vec![
+    ///     Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap(),
+    ///     Utc.with_ymd_and_hms(2024, 1, 2, 10, 0, 0).unwrap(),
+    /// ];
+    /// collection.should_have_no_duplicate_days();
+    /// ```
+    fn should_have_no_duplicate_days(&self) -> &Self;
+}
+
+impl<T> DateCollectionAssertion<T> for Vec<T>
+    where
+        T: Datelike + Clone + PartialOrd + Debug + Sub<Output=TimeDelta>,
+{
+    fn should_be_chronological(&self) -> &Self {
+        (self as &[T]).should_be_chronological();
+        self
+    }
+
+    fn should_have_no_gaps_greater_than(&self, gap: TimeDelta) -> &Self {
+        (self as &[T]).should_have_no_gaps_greater_than(gap);
+        self
+    }
+
+    fn should_be_evenly_spaced(&self, step: TimeDelta) -> &Self {
+        (self as &[T]).should_be_evenly_spaced(step);
+        self
+    }
+
+    fn should_all_fall_within(&self, range: RangeInclusive<T>) -> &Self {
+        (self as &[T]).should_all_fall_within(range);
+        self
+    }
+
+    fn should_have_no_duplicate_days(&self) -> &Self {
+        (self as &[T]).should_have_no_duplicate_days();
+        self
+    }
+}
+
+impl<T, const N: usize> DateCollectionAssertion<T> for [T; N]
+    where
+        T: Datelike + Clone + PartialOrd + Debug + Sub<Output=TimeDelta>,
+{
+    fn should_be_chronological(&self) -> &Self {
+        (self as &[T]).should_be_chronological();
+        self
+    }
+
+    fn should_have_no_gaps_greater_than(&self, gap: TimeDelta) -> &Self {
+        (self as &[T]).should_have_no_gaps_greater_than(gap);
+        self
+    }
+
+    fn should_be_evenly_spaced(&self, step: TimeDelta) -> &Self {
+        (self as &[T]).should_be_evenly_spaced(step);
+        self
+    }
+
+    fn should_all_fall_within(&self, range: RangeInclusive<T>) -> &Self {
+        (self as &[T]).should_all_fall_within(range);
+        self
+    }
+
+    fn should_have_no_duplicate_days(&self) -> &Self {
+        (self as &[T]).should_have_no_duplicate_days();
+        self
+    }
+}
+
+impl<T> DateCollectionAssertion<T> for [T]
+    where
+        T: Datelike + Clone + PartialOrd + Debug + Sub<Output=TimeDelta>,
+{
+    fn should_be_chronological(&self) -> &Self {
+        self.should(&be_monotonically_increasing());
+        self
+    }
+
+    fn should_have_no_gaps_greater_than(&self, gap: TimeDelta) -> &Self {
+        self.should(&have_no_gaps_greater_than(gap));
+        self
+    }
+
+    fn should_be_evenly_spaced(&self, step: TimeDelta) -> &Self {
+        self.should(&be_evenly_spaced(step));
+        self
+    }
+
+    fn should_all_fall_within(&self, range: RangeInclusive<T>) -> &Self {
+        self.should(&all_fall_within(range));
+        self
+    }
+
+    fn should_have_no_duplicate_days(&self) -> &Self {
+        self.should(&have_no_duplicate_days());
+        self
+    }
+}
+
+#[cfg(all(test, feature = "date"))]
+mod tests {
+    use chrono::{NaiveDate, TimeDelta, TimeZone, Utc};
+
+    use crate::assertions::collection::date::DateCollectionAssertion;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn should_be_chronological() {
+        let collection = vec![date(2024, 1, 1), date(2024, 1, 1), date(2024, 3, 1)];
+        collection.should_be_chronological();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_chronological_but_was_not() {
+        let collection = vec![date(2024, 1, 1), date(2023, 12, 31)];
+        collection.should_be_chronological();
+    }
+
+    #[test]
+    fn should_have_no_gaps_greater_than() {
+        let collection = [date(2024, 1, 1), date(2024, 1, 2), date(2024, 1, 3)];
+        collection.should_have_no_gaps_greater_than(TimeDelta::days(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "the gap between 2024-01-02 and 2024-01-05 was 3 days")]
+    fn should_have_no_gaps_greater_than_but_had() {
+        let collection = [date(2024, 1, 1), date(2024, 1, 2), date(2024, 1, 5)];
+        collection.should_have_no_gaps_greater_than(TimeDelta::days(1));
+    }
+
+    #[test]
+    fn should_be_evenly_spaced() {
+        let collection = vec![
+            Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 1, 10, 15, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 1, 10, 30, 0).unwrap(),
+        ];
+        collection.should_be_evenly_spaced(TimeDelta::minutes(15));
+    }
+
+    #[test]
+    #[should_panic(expected = "was 16m")]
+    fn should_be_evenly_spaced_but_was_not() {
+        let collection = vec![
+            Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 1, 10, 15, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 1, 10, 31, 0).unwrap(),
+        ];
+        collection.should_be_evenly_spaced(TimeDelta::minutes(15));
+    }
+
+    #[test]
+    fn should_all_fall_within() {
+        let collection: &[NaiveDate] = &[date(2024, 1, 10), date(2024, 1, 20)];
+        collection.should_all_fall_within(date(2024, 1, 1)..=date(2024, 1, 31));
+    }
+
+    #[test]
+    #[should_panic(expected = "but [2024-02-01] did not")]
+    fn should_all_fall_within_but_did_not() {
+        let collection = vec![date(2024, 1, 10), date(2024, 2, 1)];
+        collection.should_all_fall_within(date(2024, 1, 1)..=date(2024, 1, 31));
+    }
+
+    #[test]
+    fn should_have_no_duplicate_days() {
+        let collection = vec![date(2024, 1, 10), date(2025, 1, 10)];
+        collection.should_have_no_duplicate_days();
+    }
+
+    #[test]
+    #[should_panic(expected = "but 2024-01-10T18:00:00Z fell on an already seen day")]
+    fn should_have_no_duplicate_days_but_had() {
+        let collection = vec![
+            Utc.with_ymd_and_hms(2024, 1, 10, 10, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 10, 18, 0, 0).unwrap(),
+        ];
+        collection.should_have_no_duplicate_days();
+    }
+}
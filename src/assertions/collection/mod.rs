@@ -1,4 +1,7 @@
 pub mod bound;
+pub mod bytes;
+#[cfg(feature = "date")]
+pub mod date;
 pub mod duplicate;
 pub mod equal;
 pub mod increasing_decreasing;
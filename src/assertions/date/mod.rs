@@ -1,9 +1,20 @@
-use chrono::{Datelike, NaiveDate};
+use std::fmt::Debug;
 
-use crate::matchers::date::{be_a_leap_year, have_same_day, have_same_month, have_same_year};
+use chrono::{Datelike, Weekday};
+
+use crate::matchers::date::{
+    be_a_leap_year, be_a_weekday, be_a_weekend, be_in_iso_week, be_in_quarter,
+    be_the_last_day_of_month, fall_on, have_same_day, have_same_month, have_same_year,
+};
 use crate::matchers::{Should, ShouldNot};
 
-/// DateAssertion enables assertions about various properties of NaiveDate.
+pub mod offset;
+pub mod relative;
+pub mod time_of_day;
+
+/// DateAssertion enables assertions about various properties of dates.
+///
+/// It is implemented for any chrono type that implements the Datelike trait, like `NaiveDate`, `NaiveDateTime` and `DateTime<Tz>`.
 ///
 /// It offers a fluent interface for chaining multiple assertions.
 ///
@@ -31,7 +42,7 @@ pub trait DateAssertion {
     /// let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
     /// date.should_have_same_year_as(&NaiveDate::from_ymd_opt(2024, 10, 20).unwrap());
     /// ```
-    fn should_have_same_year_as(&self, other: &NaiveDate) -> &Self;
+    fn should_have_same_year_as(&self, other: &Self) -> &Self;
 
     /// - Asserts that the date does not have the same year as the other date.
     /// - Returns a reference to self for fluent chaining.
@@ -44,7 +55,7 @@ pub trait DateAssertion {
     /// let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
     /// date.should_not_have_same_year_as(&NaiveDate::from_ymd_opt(2020, 10, 20).unwrap());
     /// ```
-    fn should_not_have_same_year_as(&self, other: &NaiveDate) -> &Self;
+    fn should_not_have_same_year_as(&self, other: &Self) -> &Self;
 
     /// - Asserts that the date has the same year as the given year.
     /// - Returns a reference to self for fluent chaining.
@@ -83,7 +94,7 @@ pub trait DateAssertion {
     /// let date = NaiveDate::from_ymd_opt(2024, 1, 30).unwrap();
     /// date.should_have_same_month_as(&NaiveDate::from_ymd_opt(2024, 1, 20).unwrap());
     /// ```
-    fn should_have_same_month_as(&self, other: &NaiveDate) -> &Self;
+    fn should_have_same_month_as(&self, other: &Self) -> &Self;
 
     /// - Asserts that the date does not have the same month as the other date.
     /// - Returns a reference to self for fluent chaining.
@@ -96,7 +107,7 @@ pub trait DateAssertion {
     /// let date = NaiveDate::from_ymd_opt(2024, 10, 30).unwrap();
     /// date.should_not_have_same_month_as(&NaiveDate::from_ymd_opt(2024, 1, 20).unwrap());
     /// ```
-    fn should_not_have_same_month_as(&self, other: &NaiveDate) -> &Self;
+    fn should_not_have_same_month_as(&self, other: &Self) -> &Self;
 
     /// - Asserts that the date has the same month as the given month.
     /// - Returns a reference to self for fluent chaining.
@@ -135,7 +146,7 @@ pub trait DateAssertion {
     /// let date = NaiveDate::from_ymd_opt(2024, 10, 30).unwrap();
     /// date.should_have_same_day_as(&NaiveDate::from_ymd_opt(2024, 1, 30).unwrap());
     /// ```
-    fn should_have_same_day_as(&self, other: &NaiveDate) -> &Self;
+    fn should_have_same_day_as(&self, other: &Self) -> &Self;
 
     /// - Asserts that the date does not have the same day as the other date.
     /// - Returns a reference to self for fluent chaining.
@@ -148,7 +159,7 @@ pub trait DateAssertion {
     /// let date = NaiveDate::from_ymd_opt(2024, 10, 30).unwrap();
     /// date.should_not_have_same_day_as(&NaiveDate::from_ymd_opt(2024, 1, 20).unwrap());
     /// ```
-    fn should_not_have_same_day_as(&self, other: &NaiveDate) -> &Self;
+    fn should_not_have_same_day_as(&self, other: &Self) -> &Self;
 
     /// - Asserts that the date has the same day as the given day.
     /// - Returns a reference to self for fluent chaining.
@@ -201,14 +212,144 @@ pub trait DateAssertion {
     /// date.should_not_be_a_leap_year();
     /// ```
     fn should_not_be_a_leap_year(&self) -> &Self;
+
+    /// - Asserts that the date falls on a weekday (Monday to Friday).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::{NaiveDate};
+    /// use clearcheck::assertions::date::DateAssertion;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+    /// date.should_be_a_weekday();
+    /// ```
+    fn should_be_a_weekday(&self) -> &Self;
+
+    /// - Asserts that the date falls on a weekend (Saturday or Sunday).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::{NaiveDate};
+    /// use clearcheck::assertions::date::DateAssertion;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 13).unwrap();
+    /// date.should_be_a_weekend();
+    /// ```
+    fn should_be_a_weekend(&self) -> &Self;
+
+    /// - Asserts that the date falls on the given weekday.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    /// use clearcheck::assertions::date::DateAssertion;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+    /// date.should_fall_on(Weekday::Wed);
+    /// ```
+    fn should_fall_on(&self, weekday: Weekday) -> &Self;
+
+    /// - Asserts that the date does not fall on the given weekday.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    /// use clearcheck::assertions::date::DateAssertion;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+    /// date.should_not_fall_on(Weekday::Mon);
+    /// ```
+    fn should_not_fall_on(&self, weekday: Weekday) -> &Self;
+
+    /// - Asserts that the date is in the given ISO week.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::{NaiveDate};
+    /// use clearcheck::assertions::date::DateAssertion;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+    /// date.should_be_in_iso_week(2);
+    /// ```
+    fn should_be_in_iso_week(&self, week: u32) -> &Self;
+
+    /// - Asserts that the date is not in the given ISO week.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::{NaiveDate};
+    /// use clearcheck::assertions::date::DateAssertion;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+    /// date.should_not_be_in_iso_week(1);
+    /// ```
+    fn should_not_be_in_iso_week(&self, week: u32) -> &Self;
+
+    /// - Asserts that the date is in the given quarter (1 to 4) of its year.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::{NaiveDate};
+    /// use clearcheck::assertions::date::DateAssertion;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 5, 10).unwrap();
+    /// date.should_be_in_quarter(2);
+    /// ```
+    fn should_be_in_quarter(&self, quarter: u32) -> &Self;
+
+    /// - Asserts that the date is not in the given quarter (1 to 4) of its year.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::{NaiveDate};
+    /// use clearcheck::assertions::date::DateAssertion;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 5, 10).unwrap();
+    /// date.should_not_be_in_quarter(1);
+    /// ```
+    fn should_not_be_in_quarter(&self, quarter: u32) -> &Self;
+
+    /// - Asserts that the date is the last day of its month.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::{NaiveDate};
+    /// use clearcheck::assertions::date::DateAssertion;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+    /// date.should_be_the_last_day_of_month();
+    /// ```
+    fn should_be_the_last_day_of_month(&self) -> &Self;
+
+    /// - Asserts that the date is not the last day of its month.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::{NaiveDate};
+    /// use clearcheck::assertions::date::DateAssertion;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2023, 2, 27).unwrap();
+    /// date.should_not_be_the_last_day_of_month();
+    /// ```
+    fn should_not_be_the_last_day_of_month(&self) -> &Self;
 }
 
-impl DateAssertion for NaiveDate {
-    fn should_have_same_year_as(&self, other: &NaiveDate) -> &Self {
+impl<T: Datelike + Debug> DateAssertion for T {
+    fn should_have_same_year_as(&self, other: &Self) -> &Self {
         self.should_have_year(other.year())
     }
 
-    fn should_not_have_same_year_as(&self, other: &NaiveDate) -> &Self {
+    fn should_not_have_same_year_as(&self, other: &Self) -> &Self {
         self.should_not_have_year(other.year())
     }
 
@@ -222,11 +363,11 @@ impl DateAssertion for NaiveDate {
         self
     }
 
-    fn should_have_same_month_as(&self, other: &NaiveDate) -> &Self {
+    fn should_have_same_month_as(&self, other: &Self) -> &Self {
         self.should_have_month(other.month())
     }
 
-    fn should_not_have_same_month_as(&self, other: &NaiveDate) -> &Self {
+    fn should_not_have_same_month_as(&self, other: &Self) -> &Self {
         self.should_not_have_month(other.month())
     }
 
@@ -240,11 +381,11 @@ impl DateAssertion for NaiveDate {
         self
     }
 
-    fn should_have_same_day_as(&self, other: &NaiveDate) -> &Self {
+    fn should_have_same_day_as(&self, other: &Self) -> &Self {
         self.should_have_day(other.day())
     }
 
-    fn should_not_have_same_day_as(&self, other: &NaiveDate) -> &Self {
+    fn should_not_have_same_day_as(&self, other: &Self) -> &Self {
         self.should_not_have_day(other.day())
     }
 
@@ -267,12 +408,62 @@ impl DateAssertion for NaiveDate {
         self.should_not(&be_a_leap_year());
         self
     }
+
+    fn should_be_a_weekday(&self) -> &Self {
+        self.should(&be_a_weekday());
+        self
+    }
+
+    fn should_be_a_weekend(&self) -> &Self {
+        self.should(&be_a_weekend());
+        self
+    }
+
+    fn should_fall_on(&self, weekday: Weekday) -> &Self {
+        self.should(&fall_on(weekday));
+        self
+    }
+
+    fn should_not_fall_on(&self, weekday: Weekday) -> &Self {
+        self.should_not(&fall_on(weekday));
+        self
+    }
+
+    fn should_be_in_iso_week(&self, week: u32) -> &Self {
+        self.should(&be_in_iso_week(week));
+        self
+    }
+
+    fn should_not_be_in_iso_week(&self, week: u32) -> &Self {
+        self.should_not(&be_in_iso_week(week));
+        self
+    }
+
+    fn should_be_in_quarter(&self, quarter: u32) -> &Self {
+        self.should(&be_in_quarter(quarter));
+        self
+    }
+
+    fn should_not_be_in_quarter(&self, quarter: u32) -> &Self {
+        self.should_not(&be_in_quarter(quarter));
+        self
+    }
+
+    fn should_be_the_last_day_of_month(&self) -> &Self {
+        self.should(&be_the_last_day_of_month());
+        self
+    }
+
+    fn should_not_be_the_last_day_of_month(&self) -> &Self {
+        self.should_not(&be_the_last_day_of_month());
+        self
+    }
 }
 
 #[cfg(all(test, feature = "date"))]
 mod tests {
     use crate::assertions::date::DateAssertion;
-    use chrono::NaiveDate;
+    use chrono::{FixedOffset, NaiveDate, TimeZone, Utc, Weekday};
 
     #[test]
     fn should_have_same_year_as_other() {
@@ -456,4 +647,160 @@ mod tests {
         let date = NaiveDate::from_ymd_opt(2020, 1, 10).unwrap();
         date.should_not_be_a_leap_year();
     }
+
+    #[test]
+    fn should_have_same_year_as_other_date_time() {
+        let date_time = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap().and_hms_opt(10, 20, 30).unwrap();
+        date_time.should_have_same_year_as(&NaiveDate::from_ymd_opt(2024, 5, 20).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn should_have_month_for_utc_date_time() {
+        let date_time = Utc.with_ymd_and_hms(2024, 1, 10, 10, 20, 30).unwrap();
+        date_time.should_have_month(1).should_have_day(10).should_be_a_leap_year();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_month_for_utc_date_time_but_was_not() {
+        let date_time = Utc.with_ymd_and_hms(2024, 1, 10, 10, 20, 30).unwrap();
+        date_time.should_have_month(2);
+    }
+
+    #[test]
+    fn should_have_day_for_fixed_offset_date_time() {
+        let offset = FixedOffset::east_opt(5 * 3600 + 1800).unwrap();
+        let date_time = offset.with_ymd_and_hms(2023, 12, 31, 23, 0, 0).unwrap();
+        date_time.should_have_year(2023).should_have_day(31).should_not_be_a_leap_year();
+    }
+
+    #[test]
+    fn should_be_a_weekday() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 12).unwrap();
+        date.should_be_a_weekday();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_a_weekday_but_was_not() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 14).unwrap();
+        date.should_be_a_weekday();
+    }
+
+    #[test]
+    fn should_be_a_weekend() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 14).unwrap();
+        date.should_be_a_weekend();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_a_weekend_but_was_not() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 12).unwrap();
+        date.should_be_a_weekend();
+    }
+
+    #[test]
+    fn should_fall_on() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+        date.should_fall_on(Weekday::Wed);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_fall_on_but_did_not() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+        date.should_fall_on(Weekday::Thu);
+    }
+
+    #[test]
+    fn should_not_fall_on() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+        date.should_not_fall_on(Weekday::Thu);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_fall_on_but_did() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+        date.should_not_fall_on(Weekday::Wed);
+    }
+
+    #[test]
+    fn should_be_in_iso_week() {
+        let date = NaiveDate::from_ymd_opt(2021, 1, 3).unwrap();
+        date.should_be_in_iso_week(53);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_in_iso_week_but_was_not() {
+        let date = NaiveDate::from_ymd_opt(2021, 1, 4).unwrap();
+        date.should_be_in_iso_week(53);
+    }
+
+    #[test]
+    fn should_not_be_in_iso_week() {
+        let date = NaiveDate::from_ymd_opt(2021, 1, 4).unwrap();
+        date.should_not_be_in_iso_week(53);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_in_iso_week_but_was() {
+        let date = NaiveDate::from_ymd_opt(2021, 1, 4).unwrap();
+        date.should_not_be_in_iso_week(1);
+    }
+
+    #[test]
+    fn should_be_in_quarter() {
+        let date = NaiveDate::from_ymd_opt(2024, 10, 1).unwrap();
+        date.should_be_in_quarter(4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_in_quarter_but_was_not() {
+        let date = NaiveDate::from_ymd_opt(2024, 9, 30).unwrap();
+        date.should_be_in_quarter(4);
+    }
+
+    #[test]
+    fn should_not_be_in_quarter() {
+        let date = NaiveDate::from_ymd_opt(2024, 9, 30).unwrap();
+        date.should_not_be_in_quarter(4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_in_quarter_but_was() {
+        let date = NaiveDate::from_ymd_opt(2024, 10, 1).unwrap();
+        date.should_not_be_in_quarter(4);
+    }
+
+    #[test]
+    fn should_be_the_last_day_of_month() {
+        let date = NaiveDate::from_ymd_opt(2023, 2, 28).unwrap();
+        date.should_be_the_last_day_of_month();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_the_last_day_of_month_but_was_not() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 28).unwrap();
+        date.should_be_the_last_day_of_month();
+    }
+
+    #[test]
+    fn should_not_be_the_last_day_of_month() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 28).unwrap();
+        date.should_not_be_the_last_day_of_month();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_the_last_day_of_month_but_was() {
+        let date = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+        date.should_not_be_the_last_day_of_month();
+    }
 }
@@ -0,0 +1,160 @@
+use std::fmt::Debug;
+
+use chrono::{DateTime, FixedOffset, TimeZone};
+
+use crate::matchers::{Should, ShouldNot};
+use crate::matchers::date::offset::{be_utc, have_offset};
+
+/// OffsetAssertion enables assertions about the timezone offset of `DateTime<Tz>`.
+///
+/// # Example
+/// ```
+/// use chrono::{FixedOffset, TimeZone};
+/// use clearcheck::assertions::date::offset::OffsetAssertion;
+///
+/// let offset = FixedOffset::east_opt(3600).unwrap();
+/// let date_time = offset.with_ymd_and_hms(2024, 1, 10, 10, 20, 30).unwrap();
+///
+/// date_time
+///     .should_have_offset(offset)
+///     .should_not_be_utc();
+/// ```
+pub trait OffsetAssertion {
+    /// - Asserts that the date time has the given offset.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    /// use clearcheck::assertions::date::offset::OffsetAssertion;
+    ///
+    /// let offset = FixedOffset::east_opt(3600).unwrap();
+    /// let date_time = offset.with_ymd_and_hms(2024, 1, 10, 10, 20, 30).unwrap();
+    /// date_time.should_have_offset(offset);
+    /// ```
+    fn should_have_offset(&self, offset: FixedOffset) -> &Self;
+
+    /// - Asserts that the date time does not have the given offset.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    /// use clearcheck::assertions::date::offset::OffsetAssertion;
+    ///
+    /// let date_time = Utc.with_ymd_and_hms(2024, 1, 10, 10, 20, 30).unwrap();
+    /// date_time.should_not_have_offset(FixedOffset::east_opt(3600).unwrap());
+    /// ```
+    fn should_not_have_offset(&self, offset: FixedOffset) -> &Self;
+
+    /// - Asserts that the date time is in UTC (has a zero offset).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use clearcheck::assertions::date::offset::OffsetAssertion;
+    ///
+    /// let date_time = Utc.with_ymd_and_hms(2024, 1, 10, 10, 20, 30).unwrap();
+    /// date_time.should_be_utc();
+    /// ```
+    fn should_be_utc(&self) -> &Self;
+
+    /// - Asserts that the date time is not in UTC (has a non-zero offset).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    /// use clearcheck::assertions::date::offset::OffsetAssertion;
+    ///
+    /// let date_time = FixedOffset::west_opt(3600).unwrap().with_ymd_and_hms(2024, 1, 10, 10, 20, 30).unwrap();
+    /// date_time.should_not_be_utc();
+    /// ```
+    fn should_not_be_utc(&self) -> &Self;
+}
+
+impl<Tz: TimeZone> OffsetAssertion for DateTime<Tz>
+    where
+        Tz::Offset: Debug,
+{
+    fn should_have_offset(&self, offset: FixedOffset) -> &Self {
+        self.should(&have_offset(offset));
+        self
+    }
+
+    fn should_not_have_offset(&self, offset: FixedOffset) -> &Self {
+        self.should_not(&have_offset(offset));
+        self
+    }
+
+    fn should_be_utc(&self) -> &Self {
+        self.should(&be_utc());
+        self
+    }
+
+    fn should_not_be_utc(&self) -> &Self {
+        self.should_not(&be_utc());
+        self
+    }
+}
+
+#[cfg(all(test, feature = "date"))]
+mod tests {
+    use chrono::{FixedOffset, TimeZone, Utc};
+
+    use crate::assertions::date::offset::OffsetAssertion;
+
+    #[test]
+    fn should_have_offset() {
+        let offset = FixedOffset::east_opt(3600).unwrap();
+        let date_time = offset.with_ymd_and_hms(2024, 1, 10, 10, 20, 30).unwrap();
+        date_time.should_have_offset(offset);
+    }
+
+    #[test]
+    #[should_panic(expected = "should have the offset +01:00 but was -01:00")]
+    fn should_have_offset_but_did_not() {
+        let date_time = FixedOffset::west_opt(3600).unwrap().with_ymd_and_hms(2024, 1, 10, 10, 20, 30).unwrap();
+        date_time.should_have_offset(FixedOffset::east_opt(3600).unwrap());
+    }
+
+    #[test]
+    fn should_not_have_offset() {
+        let date_time = Utc.with_ymd_and_hms(2024, 1, 10, 10, 20, 30).unwrap();
+        date_time.should_not_have_offset(FixedOffset::east_opt(3600).unwrap());
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_have_offset_but_did() {
+        let date_time = Utc.with_ymd_and_hms(2024, 1, 10, 10, 20, 30).unwrap();
+        date_time.should_not_have_offset(FixedOffset::east_opt(0).unwrap());
+    }
+
+    #[test]
+    fn should_be_utc() {
+        let date_time = Utc.with_ymd_and_hms(2024, 1, 10, 10, 20, 30).unwrap();
+        date_time.should_be_utc();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_utc_but_was_not() {
+        let date_time = FixedOffset::east_opt(1800).unwrap().with_ymd_and_hms(2024, 1, 10, 10, 20, 30).unwrap();
+        date_time.should_be_utc();
+    }
+
+    #[test]
+    fn should_not_be_utc() {
+        let date_time = FixedOffset::east_opt(1800).unwrap().with_ymd_and_hms(2024, 1, 10, 10, 20, 30).unwrap();
+        date_time.should_not_be_utc();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_utc_but_was() {
+        let date_time = Utc.with_ymd_and_hms(2024, 1, 10, 10, 20, 30).unwrap();
+        date_time.should_not_be_utc();
+    }
+}
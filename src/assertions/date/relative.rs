@@ -0,0 +1,222 @@
+use std::fmt::Debug;
+use std::ops::Sub;
+
+use chrono::TimeDelta;
+
+use crate::matchers::{Should, ShouldNot};
+use crate::matchers::date::relative::{be_after, be_before, be_between, be_within_days_of};
+
+/// RelativeDateAssertion enables assertions about a date relative to other dates.
+///
+/// It is implemented for chrono types that can be subtracted into a `TimeDelta`, like `NaiveDate`, `NaiveDateTime` and `DateTime<Tz>`.
+/// The failure messages carry the actual distance between the dates.
+///
+/// # Example
+/// ```
+/// use chrono::NaiveDate;
+/// use clearcheck::assertions::date::relative::RelativeDateAssertion;
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+/// date
+///     .should_be_after(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())
+///     .should_be_within_days_of(NaiveDate::from_ymd_opt(2024, 1, 12).unwrap(), 2);
+/// ```
+pub trait RelativeDateAssertion {
+    /// - Asserts that the date is before the other.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use clearcheck::assertions::date::relative::RelativeDateAssertion;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+    /// date.should_be_before(NaiveDate::from_ymd_opt(2024, 1, 20).unwrap());
+    /// ```
+    fn should_be_before(&self, other: Self) -> &Self;
+
+    /// - Asserts that the date is after the other.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use clearcheck::assertions::date::relative::RelativeDateAssertion;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+    /// date.should_be_after(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+    /// ```
+    fn should_be_after(&self, other: Self) -> &Self;
+
+    /// - Asserts that the date is within the given number of days (on either side) of the other.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use clearcheck::assertions::date::relative::RelativeDateAssertion;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+    /// date.should_be_within_days_of(NaiveDate::from_ymd_opt(2024, 1, 7).unwrap(), 3);
+    /// ```
+    fn should_be_within_days_of(&self, other: Self, days: i64) -> &Self;
+
+    /// - Asserts that the date is not within the given number of days (on either side) of the other.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use clearcheck::assertions::date::relative::RelativeDateAssertion;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+    /// date.should_not_be_within_days_of(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(), 3);
+    /// ```
+    fn should_not_be_within_days_of(&self, other: Self, days: i64) -> &Self;
+
+    /// - Asserts that the date is between start and end (both inclusive).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use clearcheck::assertions::date::relative::RelativeDateAssertion;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+    /// date.should_be_between(
+    ///     NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2024, 1, 31).unwrap()
+    /// );
+    /// ```
+    fn should_be_between(&self, start: Self, end: Self) -> &Self;
+
+    /// - Asserts that the date is not between start and end (both inclusive).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use clearcheck::assertions::date::relative::RelativeDateAssertion;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 2, 10).unwrap();
+    /// date.should_not_be_between(
+    ///     NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2024, 1, 31).unwrap()
+    /// );
+    /// ```
+    fn should_not_be_between(&self, start: Self, end: Self) -> &Self;
+}
+
+impl<T> RelativeDateAssertion for T
+    where
+        T: Clone + PartialOrd + Debug + Sub<Output=TimeDelta>,
+{
+    fn should_be_before(&self, other: Self) -> &Self {
+        self.should(&be_before(other));
+        self
+    }
+
+    fn should_be_after(&self, other: Self) -> &Self {
+        self.should(&be_after(other));
+        self
+    }
+
+    fn should_be_within_days_of(&self, other: Self, days: i64) -> &Self {
+        self.should(&be_within_days_of(other, days));
+        self
+    }
+
+    fn should_not_be_within_days_of(&self, other: Self, days: i64) -> &Self {
+        self.should_not(&be_within_days_of(other, days));
+        self
+    }
+
+    fn should_be_between(&self, start: Self, end: Self) -> &Self {
+        self.should(&be_between(start, end));
+        self
+    }
+
+    fn should_not_be_between(&self, start: Self, end: Self) -> &Self {
+        self.should_not(&be_between(start, end));
+        self
+    }
+}
+
+#[cfg(all(test, feature = "date"))]
+mod tests {
+    use chrono::{NaiveDate, TimeZone, Utc};
+
+    use crate::assertions::date::relative::RelativeDateAssertion;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn should_be_before() {
+        date(2024, 1, 10).should_be_before(date(2024, 1, 11));
+    }
+
+    #[test]
+    #[should_panic(expected = "2024-01-10 should be before 2024-01-09 but was 1 day after")]
+    fn should_be_before_but_was_not() {
+        date(2024, 1, 10).should_be_before(date(2024, 1, 9));
+    }
+
+    #[test]
+    fn should_be_after() {
+        let date_time = Utc.with_ymd_and_hms(2024, 1, 10, 10, 0, 0).unwrap();
+        date_time.should_be_after(Utc.with_ymd_and_hms(2024, 1, 10, 9, 0, 0).unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "but was 1h before")]
+    fn should_be_after_but_was_not() {
+        let date_time = Utc.with_ymd_and_hms(2024, 1, 10, 9, 0, 0).unwrap();
+        date_time.should_be_after(Utc.with_ymd_and_hms(2024, 1, 10, 10, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn should_be_within_days_of() {
+        date(2024, 1, 10).should_be_within_days_of(date(2024, 1, 13), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "should be within 3 days of 2024-01-14 but was 4 days before")]
+    fn should_be_within_days_of_but_was_not() {
+        date(2024, 1, 10).should_be_within_days_of(date(2024, 1, 14), 3);
+    }
+
+    #[test]
+    fn should_not_be_within_days_of() {
+        date(2024, 1, 10).should_not_be_within_days_of(date(2024, 1, 14), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_within_days_of_but_was() {
+        date(2024, 1, 10).should_not_be_within_days_of(date(2024, 1, 10), 3);
+    }
+
+    #[test]
+    fn should_be_between() {
+        date(2024, 1, 10).should_be_between(date(2024, 1, 10), date(2024, 1, 20));
+    }
+
+    #[test]
+    #[should_panic(expected = "but was 10 days after 2024-01-20")]
+    fn should_be_between_but_was_not() {
+        date(2024, 1, 30).should_be_between(date(2024, 1, 10), date(2024, 1, 20));
+    }
+
+    #[test]
+    fn should_not_be_between() {
+        date(2024, 1, 30).should_not_be_between(date(2024, 1, 10), date(2024, 1, 20));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_between_but_was() {
+        date(2024, 1, 15).should_not_be_between(date(2024, 1, 10), date(2024, 1, 20));
+    }
+}
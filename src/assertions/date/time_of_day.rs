@@ -0,0 +1,218 @@
+use std::fmt::Debug;
+
+use chrono::Timelike;
+
+use crate::matchers::{Should, ShouldNot};
+use crate::matchers::date::time_of_day::{be_midnight, have_hour, have_minute};
+
+/// TimeOfDayAssertion enables assertions about the time of day.
+///
+/// It is implemented for any chrono type that implements the Timelike trait, like `NaiveTime`, `NaiveDateTime` and `DateTime<Tz>`.
+///
+/// # Example
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use clearcheck::assertions::date::time_of_day::TimeOfDayAssertion;
+///
+/// let date_time = Utc.with_ymd_and_hms(2024, 1, 10, 10, 20, 30).unwrap();
+/// date_time
+///     .should_have_hour(10)
+///     .should_have_minute(20)
+///     .should_not_be_midnight();
+/// ```
+pub trait TimeOfDayAssertion {
+    /// - Asserts that the time has the given hour.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::NaiveTime;
+    /// use clearcheck::assertions::date::time_of_day::TimeOfDayAssertion;
+    ///
+    /// let time = NaiveTime::from_hms_opt(10, 20, 30).unwrap();
+    /// time.should_have_hour(10);
+    /// ```
+    fn should_have_hour(&self, hour: u32) -> &Self;
+
+    /// - Asserts that the time does not have the given hour.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::NaiveTime;
+    /// use clearcheck::assertions::date::time_of_day::TimeOfDayAssertion;
+    ///
+    /// let time = NaiveTime::from_hms_opt(10, 20, 30).unwrap();
+    /// time.should_not_have_hour(11);
+    /// ```
+    fn should_not_have_hour(&self, hour: u32) -> &Self;
+
+    /// - Asserts that the time has the given minute.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::NaiveTime;
+    /// use clearcheck::assertions::date::time_of_day::TimeOfDayAssertion;
+    ///
+    /// let time = NaiveTime::from_hms_opt(10, 20, 30).unwrap();
+    /// time.should_have_minute(20);
+    /// ```
+    fn should_have_minute(&self, minute: u32) -> &Self;
+
+    /// - Asserts that the time does not have the given minute.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::NaiveTime;
+    /// use clearcheck::assertions::date::time_of_day::TimeOfDayAssertion;
+    ///
+    /// let time = NaiveTime::from_hms_opt(10, 20, 30).unwrap();
+    /// time.should_not_have_minute(30);
+    /// ```
+    fn should_not_have_minute(&self, minute: u32) -> &Self;
+
+    /// - Asserts that the time is exactly midnight (00:00:00).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use clearcheck::assertions::date::time_of_day::TimeOfDayAssertion;
+    ///
+    /// let date_time = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// date_time.should_be_midnight();
+    /// ```
+    fn should_be_midnight(&self) -> &Self;
+
+    /// - Asserts that the time is not midnight (00:00:00).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use chrono::NaiveTime;
+    /// use clearcheck::assertions::date::time_of_day::TimeOfDayAssertion;
+    ///
+    /// let time = NaiveTime::from_hms_opt(0, 0, 1).unwrap();
+    /// time.should_not_be_midnight();
+    /// ```
+    fn should_not_be_midnight(&self) -> &Self;
+}
+
+impl<T: Timelike + Debug> TimeOfDayAssertion for T {
+    fn should_have_hour(&self, hour: u32) -> &Self {
+        self.should(&have_hour(hour));
+        self
+    }
+
+    fn should_not_have_hour(&self, hour: u32) -> &Self {
+        self.should_not(&have_hour(hour));
+        self
+    }
+
+    fn should_have_minute(&self, minute: u32) -> &Self {
+        self.should(&have_minute(minute));
+        self
+    }
+
+    fn should_not_have_minute(&self, minute: u32) -> &Self {
+        self.should_not(&have_minute(minute));
+        self
+    }
+
+    fn should_be_midnight(&self) -> &Self {
+        self.should(&be_midnight());
+        self
+    }
+
+    fn should_not_be_midnight(&self) -> &Self {
+        self.should_not(&be_midnight());
+        self
+    }
+}
+
+#[cfg(all(test, feature = "date"))]
+mod tests {
+    use chrono::{FixedOffset, NaiveDate, NaiveTime, TimeZone, Utc};
+
+    use crate::assertions::date::time_of_day::TimeOfDayAssertion;
+
+    #[test]
+    fn should_have_hour() {
+        let time = NaiveTime::from_hms_opt(10, 20, 30).unwrap();
+        time.should_have_hour(10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_hour_but_did_not() {
+        let time = NaiveTime::from_hms_opt(10, 20, 30).unwrap();
+        time.should_have_hour(12);
+    }
+
+    #[test]
+    fn should_not_have_hour() {
+        let date_time = Utc.with_ymd_and_hms(2024, 1, 10, 10, 20, 30).unwrap();
+        date_time.should_not_have_hour(12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_have_hour_but_did() {
+        let date_time = Utc.with_ymd_and_hms(2024, 1, 10, 10, 20, 30).unwrap();
+        date_time.should_not_have_hour(10);
+    }
+
+    #[test]
+    fn should_have_minute() {
+        let date_time = FixedOffset::east_opt(3600).unwrap().with_ymd_and_hms(2024, 1, 10, 10, 20, 30).unwrap();
+        date_time.should_have_minute(20);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_minute_but_did_not() {
+        let time = NaiveTime::from_hms_opt(10, 20, 30).unwrap();
+        time.should_have_minute(30);
+    }
+
+    #[test]
+    fn should_not_have_minute() {
+        let time = NaiveTime::from_hms_opt(10, 20, 30).unwrap();
+        time.should_not_have_minute(30);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_have_minute_but_did() {
+        let time = NaiveTime::from_hms_opt(10, 20, 30).unwrap();
+        time.should_not_have_minute(20);
+    }
+
+    #[test]
+    fn should_be_midnight() {
+        let date_time = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        date_time.should_be_midnight();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_midnight_but_was_not() {
+        let date_time = Utc.with_ymd_and_hms(2024, 1, 10, 0, 0, 1).unwrap();
+        date_time.should_be_midnight();
+    }
+
+    #[test]
+    fn should_not_be_midnight() {
+        let time = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+        time.should_not_be_midnight();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_midnight_but_was() {
+        let time = NaiveTime::from_hms_opt(0, 0, 0).unwrap();
+        time.should_not_be_midnight();
+    }
+}
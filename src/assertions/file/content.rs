@@ -0,0 +1,439 @@
+use std::fmt::Debug;
+use std::ops::{Range, RangeInclusive};
+use std::path::Path;
+
+use crate::matchers::{Matcher, Should, ShouldNot};
+use crate::matchers::file::content::{
+    contain_line, have_bytes, have_content, have_content_matching, have_line_count,
+    have_same_content_as, have_size_in_bytes, have_size_in_exclusive_range,
+    have_size_in_inclusive_range,
+};
+
+/// FileContentAssertion enables assertions about the content and the size of a file.
+///
+/// It offers a fluent interface for chaining multiple assertions.
+///
+/// # Example
+/// ```
+/// use std::fs;
+/// use tempdir::TempDir;
+/// use clearcheck::assertions::file::content::FileContentAssertion;
+/// use clearcheck::matchers::string::boundary::begin_with;
+///
+/// let temporary_directory = TempDir::new(".").unwrap();
+/// let file_path = temporary_directory.path().join("clearcheck.txt");
+/// fs::write(&file_path, "[package]\nname = \"clearcheck\"\n").unwrap();
+///
+/// file_path
+///     .should_have_line_count(2)
+///     .should_contain_line("[package]")
+///     .should_have_content_matching(begin_with("[package]"));
+/// ```
+pub trait FileContentAssertion {
+    /// - Asserts that the (text) content of the file is the expected content.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_have_content(&self, expected: &str) -> &Self;
+
+    /// - Asserts that the (text) content of the file is not the expected content.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_have_content(&self, expected: &str) -> &Self;
+
+    /// - Asserts that the (text) content of the file matches the given string matcher.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_have_content_matching<M: Matcher<str>>(&self, matcher: M) -> &Self;
+
+    /// - Asserts that the (text) content of the file does not match the given string matcher.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_have_content_matching<M: Matcher<str>>(&self, matcher: M) -> &Self;
+
+    /// - Asserts that the content of the file is the expected bytes.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_have_bytes(&self, expected: &[u8]) -> &Self;
+
+    /// - Asserts that the file has the given number of lines.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_have_line_count(&self, line_count: usize) -> &Self;
+
+    /// - Asserts that the file contains the given line (without the line terminator).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_contain_line(&self, line: &str) -> &Self;
+
+    /// - Asserts that the file does not contain the given line (without the line terminator).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_contain_line(&self, line: &str) -> &Self;
+
+    /// - Asserts that the file has the same (text) content as the other file.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with a unified diff if the assertion fails.
+    fn should_have_same_content_as<P: AsRef<Path>>(&self, other: P) -> &Self;
+
+    /// - Asserts that the file does not have the same (text) content as the other file.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_have_same_content_as<P: AsRef<Path>>(&self, other: P) -> &Self;
+
+    /// - Asserts that the file has the given size in bytes.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_have_size_in_bytes(&self, size: u64) -> &Self;
+
+    /// - Asserts that the size of the file (in bytes) falls within the given inclusive range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_have_size_in_inclusive_range(&self, range: RangeInclusive<u64>) -> &Self;
+
+    /// - Asserts that the size of the file (in bytes) does not fall within the given inclusive range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_have_size_in_inclusive_range(&self, range: RangeInclusive<u64>) -> &Self;
+
+    /// - Asserts that the size of the file (in bytes) falls within the given exclusive range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_have_size_in_exclusive_range(&self, range: Range<u64>) -> &Self;
+
+    /// - Asserts that the size of the file (in bytes) does not fall within the given exclusive range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_have_size_in_exclusive_range(&self, range: Range<u64>) -> &Self;
+}
+
+impl<T: AsRef<Path> + Debug + ?Sized> FileContentAssertion for T {
+    fn should_have_content(&self, expected: &str) -> &Self {
+        self.should(&have_content(expected));
+        self
+    }
+
+    fn should_not_have_content(&self, expected: &str) -> &Self {
+        self.should_not(&have_content(expected));
+        self
+    }
+
+    fn should_have_content_matching<M: Matcher<str>>(&self, matcher: M) -> &Self {
+        self.should(&have_content_matching(matcher));
+        self
+    }
+
+    fn should_not_have_content_matching<M: Matcher<str>>(&self, matcher: M) -> &Self {
+        self.should_not(&have_content_matching(matcher));
+        self
+    }
+
+    fn should_have_bytes(&self, expected: &[u8]) -> &Self {
+        self.should(&have_bytes(expected));
+        self
+    }
+
+    fn should_have_line_count(&self, line_count: usize) -> &Self {
+        self.should(&have_line_count(line_count));
+        self
+    }
+
+    fn should_contain_line(&self, line: &str) -> &Self {
+        self.should(&contain_line(line));
+        self
+    }
+
+    fn should_not_contain_line(&self, line: &str) -> &Self {
+        self.should_not(&contain_line(line));
+        self
+    }
+
+    fn should_have_same_content_as<P: AsRef<Path>>(&self, other: P) -> &Self {
+        self.should(&have_same_content_as(other));
+        self
+    }
+
+    fn should_not_have_same_content_as<P: AsRef<Path>>(&self, other: P) -> &Self {
+        self.should_not(&have_same_content_as(other));
+        self
+    }
+
+    fn should_have_size_in_bytes(&self, size: u64) -> &Self {
+        self.should(&have_size_in_bytes(size));
+        self
+    }
+
+    fn should_have_size_in_inclusive_range(&self, range: RangeInclusive<u64>) -> &Self {
+        self.should(&have_size_in_inclusive_range(range));
+        self
+    }
+
+    fn should_not_have_size_in_inclusive_range(&self, range: RangeInclusive<u64>) -> &Self {
+        self.should_not(&have_size_in_inclusive_range(range));
+        self
+    }
+
+    fn should_have_size_in_exclusive_range(&self, range: Range<u64>) -> &Self {
+        self.should(&have_size_in_exclusive_range(range));
+        self
+    }
+
+    fn should_not_have_size_in_exclusive_range(&self, range: Range<u64>) -> &Self {
+        self.should_not(&have_size_in_exclusive_range(range));
+        self
+    }
+}
+
+#[cfg(all(test, feature = "file"))]
+mod tests {
+    use tempdir::TempDir;
+
+    use crate::assertions::file::content::FileContentAssertion;
+    use crate::matchers::string::boundary::end_with;
+    use crate::matchers::string::length::have_atleast_same_length;
+    use crate::matchers::file::test_support::file_with;
+
+    #[test]
+    fn should_have_content() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "assert4rs");
+        file_path.should_have_content("assert4rs");
+    }
+
+    #[test]
+    #[should_panic(expected = "should have the content \"junit\" but was \"assert4rs\"")]
+    fn should_have_content_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "assert4rs");
+        file_path.should_have_content("junit");
+    }
+
+    #[test]
+    fn should_not_have_content() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "assert4rs");
+        file_path.should_not_have_content("junit");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_have_content_but_did() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "assert4rs");
+        file_path.should_not_have_content("assert4rs");
+    }
+
+    #[test]
+    #[should_panic(expected = "should be readable")]
+    fn should_not_have_content_but_file_was_missing() {
+        let directory = TempDir::new(".").unwrap();
+        directory.path().join("missing.txt").should_not_have_content("assert4rs");
+    }
+
+    #[test]
+    #[should_panic(expected = "should be readable")]
+    fn should_not_contain_line_but_file_was_missing() {
+        let directory = TempDir::new(".").unwrap();
+        directory.path().join("missing.txt").should_not_contain_line("assert4rs");
+    }
+
+    #[test]
+    fn should_have_content_matching() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "assert4rs");
+        file_path
+            .should_have_content_matching(end_with("4rs"))
+            .should_have_content_matching(have_atleast_same_length(5));
+    }
+
+    #[test]
+    #[should_panic(expected = "content did not match")]
+    fn should_have_content_matching_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "assert4rs");
+        file_path.should_have_content_matching(end_with("junit"));
+    }
+
+    #[test]
+    fn should_not_have_content_matching() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "assert4rs");
+        file_path.should_not_have_content_matching(end_with("junit"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_have_content_matching_but_did() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "assert4rs");
+        file_path.should_not_have_content_matching(end_with("4rs"));
+    }
+
+    #[test]
+    fn should_have_bytes() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "ab");
+        file_path.should_have_bytes(b"ab");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_bytes_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "ab");
+        file_path.should_have_bytes(b"abc");
+    }
+
+    #[test]
+    fn should_have_line_count() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "one\ntwo");
+        file_path.should_have_line_count(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "should have 3 lines but had 2 lines")]
+    fn should_have_line_count_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "one\ntwo");
+        file_path.should_have_line_count(3);
+    }
+
+    #[test]
+    fn should_contain_line() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "one\ntwo");
+        file_path.should_contain_line("one");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_line_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "one\ntwo");
+        file_path.should_contain_line("three");
+    }
+
+    #[test]
+    fn should_not_contain_line() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "one\ntwo");
+        file_path.should_not_contain_line("three");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_contain_line_but_did() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "one\ntwo");
+        file_path.should_not_contain_line("two");
+    }
+
+    #[test]
+    fn should_have_same_content_as() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "one\ntwo");
+        let other_path = file_with(&directory, "other.txt", "one\ntwo");
+        file_path.should_have_same_content_as(other_path);
+    }
+
+    #[test]
+    #[should_panic(expected = "-two\n+2\n")]
+    fn should_have_same_content_as_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "one\n2");
+        let other_path = file_with(&directory, "other.txt", "one\ntwo");
+        file_path.should_have_same_content_as(other_path);
+    }
+
+    #[test]
+    fn should_not_have_same_content_as() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "one\n2");
+        let other_path = file_with(&directory, "other.txt", "one\ntwo");
+        file_path.should_not_have_same_content_as(other_path);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_have_same_content_as_but_did() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "one\ntwo");
+        let other_path = file_with(&directory, "other.txt", "one\ntwo");
+        file_path.should_not_have_same_content_as(other_path);
+    }
+
+    #[test]
+    fn should_have_size_in_bytes() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "assert4rs");
+        file_path.should_have_size_in_bytes(9);
+    }
+
+    #[test]
+    #[should_panic(expected = "should have the size 8 bytes but was 9 bytes")]
+    fn should_have_size_in_bytes_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "assert4rs");
+        file_path.should_have_size_in_bytes(8);
+    }
+
+    #[test]
+    fn should_have_size_in_inclusive_range() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "assert4rs");
+        file_path.should_have_size_in_inclusive_range(9..=10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_size_in_inclusive_range_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "assert4rs");
+        file_path.should_have_size_in_inclusive_range(1..=8);
+    }
+
+    #[test]
+    fn should_not_have_size_in_inclusive_range() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "assert4rs");
+        file_path.should_not_have_size_in_inclusive_range(1..=8);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_have_size_in_inclusive_range_but_did() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "assert4rs");
+        file_path.should_not_have_size_in_inclusive_range(1..=9);
+    }
+
+    #[test]
+    fn should_have_size_in_exclusive_range() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "assert4rs");
+        file_path.should_have_size_in_exclusive_range(9..10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_size_in_exclusive_range_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "assert4rs");
+        file_path.should_have_size_in_exclusive_range(1..9);
+    }
+
+    #[test]
+    fn should_not_have_size_in_exclusive_range() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "assert4rs");
+        file_path.should_not_have_size_in_exclusive_range(1..9);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_have_size_in_exclusive_range_but_did() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "assert4rs");
+        file_path.should_not_have_size_in_exclusive_range(1..10);
+    }
+}
@@ -0,0 +1,110 @@
+use std::fmt::Debug;
+use std::path::Path;
+
+use crate::matchers::{Should, ShouldNot};
+use crate::matchers::file::csv::{have_csv_header, have_csv_rows};
+
+/// CsvFileAssertion enables assertions about files containing CSV with a header row.
+///
+/// It offers a fluent interface for chaining multiple assertions.
+///
+/// # Example
+/// ```
+/// use std::fs;
+/// use tempdir::TempDir;
+/// use clearcheck::assertions::file::csv::CsvFileAssertion;
+///
+/// let temporary_directory = TempDir::new(".").unwrap();
+/// let file_path = temporary_directory.path().join("users.csv");
+/// fs::write(&file_path, "id,name\n1,clearcheck\n2,junit\n").unwrap();
+///
+/// file_path
+///     .should_have_csv_header(&["id", "name"])
+///     .should_have_csv_rows(2);
+/// ```
+pub trait CsvFileAssertion {
+    /// - Asserts that the csv file has the given number of rows, excluding the header row.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, or if the file is not valid csv.
+    fn should_have_csv_rows(&self, rows: usize) -> &Self;
+
+    /// - Asserts that the header row of the csv file is exactly the given columns.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, or if the file is not valid csv.
+    fn should_have_csv_header(&self, columns: &[&str]) -> &Self;
+
+    /// - Asserts that the header row of the csv file is not the given columns.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_have_csv_header(&self, columns: &[&str]) -> &Self;
+}
+
+impl<T: AsRef<Path> + Debug + ?Sized> CsvFileAssertion for T {
+    fn should_have_csv_rows(&self, rows: usize) -> &Self {
+        self.should(&have_csv_rows(rows));
+        self
+    }
+
+    fn should_have_csv_header(&self, columns: &[&str]) -> &Self {
+        self.should(&have_csv_header(columns));
+        self
+    }
+
+    fn should_not_have_csv_header(&self, columns: &[&str]) -> &Self {
+        self.should_not(&have_csv_header(columns));
+        self
+    }
+}
+
+#[cfg(all(test, feature = "csv"))]
+mod tests {
+    use tempdir::TempDir;
+
+    use crate::assertions::file::csv::CsvFileAssertion;
+    use crate::matchers::file::test_support::file_with;
+
+    #[test]
+    fn should_have_csv_rows() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "users.csv", "id,name\n1,clearcheck\n2,junit\n");
+        file_path.should_have_csv_rows(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "should have 3 csv rows (excluding the header) but had 2")]
+    fn should_have_csv_rows_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "users.csv", "id,name\n1,clearcheck\n2,junit\n");
+        file_path.should_have_csv_rows(3);
+    }
+
+    #[test]
+    fn should_have_csv_header() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "users.csv", "id,name\n1,clearcheck\n");
+        file_path.should_have_csv_header(&["id", "name"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "should have the csv header [\"id\"] but was [\"id\", \"name\"]")]
+    fn should_have_csv_header_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "users.csv", "id,name\n1,clearcheck\n");
+        file_path.should_have_csv_header(&["id"]);
+    }
+
+    #[test]
+    fn should_not_have_csv_header() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "users.csv", "id,name\n1,clearcheck\n");
+        file_path.should_not_have_csv_header(&["id"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "should contain valid csv but failed with")]
+    fn should_have_csv_header_given_invalid_csv() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "users.csv", "id,name\n1\n");
+        file_path.should_have_csv_header(&["id", "name"]);
+    }
+}
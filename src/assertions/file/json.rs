@@ -0,0 +1,171 @@
+use std::fmt::Debug;
+use std::fs;
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::matchers::{Should, ShouldNot};
+use crate::matchers::file::json::{contain_json_equal_to, contain_valid_json};
+
+/// JsonFileAssertion enables assertions about files containing JSON.
+///
+/// It offers a fluent interface for chaining multiple assertions.
+///
+/// # Example
+/// ```
+/// use std::fs;
+/// use serde_json::json;
+/// use tempdir::TempDir;
+/// use clearcheck::assertions::equal::EqualityAssertion;
+/// use clearcheck::assertions::file::json::JsonFileAssertion;
+///
+/// let temporary_directory = TempDir::new(".").unwrap();
+/// let file_path = temporary_directory.path().join("config.json");
+/// fs::write(&file_path, r#"{"name": "clearcheck", "version": 2}"#).unwrap();
+///
+/// file_path
+///     .should_contain_valid_json()
+///     .should_contain_json_equal_to(json!({"version": 2, "name": "clearcheck"}));
+///
+/// let value = file_path.should_parse_as_json();
+/// value["name"].should_equal(&json!("clearcheck"));
+/// ```
+pub trait JsonFileAssertion {
+    /// - Asserts that the file contains valid JSON.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the parse error if the assertion fails.
+    fn should_contain_valid_json(&self) -> &Self;
+
+    /// - Asserts that the file does not contain valid JSON.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_contain_valid_json(&self) -> &Self;
+
+    /// - Asserts that the file contains JSON structurally equal to the expected value.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with a diff of the pretty printed JSON if the assertion fails.
+    fn should_contain_json_equal_to(&self, expected: Value) -> &Self;
+
+    /// - Asserts that the file does not contain JSON structurally equal to the expected value.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_contain_json_equal_to(&self, expected: Value) -> &Self;
+
+    /// - Asserts that the file contains valid JSON.
+    /// - Returns the parsed JSON value for further assertions.
+    /// - Panics with the parse error if the assertion fails.
+    fn should_parse_as_json(&self) -> Value;
+}
+
+impl<T: AsRef<Path> + Debug + ?Sized> JsonFileAssertion for T {
+    fn should_contain_valid_json(&self) -> &Self {
+        self.should(&contain_valid_json());
+        self
+    }
+
+    fn should_not_contain_valid_json(&self) -> &Self {
+        self.should_not(&contain_valid_json());
+        self
+    }
+
+    fn should_contain_json_equal_to(&self, expected: Value) -> &Self {
+        self.should(&contain_json_equal_to(expected));
+        self
+    }
+
+    fn should_not_contain_json_equal_to(&self, expected: Value) -> &Self {
+        self.should_not(&contain_json_equal_to(expected));
+        self
+    }
+
+    fn should_parse_as_json(&self) -> Value {
+        self.should(&contain_valid_json());
+        serde_json::from_str(&fs::read_to_string(self).unwrap()).unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use serde_json::json;
+    use tempdir::TempDir;
+
+    use crate::assertions::equal::EqualityAssertion;
+    use crate::assertions::file::json::JsonFileAssertion;
+    use crate::matchers::file::test_support::file_with;
+
+    #[test]
+    fn should_contain_valid_json() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "config.json", r#"{"name": "clearcheck"}"#);
+        file_path.should_contain_valid_json();
+    }
+
+    #[test]
+    #[should_panic(expected = "should contain valid json but failed to parse")]
+    fn should_contain_valid_json_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "config.json", r#"{"name": "clearcheck""#);
+        file_path.should_contain_valid_json();
+    }
+
+    #[test]
+    fn should_not_contain_valid_json() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "config.json", "name = 'clearcheck'");
+        file_path.should_not_contain_valid_json();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_contain_valid_json_but_did() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "config.json", "[1, 2]");
+        file_path.should_not_contain_valid_json();
+    }
+
+    #[test]
+    fn should_contain_json_equal_to() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "config.json", r#"{"name": "clearcheck", "features": ["json"]}"#);
+        file_path.should_contain_json_equal_to(json!({"features": ["json"], "name": "clearcheck"}));
+    }
+
+    #[test]
+    #[should_panic(expected = "-    \"toml\"\n+    \"json\"")]
+    fn should_contain_json_equal_to_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "config.json", r#"{"name": "clearcheck", "features": ["json"]}"#);
+        file_path.should_contain_json_equal_to(json!({"features": ["toml"], "name": "clearcheck"}));
+    }
+
+    #[test]
+    fn should_not_contain_json_equal_to() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "config.json", r#"{"name": "clearcheck"}"#);
+        file_path.should_not_contain_json_equal_to(json!({"name": "junit"}));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_contain_json_equal_to_but_did() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "config.json", r#"{"name": "clearcheck"}"#);
+        file_path.should_not_contain_json_equal_to(json!({"name": "clearcheck"}));
+    }
+
+    #[test]
+    fn should_parse_as_json() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "config.json", r#"{"name": "clearcheck", "version": 2}"#);
+        let value = file_path.should_parse_as_json();
+        value["version"].should_equal(&json!(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "should contain valid json")]
+    fn should_parse_as_json_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "config.json", "{");
+        file_path.should_parse_as_json();
+    }
+}
@@ -0,0 +1,291 @@
+use std::fmt::Debug;
+use std::path::Path;
+use std::time::SystemTime;
+
+use crate::matchers::{Should, ShouldNot};
+use crate::matchers::file::metadata::{
+    be_a_symlink_to, be_an_empty_directory, be_hidden, be_newer_than, have_been_modified_after,
+    have_been_modified_before,
+};
+
+/// FileMetadataAssertion enables assertions about timestamps, visibility, emptiness and link targets of files and directories.
+///
+/// It offers a fluent interface for chaining multiple assertions.
+///
+/// # Example
+/// ```
+/// use std::fs::File;
+/// use std::time::{Duration, SystemTime};
+/// use tempdir::TempDir;
+/// use clearcheck::assertions::file::metadata::FileMetadataAssertion;
+///
+/// let temporary_directory = TempDir::new(".").unwrap();
+/// let file_path = temporary_directory.path().join("clearcheck.txt");
+/// let _ = File::create(&file_path).unwrap();
+///
+/// file_path
+///     .should_have_been_modified_after(SystemTime::now() - Duration::from_secs(60))
+///     .should_not_be_hidden();
+/// ```
+pub trait FileMetadataAssertion {
+    /// - Asserts that the path was last modified after the given time.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_have_been_modified_after(&self, time: SystemTime) -> &Self;
+
+    /// - Asserts that the path was last modified before the given time.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_have_been_modified_before(&self, time: SystemTime) -> &Self;
+
+    /// - Asserts that the path was last modified after the other path.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_be_newer_than<P: AsRef<Path>>(&self, other: P) -> &Self;
+
+    /// - Asserts that the path was not last modified after the other path.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_be_newer_than<P: AsRef<Path>>(&self, other: P) -> &Self;
+
+    /// - Asserts that the file name of the path starts with a `.`.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_be_hidden(&self) -> &Self;
+
+    /// - Asserts that the file name of the path does not start with a `.`.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_be_hidden(&self) -> &Self;
+
+    /// - Asserts that the path is a directory without any entries.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_be_empty_directory(&self) -> &Self;
+
+    /// - Asserts that the path is not a directory without any entries.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_be_empty_directory(&self) -> &Self;
+
+    /// - Asserts that the path is a symbolic link pointing to the given target (as stored in the link, without resolving it).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_be_symlink_to<P: AsRef<Path>>(&self, target: P) -> &Self;
+}
+
+impl<T: AsRef<Path> + Debug + ?Sized> FileMetadataAssertion for T {
+    fn should_have_been_modified_after(&self, time: SystemTime) -> &Self {
+        self.should(&have_been_modified_after(time));
+        self
+    }
+
+    fn should_have_been_modified_before(&self, time: SystemTime) -> &Self {
+        self.should(&have_been_modified_before(time));
+        self
+    }
+
+    fn should_be_newer_than<P: AsRef<Path>>(&self, other: P) -> &Self {
+        self.should(&be_newer_than(other));
+        self
+    }
+
+    fn should_not_be_newer_than<P: AsRef<Path>>(&self, other: P) -> &Self {
+        self.should_not(&be_newer_than(other));
+        self
+    }
+
+    fn should_be_hidden(&self) -> &Self {
+        self.should(&be_hidden());
+        self
+    }
+
+    fn should_not_be_hidden(&self) -> &Self {
+        self.should_not(&be_hidden());
+        self
+    }
+
+    fn should_be_empty_directory(&self) -> &Self {
+        self.should(&be_an_empty_directory());
+        self
+    }
+
+    fn should_not_be_empty_directory(&self) -> &Self {
+        self.should_not(&be_an_empty_directory());
+        self
+    }
+
+    fn should_be_symlink_to<P: AsRef<Path>>(&self, target: P) -> &Self {
+        self.should(&be_a_symlink_to(target));
+        self
+    }
+}
+
+#[cfg(all(test, feature = "file"))]
+mod tests {
+    use std::fs::File;
+    use std::time::{Duration, SystemTime};
+
+    use tempdir::TempDir;
+
+    use crate::assertions::file::metadata::FileMetadataAssertion;
+
+    #[test]
+    fn should_have_been_modified_after() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = directory.path().join("clearcheck.txt");
+        let _ = File::create(&file_path).unwrap();
+
+        file_path.should_have_been_modified_after(SystemTime::now() - Duration::from_secs(60));
+    }
+
+    #[test]
+    #[should_panic(expected = "should have been modified after")]
+    fn should_have_been_modified_after_but_was_not() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = directory.path().join("clearcheck.txt");
+        let _ = File::create(&file_path).unwrap();
+
+        file_path.should_have_been_modified_after(SystemTime::now() + Duration::from_secs(60));
+    }
+
+    #[test]
+    fn should_have_been_modified_before() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = directory.path().join("clearcheck.txt");
+        let _ = File::create(&file_path).unwrap();
+
+        file_path.should_have_been_modified_before(SystemTime::now() + Duration::from_secs(60));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_been_modified_before_but_was_not() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = directory.path().join("clearcheck.txt");
+        let _ = File::create(&file_path).unwrap();
+
+        file_path.should_have_been_modified_before(SystemTime::now() - Duration::from_secs(60));
+    }
+
+    #[test]
+    fn should_be_newer_than() {
+        let directory = TempDir::new(".").unwrap();
+        let older = directory.path().join("older.txt");
+        let newer = directory.path().join("newer.txt");
+        File::create(&older)
+            .unwrap()
+            .set_modified(SystemTime::now() - Duration::from_secs(3600))
+            .unwrap();
+        let _ = File::create(&newer).unwrap();
+
+        newer.should_be_newer_than(&older);
+    }
+
+    #[test]
+    #[should_panic(expected = "should be newer than")]
+    fn should_be_newer_than_but_was_not() {
+        let directory = TempDir::new(".").unwrap();
+        let older = directory.path().join("older.txt");
+        let newer = directory.path().join("newer.txt");
+        File::create(&older)
+            .unwrap()
+            .set_modified(SystemTime::now() - Duration::from_secs(3600))
+            .unwrap();
+        let _ = File::create(&newer).unwrap();
+
+        older.should_be_newer_than(&newer);
+    }
+
+    #[test]
+    fn should_not_be_newer_than() {
+        let directory = TempDir::new(".").unwrap();
+        let older = directory.path().join("older.txt");
+        let newer = directory.path().join("newer.txt");
+        File::create(&older)
+            .unwrap()
+            .set_modified(SystemTime::now() - Duration::from_secs(3600))
+            .unwrap();
+        let _ = File::create(&newer).unwrap();
+
+        older.should_not_be_newer_than(&newer);
+    }
+
+    #[test]
+    fn should_be_hidden() {
+        ".gitignore".should_be_hidden();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_hidden_but_was_not() {
+        "Cargo.toml".should_be_hidden();
+    }
+
+    #[test]
+    fn should_not_be_hidden() {
+        "Cargo.toml".should_not_be_hidden();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_hidden_but_was() {
+        "src/.env".should_not_be_hidden();
+    }
+
+    #[test]
+    fn should_be_empty_directory() {
+        let directory = TempDir::new(".").unwrap();
+        directory.path().should_be_empty_directory();
+    }
+
+    #[test]
+    #[should_panic(expected = "should be an empty directory")]
+    fn should_be_empty_directory_but_was_not() {
+        let directory = TempDir::new(".").unwrap();
+        let _ = File::create(directory.path().join("clearcheck.txt")).unwrap();
+        directory.path().should_be_empty_directory();
+    }
+
+    #[test]
+    fn should_not_be_empty_directory() {
+        let directory = TempDir::new(".").unwrap();
+        let _ = File::create(directory.path().join("clearcheck.txt")).unwrap();
+        directory.path().should_not_be_empty_directory();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_empty_directory_but_was() {
+        let directory = TempDir::new(".").unwrap();
+        directory.path().should_not_be_empty_directory();
+    }
+
+    #[test]
+    #[should_panic(expected = "should be a readable directory but failed with")]
+    fn should_not_be_empty_directory_but_was_missing() {
+        let directory = TempDir::new(".").unwrap();
+        directory.path().join("missing").should_not_be_empty_directory();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn should_be_symlink_to() {
+        let directory = TempDir::new(".").unwrap();
+        let link = directory.path().join("link");
+        std::os::unix::fs::symlink("target.txt", &link).unwrap();
+
+        link.should_be_symlink_to("target.txt");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    #[should_panic(expected = "but was a link to \"target.txt\"")]
+    fn should_be_symlink_to_but_was_not() {
+        let directory = TempDir::new(".").unwrap();
+        let link = directory.path().join("link");
+        std::os::unix::fs::symlink("target.txt", &link).unwrap();
+
+        link.should_be_symlink_to("other.txt");
+    }
+}
@@ -3,11 +3,24 @@ use std::path::Path;
 
 use crate::matchers::{Should, ShouldNot};
 use crate::matchers::file::{
-    be_a_directory, be_a_file, be_a_symbolic_link, be_absolute, be_readonly, be_relative,
-    be_writable, be_zero_sized, contain_all_file_names, contain_any_file_names, contain_file_name,
-    have_extension,
+    be_a_directory, be_a_file, be_a_symbolic_link, be_absolute, be_canonical, be_readonly,
+    be_relative, be_writable, be_zero_sized, contain_all_file_names, contain_any_file_names,
+    contain_file_name, end_with_path, exist, have_component_count, have_extension,
+    have_extension_ignoring_case, have_file_name, have_parent, have_stem, start_with_path,
 };
 
+pub mod content;
+#[cfg(feature = "csv")]
+pub mod csv;
+#[cfg(feature = "json")]
+pub mod json;
+pub mod metadata;
+#[cfg(feature = "toml")]
+pub mod toml;
+pub mod tree;
+#[cfg(unix)]
+pub mod unix;
+
 /// FileAssertion enables assertions about various properties of file or path.
 ///
 /// It offers a fluent interface for chaining multiple assertions.
@@ -86,6 +99,81 @@ pub trait FileAssertion {
     /// - Panics if the assertion fails.
     fn should_not_have_extension(&self, extension: &'static str) -> &Self;
 
+    /// - Asserts that the path corresponds to a file with the given extension, ignoring case.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_have_extension_ignoring_case(&self, extension: &str) -> &Self;
+
+    /// - Asserts that the path corresponds to a file that does not have the given extension, ignoring case.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_have_extension_ignoring_case(&self, extension: &str) -> &Self;
+
+    /// - Asserts that the final component of the path is the given file name.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_have_file_name(&self, file_name: &str) -> &Self;
+
+    /// - Asserts that the final component of the path is not the given file name.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_have_file_name(&self, file_name: &str) -> &Self;
+
+    /// - Asserts that the file name of the path, without its extension, is the given stem.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_have_stem(&self, stem: &str) -> &Self;
+
+    /// - Asserts that the path has the given parent.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_have_parent<P: AsRef<Path>>(&self, parent: P) -> &Self;
+
+    /// - Asserts that the path starts with the given path, comparing whole components.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_start_with_path<P: AsRef<Path>>(&self, prefix: P) -> &Self;
+
+    /// - Asserts that the path does not start with the given path, comparing whole components.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_start_with_path<P: AsRef<Path>>(&self, prefix: P) -> &Self;
+
+    /// - Asserts that the path ends with the given path, comparing whole components.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_end_with_path<P: AsRef<Path>>(&self, suffix: P) -> &Self;
+
+    /// - Asserts that the path does not end with the given path, comparing whole components.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_end_with_path<P: AsRef<Path>>(&self, suffix: P) -> &Self;
+
+    /// - Asserts that the path has the given number of components.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_have_component_count(&self, count: usize) -> &Self;
+
+    /// - Asserts that the path exists and is in its canonical form (absolute, without `.`, `..` or symbolic links).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_be_canonical(&self) -> &Self;
+
+    /// - Asserts that the path is not in its canonical form.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_be_canonical(&self) -> &Self;
+
+    /// - Asserts that the path exists on the file system.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_exist(&self) -> &Self;
+
+    /// - Asserts that the path does not exist on the file system.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_exist(&self) -> &Self;
+
     /// - Asserts that the path corresponds to a directory that contains the given file name.
     /// - Returns a reference to self for fluent chaining.
     /// - Panics if the assertion fails.
@@ -117,7 +205,7 @@ pub trait FileAssertion {
     fn should_not_contain_any_of_file_names(&self, names: Vec<&'static str>) -> &Self;
 }
 
-impl<T: AsRef<Path> + Debug> FileAssertion for T {
+impl<T: AsRef<Path> + Debug + ?Sized> FileAssertion for T {
     fn should_be_a_directory(&self) -> &Self {
         self.should(&be_a_directory());
         self
@@ -173,6 +261,81 @@ impl<T: AsRef<Path> + Debug> FileAssertion for T {
         self
     }
 
+    fn should_have_extension_ignoring_case(&self, extension: &str) -> &Self {
+        self.should(&have_extension_ignoring_case(extension));
+        self
+    }
+
+    fn should_not_have_extension_ignoring_case(&self, extension: &str) -> &Self {
+        self.should_not(&have_extension_ignoring_case(extension));
+        self
+    }
+
+    fn should_have_file_name(&self, file_name: &str) -> &Self {
+        self.should(&have_file_name(file_name));
+        self
+    }
+
+    fn should_not_have_file_name(&self, file_name: &str) -> &Self {
+        self.should_not(&have_file_name(file_name));
+        self
+    }
+
+    fn should_have_stem(&self, stem: &str) -> &Self {
+        self.should(&have_stem(stem));
+        self
+    }
+
+    fn should_have_parent<P: AsRef<Path>>(&self, parent: P) -> &Self {
+        self.should(&have_parent(parent));
+        self
+    }
+
+    fn should_start_with_path<P: AsRef<Path>>(&self, prefix: P) -> &Self {
+        self.should(&start_with_path(prefix));
+        self
+    }
+
+    fn should_not_start_with_path<P: AsRef<Path>>(&self, prefix: P) -> &Self {
+        self.should_not(&start_with_path(prefix));
+        self
+    }
+
+    fn should_end_with_path<P: AsRef<Path>>(&self, suffix: P) -> &Self {
+        self.should(&end_with_path(suffix));
+        self
+    }
+
+    fn should_not_end_with_path<P: AsRef<Path>>(&self, suffix: P) -> &Self {
+        self.should_not(&end_with_path(suffix));
+        self
+    }
+
+    fn should_have_component_count(&self, count: usize) -> &Self {
+        self.should(&have_component_count(count));
+        self
+    }
+
+    fn should_be_canonical(&self) -> &Self {
+        self.should(&be_canonical());
+        self
+    }
+
+    fn should_not_be_canonical(&self) -> &Self {
+        self.should_not(&be_canonical());
+        self
+    }
+
+    fn should_exist(&self) -> &Self {
+        self.should(&exist());
+        self
+    }
+
+    fn should_not_exist(&self) -> &Self {
+        self.should_not(&exist());
+        self
+    }
+
     fn should_contain_file_name(&self, name: &'static str) -> &Self {
         self.should(&contain_file_name(name));
         self
@@ -208,7 +371,7 @@ impl<T: AsRef<Path> + Debug> FileAssertion for T {
 mod tests {
     use std::fs::File;
     use std::io::Write;
-    use std::path::Path;
+    use std::path::{Path, PathBuf};
 
     use tempdir::TempDir;
 
@@ -387,6 +550,207 @@ mod tests {
         path.should_not_have_extension("zip");
     }
 
+    #[test]
+    fn should_have_extension_ignoring_case() {
+        let path = Path::new("/etc/sample.TXT");
+        path.should_have_extension_ignoring_case("txt");
+    }
+
+    #[test]
+    #[should_panic(expected = "should have extension \"zip\" (ignoring case) but had \"TXT\"")]
+    fn should_have_extension_ignoring_case_but_was_not() {
+        let path = Path::new("/etc/sample.TXT");
+        path.should_have_extension_ignoring_case("zip");
+    }
+
+    #[test]
+    fn should_not_have_extension_ignoring_case() {
+        let path = Path::new("/etc/sample.txt");
+        path.should_not_have_extension_ignoring_case("zip");
+    }
+
+    #[test]
+    #[should_panic(expected = "should not have extension \"zip\" (ignoring case)")]
+    fn should_not_have_extension_ignoring_case_but_was() {
+        let path = Path::new("/etc/sample.ZIP");
+        path.should_not_have_extension_ignoring_case("zip");
+    }
+
+    #[test]
+    fn should_have_file_name() {
+        let path = PathBuf::from("/etc/sample.txt");
+        path.should_have_file_name("sample.txt");
+    }
+
+    #[test]
+    #[should_panic(expected = "should have file name \"sample\" but had \"sample.txt\"")]
+    fn should_have_file_name_but_did_not() {
+        let path = PathBuf::from("/etc/sample.txt");
+        path.should_have_file_name("sample");
+    }
+
+    #[test]
+    fn should_not_have_file_name() {
+        let path = "/etc/sample.txt";
+        path.should_not_have_file_name("sample");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_have_file_name_but_did() {
+        let path = "/etc/sample.txt";
+        path.should_not_have_file_name("sample.txt");
+    }
+
+    #[test]
+    fn should_have_stem() {
+        let path = Path::new("/etc/sample.txt");
+        path.should_have_stem("sample");
+    }
+
+    #[test]
+    #[should_panic(expected = "should have stem \"sample.txt\" but had \"sample\"")]
+    fn should_have_stem_but_did_not() {
+        let path = Path::new("/etc/sample.txt");
+        path.should_have_stem("sample.txt");
+    }
+
+    #[test]
+    fn should_have_parent() {
+        let path = Path::new("/etc/conf.d/sample.txt");
+        path.should_have_parent("/etc/conf.d");
+    }
+
+    #[test]
+    #[should_panic(expected = "should have parent \"/etc\" but had \"/etc/conf.d\"")]
+    fn should_have_parent_but_did_not() {
+        let path = Path::new("/etc/conf.d/sample.txt");
+        path.should_have_parent("/etc");
+    }
+
+    #[test]
+    fn should_start_with_path() {
+        let path = Path::new("/etc/conf.d/sample.txt");
+        path.should_start_with_path("/etc/conf.d");
+    }
+
+    #[test]
+    #[should_panic(expected = "should start with the path \"/etc/conf\"")]
+    fn should_start_with_path_but_did_not() {
+        let path = Path::new("/etc/conf.d/sample.txt");
+        path.should_start_with_path("/etc/conf");
+    }
+
+    #[test]
+    fn should_not_start_with_path() {
+        let path = Path::new("/etc/conf.d/sample.txt");
+        path.should_not_start_with_path("/usr");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_start_with_path_but_did() {
+        let path = Path::new("/etc/conf.d/sample.txt");
+        path.should_not_start_with_path("/etc");
+    }
+
+    #[test]
+    fn should_end_with_path() {
+        let path = Path::new("/etc/conf.d/sample.txt");
+        path.should_end_with_path("conf.d/sample.txt");
+    }
+
+    #[test]
+    #[should_panic(expected = "should end with the path \"d/sample.txt\"")]
+    fn should_end_with_path_but_did_not() {
+        let path = Path::new("/etc/conf.d/sample.txt");
+        path.should_end_with_path("d/sample.txt");
+    }
+
+    #[test]
+    fn should_not_end_with_path() {
+        let path = Path::new("/etc/conf.d/sample.txt");
+        path.should_not_end_with_path("sample.zip");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_end_with_path_but_did() {
+        let path = Path::new("/etc/conf.d/sample.txt");
+        path.should_not_end_with_path("sample.txt");
+    }
+
+    #[test]
+    fn should_have_component_count() {
+        let path = Path::new("src/matchers/file/mod.rs");
+        path.should_have_component_count(4);
+    }
+
+    #[test]
+    #[should_panic(expected = "should have 3 components but had 4")]
+    fn should_have_component_count_but_did_not() {
+        let path = Path::new("src/matchers/file/mod.rs");
+        path.should_have_component_count(3);
+    }
+
+    #[test]
+    fn should_be_canonical() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let path = temporary_directory.path().canonicalize().unwrap();
+        path.should_be_canonical();
+    }
+
+    #[test]
+    #[should_panic(expected = "should be canonical but the canonical path was")]
+    fn should_be_canonical_but_was_not() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let path = temporary_directory
+            .path()
+            .join("..")
+            .join(temporary_directory.path().file_name().unwrap());
+        path.should_be_canonical();
+    }
+
+    #[test]
+    fn should_not_be_canonical() {
+        let path = Path::new("./src/../src");
+        path.should_not_be_canonical();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_canonical_but_was() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        let path = temporary_directory.path().canonicalize().unwrap();
+        path.should_not_be_canonical();
+    }
+
+    #[test]
+    fn should_exist() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        temporary_directory.path().should_exist();
+    }
+
+    #[test]
+    #[should_panic(expected = "should exist")]
+    fn should_exist_but_did_not() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        temporary_directory.path().join("missing.txt").should_exist();
+    }
+
+    #[test]
+    fn should_not_exist() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        temporary_directory.path().join("missing.txt").should_not_exist();
+    }
+
+    #[test]
+    #[should_panic(expected = "should not exist")]
+    fn should_not_exist_but_did() {
+        let temporary_directory = TempDir::new(".").unwrap();
+        temporary_directory.path().should_not_exist();
+    }
+
     #[test]
     fn should_contain_a_file() {
         let temporary_directory = TempDir::new(".").unwrap();
@@ -0,0 +1,114 @@
+use std::fmt::Debug;
+use std::fs;
+use std::path::Path;
+
+use toml::Table;
+
+use crate::matchers::{Should, ShouldNot};
+use crate::matchers::file::toml::contain_valid_toml;
+
+/// TomlFileAssertion enables assertions about files containing TOML.
+///
+/// It offers a fluent interface for chaining multiple assertions.
+///
+/// # Example
+/// ```
+/// use std::fs;
+/// use tempdir::TempDir;
+/// use clearcheck::assertions::file::toml::TomlFileAssertion;
+/// use clearcheck::assertions::option::SomeNoneAssertion;
+///
+/// let temporary_directory = TempDir::new(".").unwrap();
+/// let file_path = temporary_directory.path().join("Cargo.toml");
+/// fs::write(&file_path, "[package]\nname = \"clearcheck\"").unwrap();
+///
+/// let table = file_path.should_contain_valid_toml().should_parse_as_toml();
+/// table.get("package").should_be_some();
+/// ```
+pub trait TomlFileAssertion {
+    /// - Asserts that the file contains a valid TOML document.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the parse error if the assertion fails.
+    fn should_contain_valid_toml(&self) -> &Self;
+
+    /// - Asserts that the file does not contain a valid TOML document.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_contain_valid_toml(&self) -> &Self;
+
+    /// - Asserts that the file contains a valid TOML document.
+    /// - Returns the parsed TOML table for further assertions.
+    /// - Panics with the parse error if the assertion fails.
+    fn should_parse_as_toml(&self) -> Table;
+}
+
+impl<T: AsRef<Path> + Debug + ?Sized> TomlFileAssertion for T {
+    fn should_contain_valid_toml(&self) -> &Self {
+        self.should(&contain_valid_toml());
+        self
+    }
+
+    fn should_not_contain_valid_toml(&self) -> &Self {
+        self.should_not(&contain_valid_toml());
+        self
+    }
+
+    fn should_parse_as_toml(&self) -> Table {
+        self.should(&contain_valid_toml());
+        fs::read_to_string(self).unwrap().parse::<Table>().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "toml"))]
+mod tests {
+    use tempdir::TempDir;
+
+    use crate::assertions::file::toml::TomlFileAssertion;
+    use crate::assertions::option::SomeNoneAssertion;
+    use crate::matchers::file::test_support::file_with;
+
+    #[test]
+    fn should_contain_valid_toml() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "Cargo.toml", "[package]\nname = \"clearcheck\"");
+        file_path.should_contain_valid_toml();
+    }
+
+    #[test]
+    #[should_panic(expected = "should contain valid toml but failed to parse")]
+    fn should_contain_valid_toml_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "Cargo.toml", "[package\nname = ");
+        file_path.should_contain_valid_toml();
+    }
+
+    #[test]
+    fn should_not_contain_valid_toml() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "Cargo.toml", "{\"name\": \"clearcheck\"}");
+        file_path.should_not_contain_valid_toml();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_contain_valid_toml_but_did() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "Cargo.toml", "name = \"clearcheck\"");
+        file_path.should_not_contain_valid_toml();
+    }
+
+    #[test]
+    fn should_parse_as_toml() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "Cargo.toml", "[package]\nname = \"clearcheck\"");
+        file_path.should_parse_as_toml().get("package").should_be_some();
+    }
+
+    #[test]
+    #[should_panic(expected = "should contain valid toml")]
+    fn should_parse_as_toml_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "Cargo.toml", "[package");
+        file_path.should_parse_as_toml();
+    }
+}
@@ -0,0 +1,371 @@
+use std::fmt::Debug;
+use std::path::{Path, PathBuf};
+
+use crate::matchers::{Should, ShouldNot};
+use crate::matchers::file::tree::{
+    contain_files_matching_count, contain_glob, contain_path, have_same_tree_as,
+    have_same_tree_as_ignoring, match_tree_spec,
+};
+
+/// TreeAssertion enables assertions about the entries of a directory tree, keyed by their relative paths,
+/// and assertions that compare an entire directory tree, either against another directory or against an inline spec.
+///
+/// It offers a fluent interface for chaining multiple assertions.
+/// To limit the walk depth or to follow symbolic links, use [`crate::matchers::file::tree::PathTreeMatcher`] with [`crate::matchers::Should`].
+///
+/// # Example
+/// ```
+/// use std::fs;
+/// use tempdir::TempDir;
+/// use clearcheck::assertions::file::tree::TreeAssertion;
+///
+/// let generated = TempDir::new(".").unwrap();
+/// fs::create_dir(generated.path().join("src")).unwrap();
+/// fs::write(generated.path().join("src").join("lib.rs"), "").unwrap();
+/// fs::write(generated.path().join("Cargo.toml"), "").unwrap();
+///
+/// generated
+///     .path()
+///     .should_contain_path("src/lib.rs")
+///     .should_contain_files_matching_count("**/*.rs", 1)
+///     .should_match_tree_spec(&["src/", "src/lib.rs", "Cargo.toml"]);
+/// ```
+pub trait TreeAssertion {
+    /// - Asserts that the directory contains the given path, relative to the directory.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_contain_path<P: Into<PathBuf>>(&self, path: P) -> &Self;
+
+    /// - Asserts that the directory does not contain the given path, relative to the directory.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_contain_path<P: Into<PathBuf>>(&self, path: P) -> &Self;
+
+    /// - Asserts that the directory contains an entry whose relative path matches the given glob pattern.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_contain_glob<S: Into<String>>(&self, pattern: S) -> &Self;
+
+    /// - Asserts that the directory does not contain any entry whose relative path matches the given glob pattern.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the first matching entry if the assertion fails.
+    fn should_not_contain_glob<S: Into<String>>(&self, pattern: S) -> &Self;
+
+    /// - Asserts that the directory contains exactly the given number of files whose relative paths match the given glob pattern.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_contain_files_matching_count<S: Into<String>>(&self, pattern: S, count: usize) -> &Self;
+
+    /// - Asserts that the directory has the same tree (relative paths and file contents) as the other directory.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the missing, extra and different entries if the assertion fails.
+    fn should_have_same_tree_as<P: AsRef<Path>>(&self, other: P) -> &Self;
+
+    /// - Asserts that the directory does not have the same tree (relative paths and file contents) as the other directory.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_have_same_tree_as<P: AsRef<Path>>(&self, other: P) -> &Self;
+
+    /// - Asserts that the directory has the same tree as the other directory, skipping the entries that match any of the glob patterns.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the missing, extra and different entries if the assertion fails.
+    fn should_have_same_tree_as_ignoring<P: AsRef<Path>>(&self, other: P, patterns: &[&str]) -> &Self;
+
+    /// - Asserts that the directory contains exactly the entries in the spec (directories end with `/`).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the missing and extra entries if the assertion fails.
+    fn should_match_tree_spec(&self, spec: &[&str]) -> &Self;
+
+    /// - Asserts that the directory does not contain exactly the entries in the spec (directories end with `/`).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_match_tree_spec(&self, spec: &[&str]) -> &Self;
+}
+
+impl<T: AsRef<Path> + Debug + ?Sized> TreeAssertion for T {
+    fn should_contain_path<P: Into<PathBuf>>(&self, path: P) -> &Self {
+        self.should(&contain_path(path));
+        self
+    }
+
+    fn should_not_contain_path<P: Into<PathBuf>>(&self, path: P) -> &Self {
+        self.should_not(&contain_path(path));
+        self
+    }
+
+    fn should_contain_glob<S: Into<String>>(&self, pattern: S) -> &Self {
+        self.should(&contain_glob(pattern));
+        self
+    }
+
+    fn should_not_contain_glob<S: Into<String>>(&self, pattern: S) -> &Self {
+        self.should_not(&contain_glob(pattern));
+        self
+    }
+
+    fn should_contain_files_matching_count<S: Into<String>>(&self, pattern: S, count: usize) -> &Self {
+        self.should(&contain_files_matching_count(pattern, count));
+        self
+    }
+
+    fn should_have_same_tree_as<P: AsRef<Path>>(&self, other: P) -> &Self {
+        self.should(&have_same_tree_as(other));
+        self
+    }
+
+    fn should_not_have_same_tree_as<P: AsRef<Path>>(&self, other: P) -> &Self {
+        self.should_not(&have_same_tree_as(other));
+        self
+    }
+
+    fn should_have_same_tree_as_ignoring<P: AsRef<Path>>(&self, other: P, patterns: &[&str]) -> &Self {
+        self.should(&have_same_tree_as_ignoring(other, patterns));
+        self
+    }
+
+    fn should_match_tree_spec(&self, spec: &[&str]) -> &Self {
+        self.should(&match_tree_spec(spec));
+        self
+    }
+
+    fn should_not_match_tree_spec(&self, spec: &[&str]) -> &Self {
+        self.should_not(&match_tree_spec(spec));
+        self
+    }
+}
+
+#[cfg(all(test, feature = "file"))]
+mod tests {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    use tempdir::TempDir;
+
+    use crate::assertions::file::tree::TreeAssertion;
+
+    fn project_in(directory: &Path, lib_content: &str) {
+        fs::create_dir_all(directory.join("src")).unwrap();
+        fs::write(directory.join("src").join("lib.rs"), lib_content).unwrap();
+        fs::write(directory.join("Cargo.toml"), "[package]").unwrap();
+    }
+
+    #[test]
+    fn should_contain_path() {
+        let directory = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+
+        directory.path().should_contain_path("src/lib.rs");
+    }
+
+    #[test]
+    #[should_panic(expected = "should contain the path \"lib.rs\"")]
+    fn should_contain_path_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+
+        directory.path().should_contain_path("lib.rs");
+    }
+
+    #[test]
+    fn should_not_contain_path() {
+        let directory = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+
+        directory.path().should_not_contain_path(PathBuf::from("lib.rs"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_contain_path_but_did() {
+        let directory = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+
+        directory.path().should_not_contain_path("src");
+    }
+
+    #[test]
+    #[should_panic(expected = "should both be directories")]
+    fn should_not_have_same_tree_as_but_the_directory_was_missing() {
+        let directory = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+
+        directory.path().join("missing").should_not_have_same_tree_as(directory.path());
+    }
+
+    #[test]
+    #[should_panic(expected = "should be a directory")]
+    fn should_not_match_tree_spec_but_the_directory_was_missing() {
+        let directory = TempDir::new(".").unwrap();
+
+        directory.path().join("missing").should_not_match_tree_spec(&["src/lib.rs"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "should be walkable but failed with")]
+    fn should_not_contain_path_but_the_directory_was_missing() {
+        let directory = TempDir::new(".").unwrap();
+
+        directory.path().join("missing").should_not_contain_path("lib.rs");
+    }
+
+    #[test]
+    fn should_contain_glob() {
+        let directory = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+
+        directory.path().should_contain_glob("src/*.rs");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_glob_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+
+        directory.path().should_contain_glob(String::from("*.rs"));
+    }
+
+    #[test]
+    fn should_not_contain_glob() {
+        let directory = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+
+        directory.path().should_not_contain_glob("**/*.md");
+    }
+
+    #[test]
+    #[should_panic(expected = "but contained \"src/lib.rs\"")]
+    fn should_not_contain_glob_but_did() {
+        let directory = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+
+        directory.path().should_not_contain_glob("**/*.rs");
+    }
+
+    #[test]
+    fn should_contain_files_matching_count() {
+        let directory = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+
+        directory.path().should_contain_files_matching_count("**/*", 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "should contain 3 files matching \"**/*\" but contained 2")]
+    fn should_contain_files_matching_count_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+
+        directory.path().should_contain_files_matching_count("**/*", 3);
+    }
+
+    #[test]
+    fn should_have_same_tree_as() {
+        let directory = TempDir::new(".").unwrap();
+        let other = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+        project_in(other.path(), "mod tree;");
+
+        directory.path().should_have_same_tree_as(other.path());
+    }
+
+    #[test]
+    #[should_panic(expected = "had different content in [\"src/lib.rs\"]")]
+    fn should_have_same_tree_as_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        let other = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+        project_in(other.path(), "mod file;");
+
+        directory.path().should_have_same_tree_as(other.path());
+    }
+
+    #[test]
+    fn should_not_have_same_tree_as() {
+        let directory = TempDir::new(".").unwrap();
+        let other = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+        project_in(other.path(), "mod tree;");
+        fs::write(other.path().join("README.md"), "").unwrap();
+
+        directory.path().should_not_have_same_tree_as(other.path());
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_have_same_tree_as_but_did() {
+        let directory = TempDir::new(".").unwrap();
+        let other = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+        project_in(other.path(), "mod tree;");
+
+        directory.path().should_not_have_same_tree_as(other.path());
+    }
+
+    #[test]
+    fn should_have_same_tree_as_ignoring() {
+        let directory = TempDir::new(".").unwrap();
+        let other = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+        project_in(other.path(), "mod tree;");
+        fs::create_dir_all(directory.path().join("target").join("debug")).unwrap();
+
+        directory
+            .path()
+            .should_have_same_tree_as_ignoring(other.path(), &["target"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "had extra [\"target/\", \"target/debug/\"]")]
+    fn should_have_same_tree_as_ignoring_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        let other = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+        project_in(other.path(), "mod tree;");
+        fs::create_dir_all(directory.path().join("target").join("debug")).unwrap();
+
+        directory
+            .path()
+            .should_have_same_tree_as_ignoring(other.path(), &["*.log"]);
+    }
+
+    #[test]
+    fn should_match_tree_spec() {
+        let directory = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+
+        directory
+            .path()
+            .should_match_tree_spec(&["src/", "src/lib.rs", "Cargo.toml"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "was missing [\"README.md\"]")]
+    fn should_match_tree_spec_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+
+        directory
+            .path()
+            .should_match_tree_spec(&["src/lib.rs", "Cargo.toml", "README.md"]);
+    }
+
+    #[test]
+    fn should_not_match_tree_spec() {
+        let directory = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+
+        directory.path().should_not_match_tree_spec(&["Cargo.toml"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_match_tree_spec_but_did() {
+        let directory = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+
+        directory
+            .path()
+            .should_not_match_tree_spec(&["src/lib.rs", "Cargo.toml"]);
+    }
+}
@@ -0,0 +1,191 @@
+use std::fmt::Debug;
+use std::path::Path;
+
+use crate::matchers::{Should, ShouldNot};
+use crate::matchers::file::unix::{be_executable, be_owned_by_gid, be_owned_by_uid, have_mode};
+
+/// UnixFileAssertion enables assertions about unix permission bits and ownership of files and directories.
+///
+/// It offers a fluent interface for chaining multiple assertions.
+///
+/// # Example
+/// ```
+/// use std::fs;
+/// use std::fs::File;
+/// use std::os::unix::fs::PermissionsExt;
+/// use tempdir::TempDir;
+/// use clearcheck::assertions::file::unix::UnixFileAssertion;
+///
+/// let temporary_directory = TempDir::new(".").unwrap();
+/// let file_path = temporary_directory.path().join("clearcheck.sh");
+/// let _ = File::create(&file_path).unwrap();
+/// fs::set_permissions(&file_path, fs::Permissions::from_mode(0o755)).unwrap();
+///
+/// file_path
+///     .should_have_mode(0o755)
+///     .should_be_executable();
+/// ```
+pub trait UnixFileAssertion {
+    /// - Asserts that the permission bits of the path are exactly the given mode.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_have_mode(&self, mode: u32) -> &Self;
+
+    /// - Asserts that the permission bits of the path are not the given mode.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_have_mode(&self, mode: u32) -> &Self;
+
+    /// - Asserts that the path is a file with at least one of the execute bits set.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_be_executable(&self) -> &Self;
+
+    /// - Asserts that the path is not a file with any of the execute bits set.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_not_be_executable(&self) -> &Self;
+
+    /// - Asserts that the path is owned by the given user id.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_be_owned_by_uid(&self, uid: u32) -> &Self;
+
+    /// - Asserts that the path is owned by the given group id.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    fn should_be_owned_by_gid(&self, gid: u32) -> &Self;
+}
+
+impl<T: AsRef<Path> + Debug + ?Sized> UnixFileAssertion for T {
+    fn should_have_mode(&self, mode: u32) -> &Self {
+        self.should(&have_mode(mode));
+        self
+    }
+
+    fn should_not_have_mode(&self, mode: u32) -> &Self {
+        self.should_not(&have_mode(mode));
+        self
+    }
+
+    fn should_be_executable(&self) -> &Self {
+        self.should(&be_executable());
+        self
+    }
+
+    fn should_not_be_executable(&self) -> &Self {
+        self.should_not(&be_executable());
+        self
+    }
+
+    fn should_be_owned_by_uid(&self, uid: u32) -> &Self {
+        self.should(&be_owned_by_uid(uid));
+        self
+    }
+
+    fn should_be_owned_by_gid(&self, gid: u32) -> &Self {
+        self.should(&be_owned_by_gid(gid));
+        self
+    }
+}
+
+#[cfg(all(test, feature = "file"))]
+mod tests {
+    use std::fs;
+    use std::fs::File;
+    use std::os::unix::fs::{MetadataExt, PermissionsExt};
+    use std::path::{Path, PathBuf};
+
+    use tempdir::TempDir;
+
+    use crate::assertions::file::unix::UnixFileAssertion;
+
+    fn file_with_mode(directory: &Path, mode: u32) -> PathBuf {
+        let file_path = directory.join("clearcheck.sh");
+        let _ = File::create(&file_path).unwrap();
+        fs::set_permissions(&file_path, fs::Permissions::from_mode(mode)).unwrap();
+        file_path
+    }
+
+    #[test]
+    fn should_have_mode() {
+        let directory = TempDir::new(".").unwrap();
+        file_with_mode(directory.path(), 0o644).should_have_mode(0o644);
+    }
+
+    #[test]
+    #[should_panic(expected = "should have mode 0o755 but was 0o644")]
+    fn should_have_mode_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        file_with_mode(directory.path(), 0o644).should_have_mode(0o755);
+    }
+
+    #[test]
+    fn should_not_have_mode() {
+        let directory = TempDir::new(".").unwrap();
+        file_with_mode(directory.path(), 0o644).should_not_have_mode(0o755);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_have_mode_but_did() {
+        let directory = TempDir::new(".").unwrap();
+        file_with_mode(directory.path(), 0o644).should_not_have_mode(0o644);
+    }
+
+    #[test]
+    fn should_be_executable() {
+        let directory = TempDir::new(".").unwrap();
+        file_with_mode(directory.path(), 0o700).should_be_executable();
+    }
+
+    #[test]
+    #[should_panic(expected = "should be an executable file but had mode 0o600")]
+    fn should_be_executable_but_was_not() {
+        let directory = TempDir::new(".").unwrap();
+        file_with_mode(directory.path(), 0o600).should_be_executable();
+    }
+
+    #[test]
+    fn should_not_be_executable() {
+        let directory = TempDir::new(".").unwrap();
+        file_with_mode(directory.path(), 0o600).should_not_be_executable();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_executable_but_was() {
+        let directory = TempDir::new(".").unwrap();
+        file_with_mode(directory.path(), 0o755).should_not_be_executable();
+    }
+
+    #[test]
+    fn should_be_owned_by_uid() {
+        let directory = TempDir::new(".").unwrap();
+        let uid = fs::metadata(directory.path()).unwrap().uid();
+        directory.path().should_be_owned_by_uid(uid);
+    }
+
+    #[test]
+    #[should_panic(expected = "should be owned by uid")]
+    fn should_be_owned_by_uid_but_was_not() {
+        let directory = TempDir::new(".").unwrap();
+        let uid = fs::metadata(directory.path()).unwrap().uid();
+        directory.path().should_be_owned_by_uid(uid + 1);
+    }
+
+    #[test]
+    fn should_be_owned_by_gid() {
+        let directory = TempDir::new(".").unwrap();
+        let gid = fs::metadata(directory.path()).unwrap().gid();
+        directory.path().should_be_owned_by_gid(gid);
+    }
+
+    #[test]
+    #[should_panic(expected = "should be owned by gid")]
+    fn should_be_owned_by_gid_but_was_not() {
+        let directory = TempDir::new(".").unwrap();
+        let gid = fs::metadata(directory.path()).unwrap().gid();
+        directory.path().should_be_owned_by_gid(gid + 1);
+    }
+}
@@ -32,5 +32,9 @@ pub mod int;
 pub mod map;
 pub mod option;
 pub mod ordered;
+pub mod pattern;
+pub mod performance;
 pub mod result;
 pub mod string;
+pub mod time;
+pub mod tuple;
@@ -0,0 +1,101 @@
+//! Provides the [`should_match_pattern`](crate::should_match_pattern) and [`should_not_match_pattern`](crate::should_not_match_pattern) assertions.
+//!
+//! Both assertions accept a value followed by a pattern, which may carry guard matchers on the bound fields,
+//! exactly as accepted by [`match_pattern`](crate::match_pattern).
+//!
+//! # Example
+//! ```
+//! use clearcheck::should_match_pattern;
+//! use clearcheck::matchers::ordered::be_greater_than;
+//!
+//! #[derive(Debug)]
+//! enum Event {
+//!     Created { id: i32 },
+//!     Deleted { id: i32 },
+//! }
+//!
+//! let event = Event::Created { id: 10 };
+//! should_match_pattern!(event, Event::Created { id } if id => be_greater_than(0));
+//! ```
+
+/// - Asserts that the value matches the given pattern and that all the guard matchers (if any) pass.
+/// - Panics if the assertion fails, reporting the Debug representation of the value.
+/// # Example
+/// ```
+/// use clearcheck::should_match_pattern;
+///
+/// let value: Result<i32, String> = Ok(200);
+/// should_match_pattern!(value, Ok(200..=299));
+/// ```
+#[macro_export]
+macro_rules! should_match_pattern {
+    ($value:expr, $($pattern:tt)+) => {
+        $crate::matchers::Should::should(&$value, &$crate::match_pattern!($($pattern)+))
+    };
+}
+
+/// - Asserts that the value does not match the given pattern (or that one of the guard matchers fails).
+/// - Panics if the assertion fails, reporting the Debug representation of the value.
+/// # Example
+/// ```
+/// use clearcheck::should_not_match_pattern;
+///
+/// let value: Result<i32, String> = Ok(404);
+/// should_not_match_pattern!(value, Ok(200..=299));
+/// ```
+#[macro_export]
+macro_rules! should_not_match_pattern {
+    ($value:expr, $($pattern:tt)+) => {
+        $crate::matchers::ShouldNot::should_not(&$value, &$crate::match_pattern!($($pattern)+))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matchers::ordered::be_greater_than;
+
+    #[derive(Debug)]
+    enum Event {
+        Created { id: i32 },
+        Deleted { id: i32 },
+    }
+
+    #[test]
+    fn should_match_pattern() {
+        let event = Event::Created { id: 10 };
+        should_match_pattern!(event, Event::Created { .. });
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_match_pattern_but_did_not() {
+        let event = Event::Deleted { id: 10 };
+        should_match_pattern!(event, Event::Created { .. });
+    }
+
+    #[test]
+    fn should_match_pattern_with_guard() {
+        let event = Event::Created { id: 10 };
+        should_match_pattern!(event, Event::Created { id } if id => be_greater_than(0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_match_pattern_with_guard_but_guard_did_not_pass() {
+        let event = Event::Created { id: -10 };
+        should_match_pattern!(event, Event::Created { id } if id => be_greater_than(0));
+    }
+
+    #[test]
+    fn should_not_match_pattern() {
+        let event = Event::Deleted { id: 10 };
+        should_not_match_pattern!(event, Event::Created { .. });
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_match_pattern_but_did() {
+        let event = Event::Deleted { id: 10 };
+        should_not_match_pattern!(event, Event::Deleted { id } if id => be_greater_than(0));
+    }
+}
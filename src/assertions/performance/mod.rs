@@ -0,0 +1,185 @@
+use std::time::Duration;
+
+use crate::matchers::{Should, ShouldNot};
+use crate::matchers::performance::{
+    be_faster_than, complete_within, have_median_within, have_p95_within, Runs,
+};
+
+/// PerformanceAssertion enables assertions about the run time of closures.
+///
+/// It is built only on the standard library ([`std::time::Instant`]); the failure messages report the measured timings.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use clearcheck::assertions::performance::PerformanceAssertion;
+/// use clearcheck::matchers::performance::Runs;
+///
+/// let work = || (1..=1000).sum::<i32>();
+/// work
+///     .should_complete_within(Duration::from_secs(1))
+///     .should_have_median_within(Runs::new(20).with_warm_up(3), Duration::from_millis(100));
+/// ```
+pub trait PerformanceAssertion {
+    /// - Asserts that a single run of the closure completes within the budget.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use clearcheck::assertions::performance::PerformanceAssertion;
+    ///
+    /// (|| (1..=1000).sum::<i32>()).should_complete_within(Duration::from_secs(1));
+    /// ```
+    fn should_complete_within(&self, budget: Duration) -> &Self;
+
+    /// - Asserts that a single run of the closure does not complete within the budget.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use std::thread;
+    /// use std::time::Duration;
+    /// use clearcheck::assertions::performance::PerformanceAssertion;
+    ///
+    /// (|| thread::sleep(Duration::from_millis(5))).should_not_complete_within(Duration::from_millis(1));
+    /// ```
+    fn should_not_complete_within(&self, budget: Duration) -> &Self;
+
+    /// - Asserts that the median run time of the closure, over the given runs, is within the budget.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use clearcheck::assertions::performance::PerformanceAssertion;
+    /// use clearcheck::matchers::performance::Runs;
+    ///
+    /// let work = || (1..=1000).sum::<i32>();
+    /// work.should_have_median_within(Runs::new(20).with_warm_up(3), Duration::from_millis(100));
+    /// ```
+    fn should_have_median_within(&self, runs: Runs, budget: Duration) -> &Self;
+
+    /// - Asserts that the 95th percentile run time of the closure, over the given runs, is within the budget.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use clearcheck::assertions::performance::PerformanceAssertion;
+    /// use clearcheck::matchers::performance::Runs;
+    ///
+    /// let work = || (1..=1000).sum::<i32>();
+    /// work.should_have_p95_within(Runs::new(20).with_warm_up(3), Duration::from_millis(100));
+    /// ```
+    fn should_have_p95_within(&self, runs: Runs, budget: Duration) -> &Self;
+
+    /// - Asserts that the closure is faster than the other closure by the factor, comparing their median run times over the default [`Runs`].
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails, or if the factor is negative, NaN or infinite.
+    /// # Example
+    /// ```
+    /// use std::thread;
+    /// use std::time::Duration;
+    /// use clearcheck::assertions::performance::PerformanceAssertion;
+    ///
+    /// let cached = || 42;
+    /// cached.should_be_faster_than(|| thread::sleep(Duration::from_millis(20)), 2.0);
+    /// ```
+    fn should_be_faster_than<G: Fn() -> S, S>(&self, other: G, factor: f64) -> &Self;
+}
+
+impl<F: Fn() -> R, R> PerformanceAssertion for F {
+    fn should_complete_within(&self, budget: Duration) -> &Self {
+        self.should(&complete_within(budget));
+        self
+    }
+
+    fn should_not_complete_within(&self, budget: Duration) -> &Self {
+        self.should_not(&complete_within(budget));
+        self
+    }
+
+    fn should_have_median_within(&self, runs: Runs, budget: Duration) -> &Self {
+        self.should(&have_median_within(runs, budget));
+        self
+    }
+
+    fn should_have_p95_within(&self, runs: Runs, budget: Duration) -> &Self {
+        self.should(&have_p95_within(runs, budget));
+        self
+    }
+
+    fn should_be_faster_than<G: Fn() -> S, S>(&self, other: G, factor: f64) -> &Self {
+        self.should(&be_faster_than(other, factor));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use crate::assertions::performance::PerformanceAssertion;
+    use crate::matchers::performance::Runs;
+
+    fn sleep(millis: u64) -> impl Fn() {
+        move || thread::sleep(Duration::from_millis(millis))
+    }
+
+    #[test]
+    fn should_complete_within() {
+        sleep(1).should_complete_within(Duration::from_secs(10));
+    }
+
+    #[test]
+    #[should_panic(expected = "Closure should complete within 1ms but took")]
+    fn should_complete_within_but_did_not() {
+        sleep(10).should_complete_within(Duration::from_millis(1));
+    }
+
+    #[test]
+    fn should_not_complete_within() {
+        sleep(10).should_not_complete_within(Duration::from_millis(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_complete_within_but_did() {
+        sleep(1).should_not_complete_within(Duration::from_secs(10));
+    }
+
+    #[test]
+    fn should_have_median_within() {
+        sleep(1).should_have_median_within(Runs::new(5).with_warm_up(1), Duration::from_secs(10));
+    }
+
+    #[test]
+    #[should_panic(expected = "over 3 runs (warm-up 1) but measured median")]
+    fn should_have_median_within_but_did_not() {
+        sleep(5).should_have_median_within(Runs::new(3).with_warm_up(1), Duration::from_millis(1));
+    }
+
+    #[test]
+    fn should_have_p95_within() {
+        sleep(1).should_have_p95_within(Runs::new(5), Duration::from_secs(10));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_p95_within_but_did_not() {
+        sleep(5).should_have_p95_within(Runs::new(3), Duration::from_millis(1));
+    }
+
+    #[test]
+    fn should_be_faster_than() {
+        (|| 42).should_be_faster_than(sleep(20), 2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Closure should be faster than the other closure by a factor of 1.5")]
+    fn should_be_faster_than_but_was_not() {
+        sleep(2).should_be_faster_than(|| 42, 1.5);
+    }
+}
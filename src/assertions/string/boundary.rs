@@ -65,7 +65,7 @@ pub trait BoundaryAssertion {
 }
 
 impl<T> BoundaryAssertion for T
-    where T: AsRef<str> {
+    where T: AsRef<str> + ?Sized {
     fn should_begin_with(&self, prefix: &'static str) -> &Self {
         self.should(&begin_with(prefix));
         self
@@ -1,7 +1,11 @@
 use crate::matchers::Should;
-use crate::matchers::string::case::{be_lowercase, be_uppercase};
+use crate::matchers::string::case::{
+    be_camel_case, be_kebab_case, be_lowercase, be_pascal_case, be_screaming_snake_case, be_snake_case, be_title_case,
+    be_uppercase, have_first_char_uppercase,
+};
 
-/// CaseAssertion enables assertions about whether a string (or str) is lowercase or uppercase.
+/// CaseAssertion enables assertions about whether a string (or str) is lowercase or uppercase,
+/// and about the naming convention (like camel case or snake case) it follows.
 pub trait CaseAssertion {
     /// - Asserts that the string is lowercase.
     /// - Returns a reference to self for fluent chaining.
@@ -26,10 +30,94 @@ pub trait CaseAssertion {
     /// name.should_be_upper_case();
     /// ```
     fn should_be_upper_case(&self) -> &Self;
+
+    /// - Asserts that every whitespace-separated word of the string starts with an uppercase letter (or a non-letter) and has no other uppercase letters.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the first offending character and its index if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::case::CaseAssertion;
+    ///
+    /// let heading = "Getting Started";
+    /// heading.should_be_title_case();
+    /// ```
+    fn should_be_title_case(&self) -> &Self;
+
+    /// - Asserts that the string is in camel case: a lowercase letter followed by letters and digits, without consecutive uppercase letters.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the first offending character and its index if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::case::CaseAssertion;
+    ///
+    /// let identifier = "parseRequest";
+    /// identifier.should_be_camel_case();
+    /// ```
+    fn should_be_camel_case(&self) -> &Self;
+
+    /// - Asserts that the string is in pascal case: an uppercase letter followed by letters and digits, without consecutive uppercase letters.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the first offending character and its index if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::case::CaseAssertion;
+    ///
+    /// let identifier = "ParseRequest";
+    /// identifier.should_be_pascal_case();
+    /// ```
+    fn should_be_pascal_case(&self) -> &Self;
+
+    /// - Asserts that the string is in snake case: lowercase letters and digits in words separated by single underscores, starting with a letter.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the first offending character and its index if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::case::CaseAssertion;
+    ///
+    /// let identifier = "parse_request";
+    /// identifier.should_be_snake_case();
+    /// ```
+    fn should_be_snake_case(&self) -> &Self;
+
+    /// - Asserts that the string is in screaming snake case: uppercase letters and digits in words separated by single underscores, starting with a letter.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the first offending character and its index if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::case::CaseAssertion;
+    ///
+    /// let identifier = "MAX_RETRIES";
+    /// identifier.should_be_screaming_snake_case();
+    /// ```
+    fn should_be_screaming_snake_case(&self) -> &Self;
+
+    /// - Asserts that the string is in kebab case: lowercase letters and digits in words separated by single hyphens, starting with a letter.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the first offending character and its index if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::case::CaseAssertion;
+    ///
+    /// let identifier = "parse-request";
+    /// identifier.should_be_kebab_case();
+    /// ```
+    fn should_be_kebab_case(&self) -> &Self;
+
+    /// - Asserts that the first character of the string is an uppercase letter.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::case::CaseAssertion;
+    ///
+    /// let name = "Clearcheck";
+    /// name.should_have_first_char_uppercase();
+    /// ```
+    fn should_have_first_char_uppercase(&self) -> &Self;
 }
 
 impl<T> CaseAssertion for T
-    where T: AsRef<str> + PartialEq {
+    where T: AsRef<str> + PartialEq + ?Sized {
     fn should_be_lower_case(&self) -> &Self {
         self.should(&be_lowercase());
         self
@@ -39,6 +127,41 @@ impl<T> CaseAssertion for T
         self.should(&be_uppercase());
         self
     }
+
+    fn should_be_title_case(&self) -> &Self {
+        self.should(&be_title_case());
+        self
+    }
+
+    fn should_be_camel_case(&self) -> &Self {
+        self.should(&be_camel_case());
+        self
+    }
+
+    fn should_be_pascal_case(&self) -> &Self {
+        self.should(&be_pascal_case());
+        self
+    }
+
+    fn should_be_snake_case(&self) -> &Self {
+        self.should(&be_snake_case());
+        self
+    }
+
+    fn should_be_screaming_snake_case(&self) -> &Self {
+        self.should(&be_screaming_snake_case());
+        self
+    }
+
+    fn should_be_kebab_case(&self) -> &Self {
+        self.should(&be_kebab_case());
+        self
+    }
+
+    fn should_have_first_char_uppercase(&self) -> &Self {
+        self.should(&have_first_char_uppercase());
+        self
+    }
 }
 
 #[cfg(test)]
@@ -102,3 +225,106 @@ mod string_tests {
         name.should_be_upper_case();
     }
 }
+
+#[cfg(test)]
+mod convention_tests {
+    use crate::assertions::string::case::CaseAssertion;
+
+    #[test]
+    #[should_panic(expected = "\"2024\" should be lowercase")]
+    fn should_be_lower_case_but_had_only_digits() {
+        let year = "2024";
+        year.should_be_lower_case();
+    }
+
+    #[test]
+    fn should_be_title_case() {
+        let heading = "Getting Started";
+        heading.should_be_title_case();
+    }
+
+    #[test]
+    #[should_panic(expected = "should be title case but found 's' at index 8")]
+    fn should_be_title_case_but_was_not() {
+        let heading = "Getting started";
+        heading.should_be_title_case();
+    }
+
+    #[test]
+    fn should_be_camel_case() {
+        let identifier = "parseRequest";
+        identifier.should_be_camel_case();
+    }
+
+    #[test]
+    #[should_panic(expected = "should be camel case but found '_' at index 5")]
+    fn should_be_camel_case_but_was_not() {
+        let identifier = "parse_request";
+        identifier.should_be_camel_case();
+    }
+
+    #[test]
+    fn should_be_pascal_case() {
+        let identifier = String::from("ParseRequest");
+        identifier.should_be_pascal_case();
+    }
+
+    #[test]
+    #[should_panic(expected = "should be pascal case but found 'p' at index 0")]
+    fn should_be_pascal_case_but_was_not() {
+        let identifier = "parseRequest";
+        identifier.should_be_pascal_case();
+    }
+
+    #[test]
+    fn should_be_snake_case() {
+        let identifier = "parse_request";
+        identifier.should_be_snake_case();
+    }
+
+    #[test]
+    #[should_panic(expected = "should be snake case but found 'R' at index 5")]
+    fn should_be_snake_case_but_was_not() {
+        let identifier = "parseRequest";
+        identifier.should_be_snake_case();
+    }
+
+    #[test]
+    fn should_be_screaming_snake_case() {
+        let identifier = "MAX_RETRIES";
+        identifier.should_be_screaming_snake_case();
+    }
+
+    #[test]
+    #[should_panic(expected = "should be screaming snake case but found '_' at index 0")]
+    fn should_be_screaming_snake_case_but_was_not() {
+        let identifier = "_MAX_RETRIES";
+        identifier.should_be_screaming_snake_case();
+    }
+
+    #[test]
+    fn should_be_kebab_case() {
+        let identifier = "parse-request";
+        identifier.should_be_kebab_case();
+    }
+
+    #[test]
+    #[should_panic(expected = "should be kebab case but found '-' at index 6")]
+    fn should_be_kebab_case_but_was_not() {
+        let identifier = "parse--request";
+        identifier.should_be_kebab_case();
+    }
+
+    #[test]
+    fn should_have_first_char_uppercase() {
+        let name = "Clearcheck";
+        name.should_have_first_char_uppercase();
+    }
+
+    #[test]
+    #[should_panic(expected = "should have the first character uppercase but was empty")]
+    fn should_have_first_char_uppercase_but_was_empty() {
+        let name = "";
+        name.should_have_first_char_uppercase();
+    }
+}
@@ -0,0 +1,288 @@
+use chrono::NaiveDate;
+
+use crate::matchers::{Should, ShouldNot};
+use crate::matchers::string::date::{
+    be_iso8601_date, be_rfc3339, iso8601_date_failure_message, parse_as_date_with_format, parse_iso8601_date,
+};
+
+/// DateStringAssertion enables assertions about whether a string (or str) is a valid date or timestamp.
+///
+/// Like [`crate::assertions::string::numeric::NumericAssertion`], it checks that the string parses;
+/// `should_parse_as_date` additionally returns the parsed date, which allows continuing into [`crate::assertions::date::DateAssertion`].
+///
+/// # Example
+/// ```
+/// use clearcheck::assertions::date::DateAssertion;
+/// use clearcheck::assertions::string::date::DateStringAssertion;
+///
+/// "2024-01-10"
+///     .should_parse_as_date()
+///     .should_be_a_leap_year()
+///     .should_have_month(1);
+/// ```
+pub trait DateStringAssertion {
+    /// - Asserts that the string is an RFC 3339 timestamp, like `2024-01-10T10:20:30Z`.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::date::DateStringAssertion;
+    ///
+    /// let value = "2024-01-10T10:20:30+05:30";
+    /// value.should_be_rfc3339();
+    /// ```
+    fn should_be_rfc3339(&self) -> &Self;
+
+    /// - Asserts that the string is not an RFC 3339 timestamp.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::date::DateStringAssertion;
+    ///
+    /// let value = "2024-01-10 10:20:30";
+    /// value.should_not_be_rfc3339();
+    /// ```
+    fn should_not_be_rfc3339(&self) -> &Self;
+
+    /// - Asserts that the string is an ISO 8601 calendar date, like `2024-01-10`.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::date::DateStringAssertion;
+    ///
+    /// let value = "2024-01-10";
+    /// value.should_be_iso8601_date();
+    /// ```
+    fn should_be_iso8601_date(&self) -> &Self;
+
+    /// - Asserts that the string is not an ISO 8601 calendar date.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::date::DateStringAssertion;
+    ///
+    /// let value = "10/01/2024";
+    /// value.should_not_be_iso8601_date();
+    /// ```
+    fn should_not_be_iso8601_date(&self) -> &Self;
+
+    /// - Asserts that the string parses as a date with the given chrono format.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::date::DateStringAssertion;
+    ///
+    /// let value = "10/01/2024";
+    /// value.should_parse_as_date_with_format("%d/%m/%Y");
+    /// ```
+    fn should_parse_as_date_with_format(&self, format: &'static str) -> &Self;
+
+    /// - Asserts that the string does not parse as a date with the given chrono format.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::date::DateStringAssertion;
+    ///
+    /// let value = "2024-01-10";
+    /// value.should_not_parse_as_date_with_format("%d/%m/%Y");
+    /// ```
+    fn should_not_parse_as_date_with_format(&self, format: &'static str) -> &Self;
+
+    /// - Asserts that the string is an ISO 8601 calendar date.
+    /// - Returns the parsed date, which allows chaining date assertions.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::date::DateAssertion;
+    /// use clearcheck::assertions::string::date::DateStringAssertion;
+    ///
+    /// let value = "2024-01-10";
+    /// value.should_parse_as_date().should_have_day(10);
+    /// ```
+    fn should_parse_as_date(&self) -> NaiveDate;
+}
+
+impl<T> DateStringAssertion for T
+    where T: AsRef<str> + ?Sized
+{
+    fn should_be_rfc3339(&self) -> &Self {
+        self.should(&be_rfc3339());
+        self
+    }
+
+    fn should_not_be_rfc3339(&self) -> &Self {
+        self.should_not(&be_rfc3339());
+        self
+    }
+
+    fn should_be_iso8601_date(&self) -> &Self {
+        self.should(&be_iso8601_date());
+        self
+    }
+
+    fn should_not_be_iso8601_date(&self) -> &Self {
+        self.should_not(&be_iso8601_date());
+        self
+    }
+
+    fn should_parse_as_date_with_format(&self, format: &'static str) -> &Self {
+        self.should(&parse_as_date_with_format(format));
+        self
+    }
+
+    fn should_not_parse_as_date_with_format(&self, format: &'static str) -> &Self {
+        self.should_not(&parse_as_date_with_format(format));
+        self
+    }
+
+    fn should_parse_as_date(&self) -> NaiveDate {
+        match parse_iso8601_date(self.as_ref()) {
+            Ok(date) => date,
+            Err(error) => panic!(
+                "assertion failed: {}",
+                iso8601_date_failure_message(self.as_ref(), &error)
+            ),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "date"))]
+mod tests {
+    use crate::assertions::date::DateAssertion;
+    use crate::assertions::string::date::DateStringAssertion;
+
+    #[test]
+    fn should_be_rfc3339() {
+        let value = "2024-01-10T10:20:30Z";
+        value.should_be_rfc3339();
+    }
+
+    #[test]
+    #[should_panic(expected = "should be an RFC 3339 timestamp but failed to parse")]
+    fn should_be_rfc3339_but_was_not() {
+        let value = "2024-01-10";
+        value.should_be_rfc3339();
+    }
+
+    #[test]
+    fn should_not_be_rfc3339() {
+        let value = "2024-01-10";
+        value.should_not_be_rfc3339();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_rfc3339_but_was() {
+        let value = "2024-01-10T10:20:30.250-02:00";
+        value.should_not_be_rfc3339();
+    }
+
+    #[test]
+    fn should_be_iso8601_date() {
+        let value = "2024-01-10";
+        value.should_be_iso8601_date();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_iso8601_date_but_was_not() {
+        let value = "2024/01/10";
+        value.should_be_iso8601_date();
+    }
+
+    #[test]
+    fn should_not_be_iso8601_date() {
+        let value = "2024/01/10";
+        value.should_not_be_iso8601_date();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_iso8601_date_but_was() {
+        let value = "2024-01-10";
+        value.should_not_be_iso8601_date();
+    }
+
+    #[test]
+    fn should_parse_as_date_with_format() {
+        let value = "Jan 10, 2024";
+        value.should_parse_as_date_with_format("%b %d, %Y");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_parse_as_date_with_format_but_did_not() {
+        let value = "2024-01-10";
+        value.should_parse_as_date_with_format("%b %d, %Y");
+    }
+
+    #[test]
+    fn should_not_parse_as_date_with_format() {
+        let value = "2024-01-10";
+        value.should_not_parse_as_date_with_format("%b %d, %Y");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_parse_as_date_with_format_but_did() {
+        let value = "Jan 10, 2024";
+        value.should_not_parse_as_date_with_format("%b %d, %Y");
+    }
+
+    #[test]
+    fn should_parse_as_date_and_chain() {
+        let value = "2024-01-10";
+        value.should_parse_as_date().should_be_a_leap_year().should_have_day(10);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"2024-01-32\" should be an ISO 8601 date but failed to parse")]
+    fn should_parse_as_date_but_did_not() {
+        let value = "2024-01-32";
+        value.should_parse_as_date();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"2024-1-5\" should be an ISO 8601 date but failed to parse")]
+    fn should_parse_as_date_but_was_not_in_the_iso8601_shape() {
+        let value = "2024-1-5";
+        value.should_parse_as_date();
+    }
+}
+
+#[cfg(all(test, feature = "date"))]
+mod string_tests {
+    use crate::assertions::date::DateAssertion;
+    use crate::assertions::string::date::DateStringAssertion;
+
+    #[test]
+    fn should_be_rfc3339() {
+        let value = String::from("2024-01-10T10:20:30Z");
+        value.should_be_rfc3339();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_rfc3339_but_was_not() {
+        let value = String::from("2024-01-10");
+        value.should_be_rfc3339();
+    }
+
+    #[test]
+    fn should_parse_as_date_and_chain() {
+        let value = String::from("2023-06-15");
+        value.should_parse_as_date().should_not_be_a_leap_year().should_have_month(6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_parse_as_date_but_did_not() {
+        let value = String::from("15-06-2023");
+        value.should_parse_as_date();
+    }
+}
@@ -1,5 +1,9 @@
-use crate::matchers::{Should, ShouldNot};
+use crate::matchers::{Matcher, Should, ShouldNot};
 use crate::matchers::equal::be_equal_ignoring_case;
+use crate::matchers::string::equal::{
+    contain_line_matching, equal_ignoring_line_endings, equal_ignoring_whitespace, equal_lines,
+    have_line, have_number_of_lines,
+};
 
 /// IgnoreCaseEqualityAssertion enables assertions about whether a string (or str) equals other string, with case ignored.
 pub trait IgnoreCaseEqualityAssertion {
@@ -29,7 +33,7 @@ pub trait IgnoreCaseEqualityAssertion {
 }
 
 impl<T> IgnoreCaseEqualityAssertion for T
-    where T: AsRef<str> {
+    where T: AsRef<str> + ?Sized {
     fn should_be_equal_ignoring_case(&self, other: &str) -> &Self {
         self.should(&be_equal_ignoring_case(other));
         self
@@ -41,6 +45,173 @@ impl<T> IgnoreCaseEqualityAssertion for T
     }
 }
 
+/// MultilineAssertion enables assertions about multi-line string (or str) values, like command output or generated code.
+///
+/// Failure messages contain a unified diff of the lines (expected as `---`, actual as `+++`).
+pub trait MultilineAssertion {
+    /// - Asserts that the string has the same lines as the expected string, so `\n` and `\r\n` line endings and a trailing line ending do not matter.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with a unified diff of the lines if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::equal::MultilineAssertion;
+    ///
+    /// let output = "compiled\r\nfinished\r\n";
+    /// output.should_equal_lines("compiled\nfinished");
+    /// ```
+    fn should_equal_lines(&self, expected: &str) -> &Self;
+
+    /// - Asserts that the string does not have the same lines as the expected string.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::equal::MultilineAssertion;
+    ///
+    /// let output = "compiled\nfinished";
+    /// output.should_not_equal_lines("compiled\nfailed");
+    /// ```
+    fn should_not_equal_lines(&self, expected: &str) -> &Self;
+
+    /// - Asserts that the string has the given number of lines.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    ///
+    /// It is not named `should_have_line_count`, since a string is also a path and would then clash
+    /// with `FileContentAssertion::should_have_line_count` (feature `file`) whenever both traits are in scope.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::equal::MultilineAssertion;
+    ///
+    /// let output = "compiled\nfinished\n";
+    /// output.should_have_number_of_lines(2);
+    /// ```
+    fn should_have_number_of_lines(&self, count: usize) -> &Self;
+
+    /// - Asserts that the given line (starting at 1) of the string matches the given string matcher.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the line if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::equal::MultilineAssertion;
+    /// use clearcheck::matchers::string::boundary::begin_with;
+    ///
+    /// let output = "compiled\nfinished in 2s";
+    /// output.should_have_line(2, begin_with("finished"));
+    /// ```
+    fn should_have_line<M: Matcher<str>>(&self, line_number: usize, matcher: M) -> &Self;
+
+    /// - Asserts that any line of the string matches the given string matcher.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::equal::MultilineAssertion;
+    /// use clearcheck::matchers::string::boundary::begin_with;
+    ///
+    /// let output = "compiled\nfinished in 2s";
+    /// output.should_contain_line_matching(begin_with("finished"));
+    /// ```
+    fn should_contain_line_matching<M: Matcher<str>>(&self, matcher: M) -> &Self;
+
+    /// - Asserts that no line of the string matches the given string matcher.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the first matching line if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::equal::MultilineAssertion;
+    /// use clearcheck::matchers::string::boundary::begin_with;
+    ///
+    /// let output = "compiled\nfinished in 2s";
+    /// output.should_not_contain_line_matching(begin_with("error"));
+    /// ```
+    fn should_not_contain_line_matching<M: Matcher<str>>(&self, matcher: M) -> &Self;
+
+    /// - Asserts that the string equals the expected string, treating every run of whitespace (including line breaks) as a single space and ignoring leading and trailing whitespace.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with a unified diff of the lines if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::equal::MultilineAssertion;
+    ///
+    /// let code = "fn main() {\n    run();\n}";
+    /// code.should_equal_ignoring_whitespace("fn main() { run(); }");
+    /// ```
+    fn should_equal_ignoring_whitespace(&self, expected: &str) -> &Self;
+
+    /// - Asserts that the string does not equal the expected string, even when whitespace differences are ignored.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::equal::MultilineAssertion;
+    ///
+    /// let code = "fn main() { run(); }";
+    /// code.should_not_equal_ignoring_whitespace("fn main() {}");
+    /// ```
+    fn should_not_equal_ignoring_whitespace(&self, expected: &str) -> &Self;
+
+    /// - Asserts that the string equals the expected string, treating `\r\n`, `\r` and `\n` line endings as the same.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with a unified diff of the lines if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::equal::MultilineAssertion;
+    ///
+    /// let output = "compiled\r\nfinished\r\n";
+    /// output.should_equal_ignoring_line_endings("compiled\nfinished\n");
+    /// ```
+    fn should_equal_ignoring_line_endings(&self, expected: &str) -> &Self;
+}
+
+impl<T> MultilineAssertion for T
+    where T: AsRef<str> + ?Sized {
+    fn should_equal_lines(&self, expected: &str) -> &Self {
+        self.should(&equal_lines(expected));
+        self
+    }
+
+    fn should_not_equal_lines(&self, expected: &str) -> &Self {
+        self.should_not(&equal_lines(expected));
+        self
+    }
+
+    fn should_have_number_of_lines(&self, count: usize) -> &Self {
+        self.should(&have_number_of_lines(count));
+        self
+    }
+
+    fn should_have_line<M: Matcher<str>>(&self, line_number: usize, matcher: M) -> &Self {
+        self.should(&have_line(line_number, matcher));
+        self
+    }
+
+    fn should_contain_line_matching<M: Matcher<str>>(&self, matcher: M) -> &Self {
+        self.should(&contain_line_matching(matcher));
+        self
+    }
+
+    fn should_not_contain_line_matching<M: Matcher<str>>(&self, matcher: M) -> &Self {
+        self.should_not(&contain_line_matching(matcher));
+        self
+    }
+
+    fn should_equal_ignoring_whitespace(&self, expected: &str) -> &Self {
+        self.should(&equal_ignoring_whitespace(expected));
+        self
+    }
+
+    fn should_not_equal_ignoring_whitespace(&self, expected: &str) -> &Self {
+        self.should_not(&equal_ignoring_whitespace(expected));
+        self
+    }
+
+    fn should_equal_ignoring_line_endings(&self, expected: &str) -> &Self {
+        self.should(&equal_ignoring_line_endings(expected));
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::assertions::string::equal::IgnoreCaseEqualityAssertion;
@@ -102,3 +273,126 @@ mod string_tests {
         name.should_not_be_equal_ignoring_case("JOHN");
     }
 }
+
+#[cfg(test)]
+mod multiline_tests {
+    use crate::assertions::string::equal::MultilineAssertion;
+    use crate::matchers::string::boundary::begin_with;
+
+    #[test]
+    fn should_equal_lines() {
+        let output = "compiled\r\nfinished\r\n";
+        output.should_equal_lines("compiled\nfinished");
+    }
+
+    #[test]
+    #[should_panic(expected = "@@ -1,2 +1,2 @@\n compiled\n-finished\n+failed\n")]
+    fn should_equal_lines_but_did_not() {
+        let output = "compiled\nfailed";
+        output.should_equal_lines("compiled\nfinished");
+    }
+
+    #[test]
+    fn should_not_equal_lines() {
+        let output = "compiled\nfailed";
+        output.should_not_equal_lines("compiled\nfinished");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_equal_lines_but_did() {
+        let output = "compiled\nfinished";
+        output.should_not_equal_lines("compiled\nfinished\n");
+    }
+
+    #[test]
+    fn should_have_number_of_lines() {
+        let output = "compiled\nfinished\n";
+        output.should_have_number_of_lines(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "should have 3 lines but had 2")]
+    fn should_have_number_of_lines_but_did_not() {
+        let output = "compiled\nfinished\n";
+        output.should_have_number_of_lines(3);
+    }
+
+    #[test]
+    fn should_have_line() {
+        let output = "compiled\nfinished in 2s";
+        output.should_have_line(2, begin_with("finished"));
+    }
+
+    #[test]
+    #[should_panic(expected = "line 1 \"compiled\" did not match")]
+    fn should_have_line_but_did_not() {
+        let output = "compiled\nfinished in 2s";
+        output.should_have_line(1, begin_with("finished"));
+    }
+
+    #[test]
+    fn should_contain_line_matching() {
+        let output = "compiled\nfinished in 2s";
+        output.should_contain_line_matching(begin_with("finished"));
+    }
+
+    #[test]
+    #[should_panic(expected = "should contain a line matching the given matcher")]
+    fn should_contain_line_matching_but_did_not() {
+        let output = "compiled\nfinished in 2s";
+        output.should_contain_line_matching(begin_with("error"));
+    }
+
+    #[test]
+    fn should_not_contain_line_matching() {
+        let output = "compiled\nfinished in 2s";
+        output.should_not_contain_line_matching(begin_with("error"));
+    }
+
+    #[test]
+    #[should_panic(expected = "but line 2 \"error: failed\" matched")]
+    fn should_not_contain_line_matching_but_did() {
+        let output = "compiled\nerror: failed";
+        output.should_not_contain_line_matching(begin_with("error"));
+    }
+
+    #[test]
+    fn should_equal_ignoring_whitespace() {
+        let code = "fn main() {\n    run();\n}";
+        code.should_equal_ignoring_whitespace("fn main() { run(); }");
+    }
+
+    #[test]
+    #[should_panic(expected = "ignoring whitespace")]
+    fn should_equal_ignoring_whitespace_but_did_not() {
+        let code = "fn main() {\n    run();\n}";
+        code.should_equal_ignoring_whitespace("fn main() {}");
+    }
+
+    #[test]
+    fn should_not_equal_ignoring_whitespace() {
+        let code = "fn main() { run(); }";
+        code.should_not_equal_ignoring_whitespace("fn main() {}");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_equal_ignoring_whitespace_but_did() {
+        let code = "fn main() {\n}";
+        code.should_not_equal_ignoring_whitespace("fn main() { }");
+    }
+
+    #[test]
+    fn should_equal_ignoring_line_endings() {
+        let output = String::from("compiled\r\nfinished\r\n");
+        output.should_equal_ignoring_line_endings("compiled\nfinished\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "ignoring line endings")]
+    fn should_equal_ignoring_line_endings_but_did_not() {
+        let output = String::from("compiled\r\nfinished");
+        output.should_equal_ignoring_line_endings("compiled\nfinished\n");
+    }
+}
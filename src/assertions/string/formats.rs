@@ -0,0 +1,465 @@
+use crate::matchers::{Should, ShouldNot};
+use crate::matchers::string::formats::{be_base64, be_email, be_ipv4, be_ipv6, be_semver, be_uuid};
+#[cfg(feature = "url")]
+use crate::matchers::string::formats::{be_url, have_url_scheme};
+
+/// StringFormatAssertion enables assertions about whether a string (or str) is in a well-known format,
+/// like a uuid, an ip address, a semantic version, base64 or an email address.
+///
+/// The url assertions need the url feature. Failure messages name the reason the string is not in the format.
+///
+/// # Example
+/// ```
+/// use clearcheck::assertions::string::formats::StringFormatAssertion;
+///
+/// let response_id = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+/// response_id.should_be_uuid().should_not_be_base64();
+/// ```
+pub trait StringFormatAssertion {
+    /// - Asserts that the string is a hyphenated uuid (8-4-4-4-12 hex digits, in any case).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::formats::StringFormatAssertion;
+    ///
+    /// let id = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+    /// id.should_be_uuid();
+    /// ```
+    fn should_be_uuid(&self) -> &Self;
+
+    /// - Asserts that the string is not a hyphenated uuid.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::formats::StringFormatAssertion;
+    ///
+    /// let id = "67e5504410b1426f9247bb680e5fe0c8";
+    /// id.should_not_be_uuid();
+    /// ```
+    fn should_not_be_uuid(&self) -> &Self;
+
+    /// - Asserts that the string is an ipv4 address.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::formats::StringFormatAssertion;
+    ///
+    /// let address = "192.168.0.1";
+    /// address.should_be_ipv4();
+    /// ```
+    fn should_be_ipv4(&self) -> &Self;
+
+    /// - Asserts that the string is not an ipv4 address.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::formats::StringFormatAssertion;
+    ///
+    /// let address = "2001:db8::1";
+    /// address.should_not_be_ipv4();
+    /// ```
+    fn should_not_be_ipv4(&self) -> &Self;
+
+    /// - Asserts that the string is an ipv6 address.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::formats::StringFormatAssertion;
+    ///
+    /// let address = "2001:db8::1";
+    /// address.should_be_ipv6();
+    /// ```
+    fn should_be_ipv6(&self) -> &Self;
+
+    /// - Asserts that the string is not an ipv6 address.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::formats::StringFormatAssertion;
+    ///
+    /// let address = "192.168.0.1";
+    /// address.should_not_be_ipv6();
+    /// ```
+    fn should_not_be_ipv6(&self) -> &Self;
+
+    /// - Asserts that the string is a semantic version (like 1.2.3-rc.1+build.5).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::formats::StringFormatAssertion;
+    ///
+    /// let version = "1.0.0-rc.1+build.5";
+    /// version.should_be_semver();
+    /// ```
+    fn should_be_semver(&self) -> &Self;
+
+    /// - Asserts that the string is not a semantic version.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::formats::StringFormatAssertion;
+    ///
+    /// let version = "1.2";
+    /// version.should_not_be_semver();
+    /// ```
+    fn should_not_be_semver(&self) -> &Self;
+
+    /// - Asserts that the string is standard base64 (with the `+` and `/` alphabet) with `=` padding.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::formats::StringFormatAssertion;
+    ///
+    /// let encoded = "Y2xlYXJjaGVjaw==";
+    /// encoded.should_be_base64();
+    /// ```
+    fn should_be_base64(&self) -> &Self;
+
+    /// - Asserts that the string is not standard, padded base64.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::formats::StringFormatAssertion;
+    ///
+    /// let encoded = "Y2xlYXJjaGVjaw";
+    /// encoded.should_not_be_base64();
+    /// ```
+    fn should_not_be_base64(&self) -> &Self;
+
+    /// - Asserts that the string is an email address: a dot-atom local part, an `@` and a domain of at least two labels.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::formats::StringFormatAssertion;
+    ///
+    /// let email = "john.doe@example.com";
+    /// email.should_be_email();
+    /// ```
+    fn should_be_email(&self) -> &Self;
+
+    /// - Asserts that the string is not an email address.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::formats::StringFormatAssertion;
+    ///
+    /// let email = "john@localhost";
+    /// email.should_not_be_email();
+    /// ```
+    fn should_not_be_email(&self) -> &Self;
+
+    /// - Asserts that the string is an absolute url.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::formats::StringFormatAssertion;
+    ///
+    /// let url = "https://docs.rs/clearcheck";
+    /// url.should_be_url();
+    /// ```
+    #[cfg(feature = "url")]
+    fn should_be_url(&self) -> &Self;
+
+    /// - Asserts that the string is not an absolute url.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::formats::StringFormatAssertion;
+    ///
+    /// let url = "docs.rs/clearcheck";
+    /// url.should_not_be_url();
+    /// ```
+    #[cfg(feature = "url")]
+    fn should_not_be_url(&self) -> &Self;
+
+    /// - Asserts that the string is an absolute url with the given scheme (ignoring case).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::formats::StringFormatAssertion;
+    ///
+    /// let url = "https://docs.rs/clearcheck";
+    /// url.should_have_url_scheme("https");
+    /// ```
+    #[cfg(feature = "url")]
+    fn should_have_url_scheme(&self, scheme: &str) -> &Self;
+
+    /// - Asserts that the string is an absolute url with a scheme other than the given scheme.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::formats::StringFormatAssertion;
+    ///
+    /// let url = "http://docs.rs/clearcheck";
+    /// url.should_not_have_url_scheme("https");
+    /// ```
+    #[cfg(feature = "url")]
+    fn should_not_have_url_scheme(&self, scheme: &str) -> &Self;
+}
+
+impl<T> StringFormatAssertion for T
+    where T: AsRef<str> + ?Sized {
+    fn should_be_uuid(&self) -> &Self {
+        self.should(&be_uuid());
+        self
+    }
+
+    fn should_not_be_uuid(&self) -> &Self {
+        self.should_not(&be_uuid());
+        self
+    }
+
+    fn should_be_ipv4(&self) -> &Self {
+        self.should(&be_ipv4());
+        self
+    }
+
+    fn should_not_be_ipv4(&self) -> &Self {
+        self.should_not(&be_ipv4());
+        self
+    }
+
+    fn should_be_ipv6(&self) -> &Self {
+        self.should(&be_ipv6());
+        self
+    }
+
+    fn should_not_be_ipv6(&self) -> &Self {
+        self.should_not(&be_ipv6());
+        self
+    }
+
+    fn should_be_semver(&self) -> &Self {
+        self.should(&be_semver());
+        self
+    }
+
+    fn should_not_be_semver(&self) -> &Self {
+        self.should_not(&be_semver());
+        self
+    }
+
+    fn should_be_base64(&self) -> &Self {
+        self.should(&be_base64());
+        self
+    }
+
+    fn should_not_be_base64(&self) -> &Self {
+        self.should_not(&be_base64());
+        self
+    }
+
+    fn should_be_email(&self) -> &Self {
+        self.should(&be_email());
+        self
+    }
+
+    fn should_not_be_email(&self) -> &Self {
+        self.should_not(&be_email());
+        self
+    }
+
+    #[cfg(feature = "url")]
+    fn should_be_url(&self) -> &Self {
+        self.should(&be_url());
+        self
+    }
+
+    #[cfg(feature = "url")]
+    fn should_not_be_url(&self) -> &Self {
+        self.should_not(&be_url());
+        self
+    }
+
+    #[cfg(feature = "url")]
+    fn should_have_url_scheme(&self, scheme: &str) -> &Self {
+        self.should(&have_url_scheme(scheme));
+        self
+    }
+
+    #[cfg(feature = "url")]
+    fn should_not_have_url_scheme(&self, scheme: &str) -> &Self {
+        self.should_not(&have_url_scheme(scheme));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::string::formats::StringFormatAssertion;
+
+    #[test]
+    fn should_be_uuid() {
+        let id = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+        id.should_be_uuid();
+    }
+
+    #[test]
+    #[should_panic(expected = "should be a valid uuid but found '_' at index 8 instead of a hyphen")]
+    fn should_be_uuid_but_was_not() {
+        let id = "67e55044_10b1-426f-9247-bb680e5fe0c8";
+        id.should_be_uuid();
+    }
+
+    #[test]
+    fn should_not_be_uuid() {
+        let id = "67e5504410b1426f9247bb680e5fe0c8";
+        id.should_not_be_uuid();
+    }
+
+    #[test]
+    #[should_panic(expected = "should not be a valid uuid")]
+    fn should_not_be_uuid_but_was() {
+        let id = String::from("67e55044-10b1-426f-9247-bb680e5fe0c8");
+        id.should_not_be_uuid();
+    }
+
+    #[test]
+    fn should_be_ipv4() {
+        let address = "192.168.0.1";
+        address.should_be_ipv4().should_not_be_ipv6();
+    }
+
+    #[test]
+    #[should_panic(expected = "should be a valid ipv4 address but failed with: invalid IPv4 address syntax")]
+    fn should_be_ipv4_but_was_not() {
+        let address = "192.168.0";
+        address.should_be_ipv4();
+    }
+
+    #[test]
+    fn should_be_ipv6() {
+        let address = "2001:db8::1";
+        address.should_be_ipv6().should_not_be_ipv4();
+    }
+
+    #[test]
+    #[should_panic(expected = "should be a valid ipv6 address")]
+    fn should_be_ipv6_but_was_not() {
+        let address = "2001:db8:::1";
+        address.should_be_ipv6();
+    }
+
+    #[test]
+    fn should_be_semver() {
+        let version = "1.0.0-rc.1+build.5";
+        version.should_be_semver();
+    }
+
+    #[test]
+    #[should_panic(expected = "should be a valid semver but had an invalid pre-release identifier \"\"")]
+    fn should_be_semver_but_was_not() {
+        let version = "1.0.0-rc..1";
+        version.should_be_semver();
+    }
+
+    #[test]
+    fn should_not_be_semver() {
+        let version = "v1.0.0";
+        version.should_not_be_semver();
+    }
+
+    #[test]
+    fn should_be_base64() {
+        let encoded = "Y2xlYXJjaGVjaw==";
+        encoded.should_be_base64();
+    }
+
+    #[test]
+    #[should_panic(expected = "should be valid base64 but had 3 padding characters instead of at most 2")]
+    fn should_be_base64_but_was_not() {
+        let encoded = "Y2xlY===";
+        encoded.should_be_base64();
+    }
+
+    #[test]
+    fn should_not_be_base64() {
+        let encoded = "Y2xlYXJjaGVjaw";
+        encoded.should_not_be_base64();
+    }
+
+    #[test]
+    fn should_be_email() {
+        let email = "john.doe@example.com";
+        email.should_be_email();
+    }
+
+    #[test]
+    #[should_panic(expected = "should be a valid email but had an invalid domain label \"-example\"")]
+    fn should_be_email_but_was_not() {
+        let email = "john.doe@-example.com";
+        email.should_be_email();
+    }
+
+    #[test]
+    fn should_not_be_email() {
+        let email = "john@localhost";
+        email.should_not_be_email();
+    }
+}
+
+#[cfg(all(test, feature = "url"))]
+mod url_tests {
+    use crate::assertions::string::formats::StringFormatAssertion;
+
+    #[test]
+    fn should_be_url() {
+        let url = "https://docs.rs/clearcheck";
+        url.should_be_url();
+    }
+
+    #[test]
+    #[should_panic(expected = "should be a valid url but failed with: relative URL without a base")]
+    fn should_be_url_but_was_not() {
+        let url = "docs.rs/clearcheck";
+        url.should_be_url();
+    }
+
+    #[test]
+    fn should_not_be_url() {
+        let url = "docs.rs/clearcheck";
+        url.should_not_be_url();
+    }
+
+    #[test]
+    fn should_have_url_scheme() {
+        let url = "HTTPS://docs.rs/clearcheck";
+        url.should_have_url_scheme("https");
+    }
+
+    #[test]
+    #[should_panic(expected = "should have url scheme \"https\" but was \"http\"")]
+    fn should_have_url_scheme_but_did_not() {
+        let url = "http://docs.rs/clearcheck";
+        url.should_have_url_scheme("https");
+    }
+
+    #[test]
+    fn should_not_have_url_scheme() {
+        let url = "http://docs.rs/clearcheck";
+        url.should_not_have_url_scheme("https");
+    }
+
+    #[test]
+    #[should_panic(expected = "should have url scheme \"https\" but was not a valid url")]
+    fn should_have_url_scheme_but_was_not_a_url() {
+        let url = "docs.rs/clearcheck";
+        url.should_have_url_scheme("https");
+    }
+}
@@ -3,7 +3,9 @@ use std::ops::{Range, RangeInclusive};
 use crate::matchers::{Should, ShouldNot};
 use crate::matchers::range::{have_length_in_exclusive_range, have_length_in_inclusive_range};
 use crate::matchers::string::length::{
-    have_atleast_same_length, have_atmost_same_length, have_same_length,
+    have_atleast_same_length, have_atleast_unit_length, have_atmost_same_length,
+    have_atmost_unit_length, have_same_length, have_unit_length,
+    have_unit_length_in_exclusive_range, have_unit_length_in_inclusive_range, LengthUnit,
 };
 
 /// LengthAssertion enables assertions about the length of string (or str) values.
@@ -123,7 +125,7 @@ pub trait LengthAssertion {
 }
 
 impl<T> LengthAssertion for T
-    where T: AsRef<str> {
+    where T: AsRef<str> + ?Sized {
     fn should_have_length(&self, length: usize) -> &Self {
         self.should(&have_same_length(length));
         self
@@ -167,6 +169,358 @@ impl<T> LengthAssertion for T
     }
 }
 
+/// UnicodeLengthAssertion enables assertions about the length of string (or str) values measured in chars (Unicode scalar values),
+/// graphemes (user-perceived characters) or terminal display width, unlike [`LengthAssertion`] which measures bytes.
+///
+/// Grapheme and display width assertions require the `unicode` feature.
+///
+/// It offers a fluent interface for chaining multiple assertions.
+///
+/// # Example
+/// ```
+/// use clearcheck::assertions::string::length::{LengthAssertion, UnicodeLengthAssertion};
+///
+/// let name = "héllo";
+/// name
+///     .should_have_length(6)
+///     .should_have_char_count(5)
+///     .should_have_char_count_in_inclusive_range(1..=5);
+/// ```
+pub trait UnicodeLengthAssertion {
+    /// - Asserts that the string's number of Unicode scalar values (chars) is exactly the given count.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::length::UnicodeLengthAssertion;
+    ///
+    /// let value = "héllo";
+    /// value.should_have_char_count(5);
+    /// ```
+    fn should_have_char_count(&self, count: usize) -> &Self;
+
+    /// - Asserts that the string's number of Unicode scalar values (chars) is not the given count.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::length::UnicodeLengthAssertion;
+    ///
+    /// let value = "héllo";
+    /// value.should_not_have_char_count(4);
+    /// ```
+    fn should_not_have_char_count(&self, count: usize) -> &Self;
+
+    /// - Asserts that the string's number of Unicode scalar values (chars) is greater than or equal to the given count.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::length::UnicodeLengthAssertion;
+    ///
+    /// let value = "héllo";
+    /// value.should_have_at_least_char_count(4);
+    /// ```
+    fn should_have_at_least_char_count(&self, count: usize) -> &Self;
+
+    /// - Asserts that the string's number of Unicode scalar values (chars) is less than or equal to the given count.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::length::UnicodeLengthAssertion;
+    ///
+    /// let value = "héllo";
+    /// value.should_have_at_most_char_count(5);
+    /// ```
+    fn should_have_at_most_char_count(&self, count: usize) -> &Self;
+
+    /// - Asserts that the string's number of Unicode scalar values (chars) falls within the given inclusive range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::length::UnicodeLengthAssertion;
+    ///
+    /// let value = "héllo";
+    /// value.should_have_char_count_in_inclusive_range(1..=5);
+    /// ```
+    fn should_have_char_count_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self;
+
+    /// - Asserts that the string's number of Unicode scalar values (chars) falls within the given exclusive range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::length::UnicodeLengthAssertion;
+    ///
+    /// let value = "héllo";
+    /// value.should_have_char_count_in_exclusive_range(1..6);
+    /// ```
+    fn should_have_char_count_in_exclusive_range(&self, range: Range<usize>) -> &Self;
+
+    /// - Asserts that the string's number of extended grapheme clusters is exactly the given count.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::length::UnicodeLengthAssertion;
+    ///
+    /// let value = "he\u{301}llo";
+    /// value.should_have_grapheme_count(5);
+    /// ```
+    #[cfg(feature = "unicode")]
+    fn should_have_grapheme_count(&self, count: usize) -> &Self;
+
+    /// - Asserts that the string's number of extended grapheme clusters is not the given count.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::length::UnicodeLengthAssertion;
+    ///
+    /// let value = "he\u{301}llo";
+    /// value.should_not_have_grapheme_count(4);
+    /// ```
+    #[cfg(feature = "unicode")]
+    fn should_not_have_grapheme_count(&self, count: usize) -> &Self;
+
+    /// - Asserts that the string's number of extended grapheme clusters is greater than or equal to the given count.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::length::UnicodeLengthAssertion;
+    ///
+    /// let value = "he\u{301}llo";
+    /// value.should_have_at_least_grapheme_count(4);
+    /// ```
+    #[cfg(feature = "unicode")]
+    fn should_have_at_least_grapheme_count(&self, count: usize) -> &Self;
+
+    /// - Asserts that the string's number of extended grapheme clusters is less than or equal to the given count.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::length::UnicodeLengthAssertion;
+    ///
+    /// let value = "he\u{301}llo";
+    /// value.should_have_at_most_grapheme_count(5);
+    /// ```
+    #[cfg(feature = "unicode")]
+    fn should_have_at_most_grapheme_count(&self, count: usize) -> &Self;
+
+    /// - Asserts that the string's number of extended grapheme clusters falls within the given inclusive range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::length::UnicodeLengthAssertion;
+    ///
+    /// let value = "he\u{301}llo";
+    /// value.should_have_grapheme_count_in_inclusive_range(1..=5);
+    /// ```
+    #[cfg(feature = "unicode")]
+    fn should_have_grapheme_count_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self;
+
+    /// - Asserts that the string's number of extended grapheme clusters falls within the given exclusive range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::length::UnicodeLengthAssertion;
+    ///
+    /// let value = "he\u{301}llo";
+    /// value.should_have_grapheme_count_in_exclusive_range(1..6);
+    /// ```
+    #[cfg(feature = "unicode")]
+    fn should_have_grapheme_count_in_exclusive_range(&self, range: Range<usize>) -> &Self;
+
+    /// - Asserts that the string's terminal display width is exactly the given width.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::length::UnicodeLengthAssertion;
+    ///
+    /// let value = "日本";
+    /// value.should_have_display_width(4);
+    /// ```
+    #[cfg(feature = "unicode")]
+    fn should_have_display_width(&self, width: usize) -> &Self;
+
+    /// - Asserts that the string's terminal display width is not the given width.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::length::UnicodeLengthAssertion;
+    ///
+    /// let value = "日本";
+    /// value.should_not_have_display_width(3);
+    /// ```
+    #[cfg(feature = "unicode")]
+    fn should_not_have_display_width(&self, width: usize) -> &Self;
+
+    /// - Asserts that the string's terminal display width is greater than or equal to the given width.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::length::UnicodeLengthAssertion;
+    ///
+    /// let value = "日本";
+    /// value.should_have_at_least_display_width(3);
+    /// ```
+    #[cfg(feature = "unicode")]
+    fn should_have_at_least_display_width(&self, width: usize) -> &Self;
+
+    /// - Asserts that the string's terminal display width is less than or equal to the given width.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::length::UnicodeLengthAssertion;
+    ///
+    /// let value = "日本";
+    /// value.should_have_at_most_display_width(4);
+    /// ```
+    #[cfg(feature = "unicode")]
+    fn should_have_at_most_display_width(&self, width: usize) -> &Self;
+
+    /// - Asserts that the string's terminal display width falls within the given inclusive range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::length::UnicodeLengthAssertion;
+    ///
+    /// let value = "日本";
+    /// value.should_have_display_width_in_inclusive_range(1..=4);
+    /// ```
+    #[cfg(feature = "unicode")]
+    fn should_have_display_width_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self;
+
+    /// - Asserts that the string's terminal display width falls within the given exclusive range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::length::UnicodeLengthAssertion;
+    ///
+    /// let value = "日本";
+    /// value.should_have_display_width_in_exclusive_range(1..5);
+    /// ```
+    #[cfg(feature = "unicode")]
+    fn should_have_display_width_in_exclusive_range(&self, range: Range<usize>) -> &Self;
+}
+
+impl<T> UnicodeLengthAssertion for T
+    where T: AsRef<str> + ?Sized {
+    fn should_have_char_count(&self, count: usize) -> &Self {
+        self.should(&have_unit_length(LengthUnit::Chars, count));
+        self
+    }
+
+    fn should_not_have_char_count(&self, count: usize) -> &Self {
+        self.should_not(&have_unit_length(LengthUnit::Chars, count));
+        self
+    }
+
+    fn should_have_at_least_char_count(&self, count: usize) -> &Self {
+        self.should(&have_atleast_unit_length(LengthUnit::Chars, count));
+        self
+    }
+
+    fn should_have_at_most_char_count(&self, count: usize) -> &Self {
+        self.should(&have_atmost_unit_length(LengthUnit::Chars, count));
+        self
+    }
+
+    fn should_have_char_count_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self {
+        self.should(&have_unit_length_in_inclusive_range(LengthUnit::Chars, range));
+        self
+    }
+
+    fn should_have_char_count_in_exclusive_range(&self, range: Range<usize>) -> &Self {
+        self.should(&have_unit_length_in_exclusive_range(LengthUnit::Chars, range));
+        self
+    }
+
+    #[cfg(feature = "unicode")]
+    fn should_have_grapheme_count(&self, count: usize) -> &Self {
+        self.should(&have_unit_length(LengthUnit::Graphemes, count));
+        self
+    }
+
+    #[cfg(feature = "unicode")]
+    fn should_not_have_grapheme_count(&self, count: usize) -> &Self {
+        self.should_not(&have_unit_length(LengthUnit::Graphemes, count));
+        self
+    }
+
+    #[cfg(feature = "unicode")]
+    fn should_have_at_least_grapheme_count(&self, count: usize) -> &Self {
+        self.should(&have_atleast_unit_length(LengthUnit::Graphemes, count));
+        self
+    }
+
+    #[cfg(feature = "unicode")]
+    fn should_have_at_most_grapheme_count(&self, count: usize) -> &Self {
+        self.should(&have_atmost_unit_length(LengthUnit::Graphemes, count));
+        self
+    }
+
+    #[cfg(feature = "unicode")]
+    fn should_have_grapheme_count_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self {
+        self.should(&have_unit_length_in_inclusive_range(LengthUnit::Graphemes, range));
+        self
+    }
+
+    #[cfg(feature = "unicode")]
+    fn should_have_grapheme_count_in_exclusive_range(&self, range: Range<usize>) -> &Self {
+        self.should(&have_unit_length_in_exclusive_range(LengthUnit::Graphemes, range));
+        self
+    }
+
+    #[cfg(feature = "unicode")]
+    fn should_have_display_width(&self, width: usize) -> &Self {
+        self.should(&have_unit_length(LengthUnit::DisplayWidth, width));
+        self
+    }
+
+    #[cfg(feature = "unicode")]
+    fn should_not_have_display_width(&self, width: usize) -> &Self {
+        self.should_not(&have_unit_length(LengthUnit::DisplayWidth, width));
+        self
+    }
+
+    #[cfg(feature = "unicode")]
+    fn should_have_at_least_display_width(&self, width: usize) -> &Self {
+        self.should(&have_atleast_unit_length(LengthUnit::DisplayWidth, width));
+        self
+    }
+
+    #[cfg(feature = "unicode")]
+    fn should_have_at_most_display_width(&self, width: usize) -> &Self {
+        self.should(&have_atmost_unit_length(LengthUnit::DisplayWidth, width));
+        self
+    }
+
+    #[cfg(feature = "unicode")]
+    fn should_have_display_width_in_inclusive_range(&self, range: RangeInclusive<usize>) -> &Self {
+        self.should(&have_unit_length_in_inclusive_range(LengthUnit::DisplayWidth, range));
+        self
+    }
+
+    #[cfg(feature = "unicode")]
+    fn should_have_display_width_in_exclusive_range(&self, range: Range<usize>) -> &Self {
+        self.should(&have_unit_length_in_exclusive_range(LengthUnit::DisplayWidth, range));
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::assertions::string::length::LengthAssertion;
@@ -384,3 +738,143 @@ mod string_tests {
         name.should_not_have_length_in_exclusive_range(3..9);
     }
 }
+
+#[cfg(test)]
+mod char_count_tests {
+    use crate::assertions::string::length::UnicodeLengthAssertion;
+
+    #[test]
+    fn should_have_char_count() {
+        let value = "héllo";
+        value.should_have_char_count(5);
+    }
+
+    #[test]
+    #[should_panic(expected = "char count should be 6 but was 5")]
+    fn should_have_char_count_but_was_not() {
+        let value = "héllo";
+        value.should_have_char_count(6);
+    }
+
+    #[test]
+    fn should_not_have_char_count() {
+        let value = "héllo";
+        value.should_not_have_char_count(6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_have_char_count_but_was() {
+        let value = "héllo";
+        value.should_not_have_char_count(5);
+    }
+
+    #[test]
+    fn should_have_at_least_char_count() {
+        let value = "héllo";
+        value.should_have_at_least_char_count(5);
+    }
+
+    #[test]
+    #[should_panic(expected = "char count should be at least 6 but was 5")]
+    fn should_have_at_least_char_count_but_was_not() {
+        let value = "héllo";
+        value.should_have_at_least_char_count(6);
+    }
+
+    #[test]
+    fn should_have_at_most_char_count() {
+        let value = "héllo";
+        value.should_have_at_most_char_count(5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_at_most_char_count_but_was_not() {
+        let value = "héllo";
+        value.should_have_at_most_char_count(4);
+    }
+
+    #[test]
+    fn should_have_char_count_in_inclusive_range() {
+        let value = "héllo";
+        value.should_have_char_count_in_inclusive_range(5..=6);
+    }
+
+    #[test]
+    #[should_panic(expected = "char count should be in the range 6..=8 but was 5")]
+    fn should_have_char_count_in_inclusive_range_but_was_not() {
+        let value = "héllo";
+        value.should_have_char_count_in_inclusive_range(6..=8);
+    }
+
+    #[test]
+    fn should_have_char_count_in_exclusive_range() {
+        let value = "héllo";
+        value.should_have_char_count_in_exclusive_range(5..6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_char_count_in_exclusive_range_but_was_not() {
+        let value = "héllo";
+        value.should_have_char_count_in_exclusive_range(1..5);
+    }
+}
+
+#[cfg(all(test, feature = "unicode"))]
+mod unicode_length_tests {
+    use crate::assertions::string::length::UnicodeLengthAssertion;
+
+    #[test]
+    fn should_have_grapheme_count() {
+        let value = "he\u{301}llo";
+        value.should_have_grapheme_count(5).should_have_char_count(6);
+    }
+
+    #[test]
+    #[should_panic(expected = "grapheme count should be 6 but was 5")]
+    fn should_have_grapheme_count_but_was_not() {
+        let value = "he\u{301}llo";
+        value.should_have_grapheme_count(6);
+    }
+
+    #[test]
+    fn should_have_grapheme_count_in_inclusive_range() {
+        let value = "👨‍👩‍👧👍";
+        value.should_have_grapheme_count_in_inclusive_range(1..=2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_grapheme_count_in_exclusive_range_but_was_not() {
+        let value = "👨‍👩‍👧👍";
+        value.should_have_grapheme_count_in_exclusive_range(3..5);
+    }
+
+    #[test]
+    fn should_have_display_width() {
+        let value = "日本";
+        value.should_have_display_width(4);
+    }
+
+    #[test]
+    #[should_panic(expected = "display width should be 2 but was 4")]
+    fn should_have_display_width_but_was_not() {
+        let value = "日本";
+        value.should_have_display_width(2);
+    }
+
+    #[test]
+    fn should_have_at_most_display_width() {
+        let value = "日本";
+        value.should_have_at_most_display_width(4);
+    }
+
+    #[test]
+    #[should_panic(expected = "display width should be at least 5 but was 4")]
+    fn should_have_at_least_display_width_but_was_not() {
+        let value = "日本";
+        value.should_have_at_least_display_width(5);
+    }
+}
@@ -1,6 +1,11 @@
 use crate::matchers::{Should, ShouldNot};
 use crate::matchers::string::empty::be_empty;
-use crate::matchers::string::membership::{contain, contain_a_digit, contain_all_characters, contain_any_of_characters, contain_character, contain_ignoring_case, contain_only_digits, not_contain_digits};
+use crate::matchers::string::membership::{
+    be_alphabetic, be_alphanumeric, be_ascii, be_ascii_printable, be_blank, be_hex, contain_control_characters, contain_whitespace,
+    only_contain_chars_matching,
+    contain, contain_a_digit, contain_all_characters, contain_all_of, contain_any_of, contain_any_of_characters, contain_at_index,
+    contain_character, contain_ignoring_case, contain_in_order, contain_only_digits, contain_only_once, contain_times, not_contain_digits,
+};
 
 /// MembershipAssertion enables assertions about the presence or absence of characters, substrings, or digits within string (or str) values.
 ///
@@ -136,7 +141,7 @@ pub trait MembershipAssertion {
     /// let email = "john@gmail.com";
     /// email.should_contain("gmail");
     /// ```
-    fn should_contain(&self, substr: &'static str) -> &Self;
+    fn should_contain<S: Into<String>>(&self, substr: S) -> &Self;
 
     /// - Asserts that the string does not contain the given substring.
     /// - Returns a reference to self for fluent chaining.
@@ -148,7 +153,7 @@ pub trait MembershipAssertion {
     /// let email = "john@gmail.com";
     /// email.should_not_contain("yahoo");
     /// ```
-    fn should_not_contain(&self, substr: &'static str) -> &Self;
+    fn should_not_contain<S: Into<String>>(&self, substr: S) -> &Self;
 
     /// - Asserts that the string contains the substring, ignoring case differences.
     /// - Returns a reference to self for fluent chaining.
@@ -160,7 +165,7 @@ pub trait MembershipAssertion {
     /// let email = "john@gmail.com";
     /// email.should_contain_ignoring_case("GMAIL");
     /// ```
-    fn should_contain_ignoring_case(&self, substr: &'static str) -> &Self;
+    fn should_contain_ignoring_case<S: Into<String>>(&self, substr: S) -> &Self;
 
     /// - Asserts that the string does not contain the substring, ignoring case differences.
     /// - Returns a reference to self for fluent chaining.
@@ -172,7 +177,108 @@ pub trait MembershipAssertion {
     /// let email = "john@gmail.com";
     /// email.should_not_contain_ignoring_case("YaHoo");
     /// ```
-    fn should_not_contain_ignoring_case(&self, substr: &'static str) -> &Self;
+    fn should_not_contain_ignoring_case<S: Into<String>>(&self, substr: S) -> &Self;
+
+    /// - Asserts that the string contains the given number of non-overlapping occurrences of the substring.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the char indices of the occurrences if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let log = "retry; retry; done";
+    /// log.should_contain_times("retry", 2);
+    /// ```
+    fn should_contain_times<S: Into<String>>(&self, substr: S, times: usize) -> &Self;
+
+    /// - Asserts that the string contains exactly one occurrence of the substring.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the char indices of the occurrences if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let log = "BEGIN body END";
+    /// log.should_contain_only_once(String::from("END"));
+    /// ```
+    fn should_contain_only_once<S: Into<String>>(&self, substr: S) -> &Self;
+
+    /// - Asserts that the substring occurs at the given char index of the string.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the char indices of the occurrences if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let log = "BEGIN body END";
+    /// log.should_contain_at_index("body", 6);
+    /// ```
+    fn should_contain_at_index<S: Into<String>>(&self, substr: S, index: usize) -> &Self;
+
+    /// - Asserts that the string contains the given substrings one after the other (without overlapping), in the given order.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the char indices of the substrings found so far if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let log = "BEGIN body END";
+    /// log.should_contain_in_order(["BEGIN", "body", "END"]);
+    /// ```
+    fn should_contain_in_order<I, S>(&self, substrs: I) -> &Self
+        where I: IntoIterator<Item = S>, S: Into<String>;
+
+    /// - Asserts that the string does not contain the given substrings in the given order.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let log = "BEGIN body END";
+    /// log.should_not_contain_in_order(["END", "BEGIN"]);
+    /// ```
+    fn should_not_contain_in_order<I, S>(&self, substrs: I) -> &Self
+        where I: IntoIterator<Item = S>, S: Into<String>;
+
+    /// - Asserts that the string contains all the given substrings.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the missing substrings if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let log = "BEGIN body END";
+    /// log.should_contain_all_of(vec![String::from("BEGIN"), String::from("END")]);
+    /// ```
+    fn should_contain_all_of<I, S>(&self, substrs: I) -> &Self
+        where I: IntoIterator<Item = S>, S: Into<String>;
+
+    /// - Asserts that the string contains any of the given substrings.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let log = "BEGIN body END";
+    /// log.should_contain_any_of(["COMMIT", "END"]);
+    /// ```
+    fn should_contain_any_of<I, S>(&self, substrs: I) -> &Self
+        where I: IntoIterator<Item = S>, S: Into<String>;
+
+    /// - Asserts that the string does not contain any of the given substrings.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the char indices of the substrings found if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let log = "BEGIN body END";
+    /// log.should_not_contain_any_of(["COMMIT", "ROLLBACK"]);
+    /// ```
+    fn should_not_contain_any_of<I, S>(&self, substrs: I) -> &Self
+        where I: IntoIterator<Item = S>, S: Into<String>;
 
     /// - Asserts that the string is empty (has zero characters).
     /// - Returns a reference to self for fluent chaining.
@@ -197,10 +303,214 @@ pub trait MembershipAssertion {
     /// email.should_not_be_empty();
     /// ```
     fn should_not_be_empty(&self) -> &Self;
+
+    /// - Asserts that the string contains only alphabetic characters.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the first non-alphabetic character and its index if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let name = "clearcheck";
+    /// name.should_be_alphabetic();
+    /// ```
+    fn should_be_alphabetic(&self) -> &Self;
+
+    /// - Asserts that the string contains a character that is not alphabetic.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let name = "assert4j";
+    /// name.should_not_be_alphabetic();
+    /// ```
+    fn should_not_be_alphabetic(&self) -> &Self;
+
+    /// - Asserts that the string contains only alphabetic or numeric characters.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the first offending character and its index if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let name = "assert4j";
+    /// name.should_be_alphanumeric();
+    /// ```
+    fn should_be_alphanumeric(&self) -> &Self;
+
+    /// - Asserts that the string contains a character that is neither alphabetic nor numeric.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let name = "assert-4j";
+    /// name.should_not_be_alphanumeric();
+    /// ```
+    fn should_not_be_alphanumeric(&self) -> &Self;
+
+    /// - Asserts that the string contains only ASCII characters.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the first non-ASCII character and its index if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let name = "clear check!";
+    /// name.should_be_ascii();
+    /// ```
+    fn should_be_ascii(&self) -> &Self;
+
+    /// - Asserts that the string contains a non-ASCII character.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let name = "café";
+    /// name.should_not_be_ascii();
+    /// ```
+    fn should_not_be_ascii(&self) -> &Self;
+
+    /// - Asserts that the string contains only printable ASCII characters (' ' through '~').
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the first non-printable character and its index if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let name = "clear check!";
+    /// name.should_be_ascii_printable();
+    /// ```
+    fn should_be_ascii_printable(&self) -> &Self;
+
+    /// - Asserts that the string contains a character that is not printable ASCII.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let name = "clear\tcheck";
+    /// name.should_not_be_ascii_printable();
+    /// ```
+    fn should_not_be_ascii_printable(&self) -> &Self;
+
+    /// - Asserts that the string contains only ASCII hex digits (case-insensitive).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the first non-hex character and its index if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let digest = "deadBEEF01";
+    /// digest.should_be_hex();
+    /// ```
+    fn should_be_hex(&self) -> &Self;
+
+    /// - Asserts that the string contains a character that is not an ASCII hex digit.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let digest = "0xff";
+    /// digest.should_not_be_hex();
+    /// ```
+    fn should_not_be_hex(&self) -> &Self;
+
+    /// - Asserts that the string is empty or contains only whitespace.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the first non-whitespace character and its index if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let value = " \t\n";
+    /// value.should_be_blank();
+    /// ```
+    fn should_be_blank(&self) -> &Self;
+
+    /// - Asserts that the string contains a character that is not whitespace.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let value = "  x ";
+    /// value.should_not_be_blank();
+    /// ```
+    fn should_not_be_blank(&self) -> &Self;
+
+    /// - Asserts that the string contains a whitespace character.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let value = "clear check";
+    /// value.should_contain_whitespace();
+    /// ```
+    fn should_contain_whitespace(&self) -> &Self;
+
+    /// - Asserts that the string does not contain any whitespace character.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the first whitespace character and its index if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let value = "clearcheck";
+    /// value.should_not_contain_whitespace();
+    /// ```
+    fn should_not_contain_whitespace(&self) -> &Self;
+
+    /// - Asserts that the string contains a control character.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let value = "clear\u{7}check";
+    /// value.should_contain_control_characters();
+    /// ```
+    fn should_contain_control_characters(&self) -> &Self;
+
+    /// - Asserts that the string does not contain any control character.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the first control character and its index if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let value = "clear check";
+    /// value.should_not_contain_control_characters();
+    /// ```
+    fn should_not_contain_control_characters(&self) -> &Self;
+
+    /// - Asserts that every character in the string satisfies the given predicate.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the first character that does not satisfy the predicate, and its index, if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::membership::MembershipAssertion;
+    ///
+    /// let slug = "clear-check";
+    /// slug.should_only_contain_chars_matching(|ch| ch.is_ascii_lowercase() || ch == '-');
+    /// ```
+    fn should_only_contain_chars_matching<F>(&self, predicate: F) -> &Self where F: Fn(char) -> bool;
 }
 
 impl<T> MembershipAssertion for T
-    where T: AsRef<str> {
+    where T: AsRef<str> + ?Sized {
     fn should_only_contain_digits(&self) -> &Self {
         self.should(&contain_only_digits());
         self
@@ -246,26 +556,76 @@ impl<T> MembershipAssertion for T
         self
     }
 
-    fn should_contain(&self, substr: &'static str) -> &Self {
+    fn should_contain<S: Into<String>>(&self, substr: S) -> &Self {
         self.should(&contain(substr));
         self
     }
 
-    fn should_not_contain(&self, substr: &'static str) -> &Self {
+    fn should_not_contain<S: Into<String>>(&self, substr: S) -> &Self {
         self.should_not(&contain(substr));
         self
     }
 
-    fn should_contain_ignoring_case(&self, substr: &'static str) -> &Self {
+    fn should_contain_ignoring_case<S: Into<String>>(&self, substr: S) -> &Self {
         self.should(&contain_ignoring_case(substr));
         self
     }
 
-    fn should_not_contain_ignoring_case(&self, substr: &'static str) -> &Self {
+    fn should_not_contain_ignoring_case<S: Into<String>>(&self, substr: S) -> &Self {
         self.should_not(&contain_ignoring_case(substr));
         self
     }
 
+    fn should_contain_times<S: Into<String>>(&self, substr: S, times: usize) -> &Self {
+        self.should(&contain_times(substr, times));
+        self
+    }
+
+    fn should_contain_only_once<S: Into<String>>(&self, substr: S) -> &Self {
+        self.should(&contain_only_once(substr));
+        self
+    }
+
+    fn should_contain_at_index<S: Into<String>>(&self, substr: S, index: usize) -> &Self {
+        self.should(&contain_at_index(substr, index));
+        self
+    }
+
+    fn should_contain_in_order<I, S>(&self, substrs: I) -> &Self
+        where I: IntoIterator<Item = S>, S: Into<String>
+    {
+        self.should(&contain_in_order(substrs));
+        self
+    }
+
+    fn should_not_contain_in_order<I, S>(&self, substrs: I) -> &Self
+        where I: IntoIterator<Item = S>, S: Into<String>
+    {
+        self.should_not(&contain_in_order(substrs));
+        self
+    }
+
+    fn should_contain_all_of<I, S>(&self, substrs: I) -> &Self
+        where I: IntoIterator<Item = S>, S: Into<String>
+    {
+        self.should(&contain_all_of(substrs));
+        self
+    }
+
+    fn should_contain_any_of<I, S>(&self, substrs: I) -> &Self
+        where I: IntoIterator<Item = S>, S: Into<String>
+    {
+        self.should(&contain_any_of(substrs));
+        self
+    }
+
+    fn should_not_contain_any_of<I, S>(&self, substrs: I) -> &Self
+        where I: IntoIterator<Item = S>, S: Into<String>
+    {
+        self.should_not(&contain_any_of(substrs));
+        self
+    }
+
     fn should_be_empty(&self) -> &Self {
         self.should(&be_empty());
         self
@@ -275,6 +635,91 @@ impl<T> MembershipAssertion for T
         self.should_not(&be_empty());
         self
     }
+
+    fn should_be_alphabetic(&self) -> &Self {
+        self.should(&be_alphabetic());
+        self
+    }
+
+    fn should_not_be_alphabetic(&self) -> &Self {
+        self.should_not(&be_alphabetic());
+        self
+    }
+
+    fn should_be_alphanumeric(&self) -> &Self {
+        self.should(&be_alphanumeric());
+        self
+    }
+
+    fn should_not_be_alphanumeric(&self) -> &Self {
+        self.should_not(&be_alphanumeric());
+        self
+    }
+
+    fn should_be_ascii(&self) -> &Self {
+        self.should(&be_ascii());
+        self
+    }
+
+    fn should_not_be_ascii(&self) -> &Self {
+        self.should_not(&be_ascii());
+        self
+    }
+
+    fn should_be_ascii_printable(&self) -> &Self {
+        self.should(&be_ascii_printable());
+        self
+    }
+
+    fn should_not_be_ascii_printable(&self) -> &Self {
+        self.should_not(&be_ascii_printable());
+        self
+    }
+
+    fn should_be_hex(&self) -> &Self {
+        self.should(&be_hex());
+        self
+    }
+
+    fn should_not_be_hex(&self) -> &Self {
+        self.should_not(&be_hex());
+        self
+    }
+
+    fn should_be_blank(&self) -> &Self {
+        self.should(&be_blank());
+        self
+    }
+
+    fn should_not_be_blank(&self) -> &Self {
+        self.should_not(&be_blank());
+        self
+    }
+
+    fn should_contain_whitespace(&self) -> &Self {
+        self.should(&contain_whitespace());
+        self
+    }
+
+    fn should_not_contain_whitespace(&self) -> &Self {
+        self.should_not(&contain_whitespace());
+        self
+    }
+
+    fn should_contain_control_characters(&self) -> &Self {
+        self.should(&contain_control_characters());
+        self
+    }
+
+    fn should_not_contain_control_characters(&self) -> &Self {
+        self.should_not(&contain_control_characters());
+        self
+    }
+
+    fn should_only_contain_chars_matching<F>(&self, predicate: F) -> &Self where F: Fn(char) -> bool {
+        self.should(&only_contain_chars_matching(predicate));
+        self
+    }
 }
 
 #[cfg(test)]
@@ -675,4 +1120,270 @@ mod string_tests {
         let name = String::from("");
         name.should_not_be_empty();
     }
+
+    #[test]
+    fn should_contain_an_owned_substring() {
+        let email = "john@gmail.com";
+        let domain = String::from("gmail");
+        email.should_contain(&domain).should_contain(domain);
+    }
+
+    #[test]
+    fn should_contain_times() {
+        let log = "retry; retry; done";
+        log.should_contain_times("retry", 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "should contain the substring \"retry\" 3 time(s) but found it 2 time(s) at [0, 7]")]
+    fn should_contain_times_but_it_did_not() {
+        let log = "retry; retry; done";
+        log.should_contain_times("retry", 3);
+    }
+
+    #[test]
+    fn should_contain_only_once() {
+        let log = String::from("BEGIN body END");
+        log.should_contain_only_once(String::from("END"));
+    }
+
+    #[test]
+    #[should_panic(expected = "but found it 2 time(s) at [0, 9]")]
+    fn should_contain_only_once_but_it_did_not() {
+        let log = "END body END";
+        log.should_contain_only_once("END");
+    }
+
+    #[test]
+    fn should_contain_at_index() {
+        let log = "BEGIN body END";
+        log.should_contain_at_index("body", 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "should contain the substring \"body\" at index 0 but found it at [6]")]
+    fn should_contain_at_index_but_it_did_not() {
+        let log = "BEGIN body END";
+        log.should_contain_at_index("body", 0);
+    }
+
+    #[test]
+    fn should_contain_in_order() {
+        let log = "BEGIN body END";
+        log.should_contain_in_order(["BEGIN", "body", "END"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "but found \"BEGIN\" at 0, \"END\" at 11, \"body\" not found after 14")]
+    fn should_contain_in_order_but_it_did_not() {
+        let log = "BEGIN body END";
+        log.should_contain_in_order(["BEGIN", "END", "body"]);
+    }
+
+    #[test]
+    fn should_not_contain_in_order() {
+        let log = "BEGIN body END";
+        log.should_not_contain_in_order(["END", "BEGIN"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "should not contain the substrings [\"BEGIN\", \"END\"] in order but found \"BEGIN\" at 0, \"END\" at 11")]
+    fn should_not_contain_in_order_but_it_did() {
+        let log = "BEGIN body END";
+        log.should_not_contain_in_order(["BEGIN", "END"]);
+    }
+
+    #[test]
+    fn should_contain_all_of() {
+        let log = "BEGIN body END";
+        log.should_contain_all_of(vec![String::from("BEGIN"), String::from("END")]);
+    }
+
+    #[test]
+    #[should_panic(expected = "but was missing [\"COMMIT\"]")]
+    fn should_contain_all_of_but_it_did_not() {
+        let log = "BEGIN body END";
+        log.should_contain_all_of(["BEGIN", "COMMIT"]);
+    }
+
+    #[test]
+    fn should_contain_any_of() {
+        let log = "BEGIN body END";
+        log.should_contain_any_of(["COMMIT", "END"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_any_of_but_it_did_not() {
+        let log = "BEGIN body END";
+        log.should_contain_any_of(["COMMIT", "ROLLBACK"]);
+    }
+
+    #[test]
+    fn should_not_contain_any_of() {
+        let log = "BEGIN body END";
+        log.should_not_contain_any_of(["COMMIT", "ROLLBACK"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "but found \"END\" at 11")]
+    fn should_not_contain_any_of_but_it_did() {
+        let log = "BEGIN body END";
+        log.should_not_contain_any_of(["COMMIT", "END"]);
+    }
+
+    #[test]
+    fn should_be_alphabetic() {
+        let name = "clearcheck";
+        name.should_be_alphabetic();
+    }
+
+    #[test]
+    #[should_panic(expected = "\"assert4j\" should be alphabetic but found '4' at index 6")]
+    fn should_be_alphabetic_but_was_not() {
+        let name = "assert4j";
+        name.should_be_alphabetic();
+    }
+
+    #[test]
+    fn should_not_be_alphabetic() {
+        let name = "assert4j";
+        name.should_not_be_alphabetic();
+    }
+
+    #[test]
+    fn should_be_alphanumeric() {
+        let name = "assert4j";
+        name.should_be_alphanumeric();
+    }
+
+    #[test]
+    #[should_panic(expected = "should be alphanumeric but found '-' at index 6")]
+    fn should_be_alphanumeric_but_was_not() {
+        let name = "assert-4j";
+        name.should_be_alphanumeric();
+    }
+
+    #[test]
+    fn should_be_ascii() {
+        let name = String::from("clear check!");
+        name.should_be_ascii();
+    }
+
+    #[test]
+    #[should_panic(expected = "should be ascii but found 'é' at index 3")]
+    fn should_be_ascii_but_was_not() {
+        let name = "café";
+        name.should_be_ascii();
+    }
+
+    #[test]
+    fn should_be_ascii_printable() {
+        let name = "clear check!";
+        name.should_be_ascii_printable();
+    }
+
+    #[test]
+    #[should_panic(expected = "should be ascii printable but found '\\n' at index 5")]
+    fn should_be_ascii_printable_but_was_not() {
+        let name = "clear\ncheck";
+        name.should_be_ascii_printable();
+    }
+
+    #[test]
+    fn should_be_hex() {
+        let digest = "deadBEEF01";
+        digest.should_be_hex();
+    }
+
+    #[test]
+    #[should_panic(expected = "should be hex but found 'x' at index 1")]
+    fn should_be_hex_but_was_not() {
+        let digest = "0xff";
+        digest.should_be_hex();
+    }
+
+    #[test]
+    fn should_not_be_hex() {
+        let digest = "0xff";
+        digest.should_not_be_hex();
+    }
+
+    #[test]
+    fn should_be_blank() {
+        let value = " \t\n";
+        value.should_be_blank();
+    }
+
+    #[test]
+    #[should_panic(expected = "should be blank but found 'x' at index 2")]
+    fn should_be_blank_but_was_not() {
+        let value = "  x ";
+        value.should_be_blank();
+    }
+
+    #[test]
+    fn should_not_be_blank() {
+        let value = "  x ";
+        value.should_not_be_blank();
+    }
+
+    #[test]
+    #[should_panic(expected = "should not be blank")]
+    fn should_not_be_blank_but_was() {
+        let value = "";
+        value.should_not_be_blank();
+    }
+
+    #[test]
+    fn should_contain_whitespace() {
+        let value = "clear check";
+        value.should_contain_whitespace();
+    }
+
+    #[test]
+    #[should_panic(expected = "should contain whitespace")]
+    fn should_contain_whitespace_but_it_did_not() {
+        let value = "clearcheck";
+        value.should_contain_whitespace();
+    }
+
+    #[test]
+    fn should_not_contain_whitespace() {
+        let value = "clearcheck";
+        value.should_not_contain_whitespace();
+    }
+
+    #[test]
+    #[should_panic(expected = "should not contain whitespace but found '\\t' at index 5")]
+    fn should_not_contain_whitespace_but_it_did() {
+        let value = "clear\tcheck";
+        value.should_not_contain_whitespace();
+    }
+
+    #[test]
+    fn should_contain_control_characters() {
+        let value = "clear\u{7}check";
+        value.should_contain_control_characters();
+    }
+
+    #[test]
+    #[should_panic(expected = "should not contain control characters but found '\\u{7}' at index 5")]
+    fn should_not_contain_control_characters_but_it_did() {
+        let value = "clear\u{7}check";
+        value.should_not_contain_control_characters();
+    }
+
+    #[test]
+    fn should_only_contain_chars_matching() {
+        let slug = "clear-check";
+        slug.should_only_contain_chars_matching(|ch| ch.is_ascii_lowercase() || ch == '-');
+    }
+
+    #[test]
+    #[should_panic(expected = "should only contain characters matching the given predicate but found 'C' at index 6")]
+    fn should_only_contain_chars_matching_but_it_did_not() {
+        let slug = "clear-Check";
+        slug.should_only_contain_chars_matching(|ch| ch.is_ascii_lowercase() || ch == '-');
+    }
 }
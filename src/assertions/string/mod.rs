@@ -1,8 +1,12 @@
 pub mod boundary;
 pub mod case;
+#[cfg(feature = "date")]
+pub mod date;
 pub mod equal;
+pub mod formats;
 pub mod length;
 pub mod membership;
 pub mod numeric;
+pub mod similarity;
 #[cfg(feature = "regex")]
 pub mod regex;
@@ -1,9 +1,26 @@
+use std::fmt::{Debug, Display};
+use std::ops::{Range, RangeInclusive};
 use std::str::FromStr;
 
 use crate::matchers::{Should, ShouldNot};
-use crate::matchers::string::numeric::be_numeric;
+use crate::matchers::string::numeric::{be_numeric, be_numeric_in_exclusive_range, be_numeric_in_inclusive_range, parse_failure_message};
 
 /// NumericAssertion enables assertions about whether a string (or str) is numeric.
+///
+/// [should_parse_as](NumericAssertion::should_parse_as) returns the parsed number,
+/// so the fluent chain can continue with numeric assertions.
+///
+/// # Example
+/// ```
+/// use clearcheck::assertions::ordered::OrderedAssertion;
+/// use clearcheck::assertions::string::numeric::NumericAssertion;
+///
+/// let count = "42";
+/// count
+///     .should_be_numeric_in_range::<i32>(0..=100)
+///     .should_parse_as::<i32>()
+///     .should_be_greater_than(&10);
+/// ```
 pub trait NumericAssertion {
     /// - Asserts that the string is numeric.
     /// - Returns a reference to self for fluent chaining.
@@ -28,10 +45,52 @@ pub trait NumericAssertion {
     /// name.should_not_be_numeric::<i32>();
     /// ```
     fn should_not_be_numeric<T: FromStr>(&self) -> &Self;
+
+    /// - Asserts that the string is numeric.
+    /// - Returns the parsed number for further assertions.
+    /// - Panics with the parse error if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::ordered::OrderedAssertion;
+    /// use clearcheck::assertions::string::numeric::NumericAssertion;
+    ///
+    /// let value = "42";
+    /// value.should_parse_as::<i32>().should_be_greater_than(&10);
+    /// ```
+    fn should_parse_as<T: FromStr>(&self) -> T
+        where T::Err: Display;
+
+    /// - Asserts that the string parses to a number within the given inclusive range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the parsed number, or the parse error, if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::numeric::NumericAssertion;
+    ///
+    /// let ratio = "0.75";
+    /// ratio.should_be_numeric_in_range::<f64>(0.0..=1.0);
+    /// ```
+    fn should_be_numeric_in_range<T>(&self, range: RangeInclusive<T>) -> &Self
+        where T: FromStr + PartialOrd + Debug,
+              T::Err: Display;
+
+    /// - Asserts that the string parses to a number within the given exclusive range.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the parsed number, or the parse error, if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::numeric::NumericAssertion;
+    ///
+    /// let percentage = "99";
+    /// percentage.should_be_numeric_in_exclusive_range::<u8>(0..100);
+    /// ```
+    fn should_be_numeric_in_exclusive_range<T>(&self, range: Range<T>) -> &Self
+        where T: FromStr + PartialOrd + Debug,
+              T::Err: Display;
 }
 
 impl<S> NumericAssertion for S
-    where S: AsRef<str>
+    where S: AsRef<str> + ?Sized
 {
     fn should_be_numeric<T: FromStr>(&self) -> &Self {
         self.should(&be_numeric::<T>());
@@ -42,6 +101,28 @@ impl<S> NumericAssertion for S
         self.should_not(&be_numeric::<T>());
         self
     }
+
+    fn should_parse_as<T: FromStr>(&self) -> T
+        where T::Err: Display {
+        match self.as_ref().parse::<T>() {
+            Ok(number) => number,
+            Err(err) => panic!("assertion failed: {}", parse_failure_message(self.as_ref(), &err)),
+        }
+    }
+
+    fn should_be_numeric_in_range<T>(&self, range: RangeInclusive<T>) -> &Self
+        where T: FromStr + PartialOrd + Debug,
+              T::Err: Display {
+        self.should(&be_numeric_in_inclusive_range(range));
+        self
+    }
+
+    fn should_be_numeric_in_exclusive_range<T>(&self, range: Range<T>) -> &Self
+        where T: FromStr + PartialOrd + Debug,
+              T::Err: Display {
+        self.should(&be_numeric_in_exclusive_range(range));
+        self
+    }
 }
 
 #[cfg(test)]
@@ -105,3 +186,96 @@ mod string_tests {
         value.should_not_be_numeric::<i32>();
     }
 }
+
+#[cfg(test)]
+mod parse_tests {
+    use std::str::FromStr;
+
+    use crate::assertions::equal::EqualityAssertion;
+    use crate::assertions::ordered::OrderedAssertion;
+    use crate::assertions::string::numeric::NumericAssertion;
+
+    struct Even;
+
+    impl FromStr for Even {
+        type Err = ();
+
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            match value.parse::<u32>() {
+                Ok(number) if number % 2 == 0 => Ok(Even),
+                _ => Err(()),
+            }
+        }
+    }
+
+    #[test]
+    fn should_be_numeric_given_a_parse_error_without_display() {
+        "42".should_be_numeric::<Even>();
+        "43".should_not_be_numeric::<Even>();
+    }
+
+    #[test]
+    fn should_parse_as() {
+        let value = "42";
+        value.should_parse_as::<i32>().should_be_greater_than(&10).should_equal(&42);
+    }
+
+    #[test]
+    fn should_parse_an_owned_string_as() {
+        let value = String::from("0.5");
+        value.should_parse_as::<f64>().should_be_less_than(&1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"forty two\" should be numeric but failed with: invalid digit found in string")]
+    fn should_parse_as_but_failed() {
+        let value = "forty two";
+        value.should_parse_as::<i32>();
+    }
+
+    #[test]
+    fn should_be_numeric_in_range() {
+        let ratio = "0.75";
+        ratio.should_be_numeric_in_range::<f64>(0.0..=1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"1.5\" should be numeric in the range 0.0..=1.0 but was 1.5")]
+    fn should_be_numeric_in_range_but_was_not() {
+        let ratio = "1.5";
+        ratio.should_be_numeric_in_range::<f64>(0.0..=1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "should be numeric in the range 0.0..=1.0 but failed with: invalid float literal")]
+    fn should_be_numeric_in_range_but_was_not_numeric() {
+        let ratio = "half";
+        ratio.should_be_numeric_in_range::<f64>(0.0..=1.0);
+    }
+
+    #[test]
+    fn should_be_numeric_in_exclusive_range() {
+        let percentage = "99";
+        percentage.should_be_numeric_in_exclusive_range::<u8>(0..100);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"100\" should be numeric in the range 0..100 but was 100")]
+    fn should_be_numeric_in_exclusive_range_but_was_not() {
+        let percentage = "100";
+        percentage.should_be_numeric_in_exclusive_range::<u8>(0..100);
+    }
+}
+
+#[cfg(all(test, feature = "num"))]
+mod int_tests {
+    use crate::assertions::int::IntAssertion;
+    use crate::assertions::ordered::OrderedAssertion;
+    use crate::assertions::string::numeric::NumericAssertion;
+
+    #[test]
+    fn should_parse_as_and_continue_with_int_assertions() {
+        let value = "42";
+        value.should_parse_as::<i32>().should_be_even().should_be_greater_than(&10);
+    }
+}
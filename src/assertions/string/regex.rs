@@ -1,9 +1,29 @@
-use regex::Regex;
+use regex::{Captures, Regex};
 
-use crate::matchers::{Should, ShouldNot};
-use crate::matchers::string::regex::match_with;
+use crate::matchers::{Matcher, Should, ShouldNot};
+use crate::matchers::string::regex::{
+    have_all_matches_satisfying, have_capture, have_match_count, match_fully_with, match_with, CaptureGroup,
+};
 
-/// RegularExpressionAssertion enables assertions about whether a string (or str) matches a regular expression.
+/// RegularExpressionAssertion enables assertions about whether a string (or str) matches a regular expression,
+/// and about the text captured by its groups.
+///
+/// # Example
+/// ```
+/// use regex::Regex;
+/// use clearcheck::assertions::equal::EqualityAssertion;
+/// use clearcheck::assertions::string::regex::RegularExpressionAssertion;
+/// use clearcheck::matchers::string::boundary::begin_with;
+///
+/// let regex = Regex::new(r"(?<year>\d{4})-(?<month>\d{2})-(?<day>\d{2})").unwrap();
+/// let phrase = "Started clearcheck on 2024-01-02.";
+/// phrase
+///     .should_have_capture(regex.clone(), "year", begin_with("20"))
+///     .should_have_match_count(regex.clone(), 1);
+///
+/// let captures = phrase.should_capture(regex);
+/// (&captures["month"]).should_equal(&"01");
+/// ```
 pub trait RegularExpressionAssertion {
     /// - Asserts that the string matches the given regular expression.
     /// - Returns a reference to self for fluent chaining.
@@ -32,10 +52,102 @@ pub trait RegularExpressionAssertion {
     /// phrase.should_not_match(regex);
     /// ```
     fn should_not_match(&self, regex: Regex) -> &Self;
+
+    /// - Asserts that the whole string (not just a part of it) matches the given regular expression.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use regex::Regex;
+    /// use clearcheck::assertions::string::regex::RegularExpressionAssertion;
+    ///
+    /// let regex = Regex::new(r"\d{4}-\d{2}-\d{2}").unwrap();
+    /// let date = "2024-01-02";
+    /// date.should_match_fully(regex);
+    /// ```
+    fn should_match_fully(&self, regex: Regex) -> &Self;
+
+    /// - Asserts that the whole string does not match the given regular expression, though a part of it may.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use regex::Regex;
+    /// use clearcheck::assertions::string::regex::RegularExpressionAssertion;
+    ///
+    /// let regex = Regex::new(r"\d{4}-\d{2}-\d{2}").unwrap();
+    /// let phrase = "Started clearcheck on 2024-01-02.";
+    /// phrase.should_not_match_fully(regex);
+    /// ```
+    fn should_not_match_fully(&self, regex: Regex) -> &Self;
+
+    /// - Asserts that the given capture group (index or name) of the first match of the regular expression satisfies the given string matcher.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the capture group and its captured text if the assertion fails.
+    /// # Example
+    /// ```
+    /// use regex::Regex;
+    /// use clearcheck::assertions::string::regex::RegularExpressionAssertion;
+    /// use clearcheck::matchers::string::boundary::begin_with;
+    ///
+    /// let regex = Regex::new(r"(?<year>\d{4})-(\d{2})-(\d{2})").unwrap();
+    /// let phrase = "Started clearcheck on 2024-01-02.";
+    /// phrase.should_have_capture(regex.clone(), "year", begin_with("20"));
+    /// phrase.should_have_capture(regex, 2, begin_with("0"));
+    /// ```
+    fn should_have_capture<G, M>(&self, regex: Regex, group: G, matcher: M) -> &Self
+        where G: Into<CaptureGroup>,
+              M: Matcher<str>;
+
+    /// - Asserts that the string has the given number of non-overlapping matches of the regular expression.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the matches if the assertion fails.
+    /// # Example
+    /// ```
+    /// use regex::Regex;
+    /// use clearcheck::assertions::string::regex::RegularExpressionAssertion;
+    ///
+    /// let regex = Regex::new(r"\d+").unwrap();
+    /// let phrase = "1, 22 and 333";
+    /// phrase.should_have_match_count(regex, 3);
+    /// ```
+    fn should_have_match_count(&self, regex: Regex, count: usize) -> &Self;
+
+    /// - Asserts that the string has at least one match of the regular expression, and every (non-overlapping) match satisfies the given string matcher.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics with the first match that does not satisfy the matcher if the assertion fails.
+    /// # Example
+    /// ```
+    /// use regex::Regex;
+    /// use clearcheck::assertions::string::regex::RegularExpressionAssertion;
+    /// use clearcheck::matchers::string::length::have_atleast_same_length;
+    ///
+    /// let regex = Regex::new(r"\d+").unwrap();
+    /// let phrase = "10, 22 and 333";
+    /// phrase.should_have_all_matches_satisfying(regex, have_atleast_same_length(2));
+    /// ```
+    fn should_have_all_matches_satisfying<M: Matcher<str>>(&self, regex: Regex, matcher: M) -> &Self;
+
+    /// - Asserts that the string matches the given regular expression.
+    /// - Returns the captures of the first match for further assertions.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use regex::Regex;
+    /// use clearcheck::assertions::equal::EqualityAssertion;
+    /// use clearcheck::assertions::string::regex::RegularExpressionAssertion;
+    ///
+    /// let regex = Regex::new(r"(?<year>\d{4})-(\d{2})-(\d{2})").unwrap();
+    /// let phrase = "Started clearcheck on 2024-01-02.";
+    /// let captures = phrase.should_capture(regex);
+    /// (&captures["year"]).should_equal(&"2024");
+    /// (&captures[3]).should_equal(&"02");
+    /// ```
+    fn should_capture(&self, regex: Regex) -> Captures<'_>;
 }
 
 impl<T> RegularExpressionAssertion for T
-    where T: AsRef<str> {
+    where T: AsRef<str> + ?Sized {
     fn should_match(&self, regex: Regex) -> &Self {
         self.should(&match_with(regex));
         self
@@ -45,6 +157,38 @@ impl<T> RegularExpressionAssertion for T
         self.should_not(&match_with(regex));
         self
     }
+
+    fn should_match_fully(&self, regex: Regex) -> &Self {
+        self.should(&match_fully_with(regex));
+        self
+    }
+
+    fn should_not_match_fully(&self, regex: Regex) -> &Self {
+        self.should_not(&match_fully_with(regex));
+        self
+    }
+
+    fn should_have_capture<G, M>(&self, regex: Regex, group: G, matcher: M) -> &Self
+        where G: Into<CaptureGroup>,
+              M: Matcher<str> {
+        self.should(&have_capture(regex, group, matcher));
+        self
+    }
+
+    fn should_have_match_count(&self, regex: Regex, count: usize) -> &Self {
+        self.should(&have_match_count(regex, count));
+        self
+    }
+
+    fn should_have_all_matches_satisfying<M: Matcher<str>>(&self, regex: Regex, matcher: M) -> &Self {
+        self.should(&have_all_matches_satisfying(regex, matcher));
+        self
+    }
+
+    fn should_capture(&self, regex: Regex) -> Captures<'_> {
+        self.should(&match_with(regex.clone()));
+        regex.captures(self.as_ref()).unwrap()
+    }
 }
 
 #[cfg(all(test, feature = "regex"))]
@@ -120,3 +264,114 @@ mod string_tests {
         str.should_not_match(regex);
     }
 }
+
+#[cfg(all(test, feature = "regex"))]
+mod capture_tests {
+    use regex::Regex;
+
+    use crate::assertions::equal::EqualityAssertion;
+    use crate::assertions::string::regex::RegularExpressionAssertion;
+    use crate::matchers::string::boundary::begin_with;
+    use crate::matchers::string::length::have_atleast_same_length;
+
+    #[test]
+    fn should_match_fully() {
+        let regex = Regex::new(r"\d{4}-\d{2}-\d{2}").unwrap();
+        let date = "2024-01-02";
+        date.should_match_fully(regex);
+    }
+
+    #[test]
+    #[should_panic(expected = "should fully match the regular expression")]
+    fn should_match_fully_but_it_did_not() {
+        let regex = Regex::new(r"\d{4}-\d{2}-\d{2}").unwrap();
+        let phrase = "On 2024-01-02";
+        phrase.should_match_fully(regex);
+    }
+
+    #[test]
+    fn should_not_match_fully() {
+        let regex = Regex::new(r"\d{4}-\d{2}-\d{2}").unwrap();
+        let phrase = String::from("On 2024-01-02");
+        phrase.should_not_match_fully(regex);
+    }
+
+    #[test]
+    #[should_panic(expected = "should not fully match the regular expression")]
+    fn should_not_match_fully_but_it_did() {
+        let regex = Regex::new(r"\d{4}-\d{2}-\d{2}").unwrap();
+        let date = "2024-01-02";
+        date.should_not_match_fully(regex);
+    }
+
+    #[test]
+    #[should_panic(expected = "should not fully match the regular expression")]
+    fn should_not_match_fully_but_a_later_alternative_did() {
+        let regex = Regex::new(r"a|ab").unwrap();
+        "ab".should_not_match_fully(regex);
+    }
+
+    #[test]
+    fn should_have_capture() {
+        let regex = Regex::new(r"(?<year>\d{4})-(\d{2})-(\d{2})").unwrap();
+        let phrase = "Started clearcheck on 2024-01-02.";
+        phrase
+            .should_have_capture(regex.clone(), "year", begin_with("20"))
+            .should_have_capture(regex, 2, begin_with("0"));
+    }
+
+    #[test]
+    #[should_panic(expected = "group \"year\" of Regex(\"(?<year>\\\\d{4})-(\\\\d{2})\") captured \"2024\" which did not match")]
+    fn should_have_capture_but_it_did_not() {
+        let regex = Regex::new(r"(?<year>\d{4})-(\d{2})").unwrap();
+        let phrase = "Started clearcheck on 2024-01.";
+        phrase.should_have_capture(regex, "year", begin_with("19"));
+    }
+
+    #[test]
+    fn should_have_match_count() {
+        let regex = Regex::new(r"\d+").unwrap();
+        let phrase = "1, 22 and 333";
+        phrase.should_have_match_count(regex, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "should have 2 matches of the regular expression")]
+    fn should_have_match_count_but_it_did_not() {
+        let regex = Regex::new(r"\d+").unwrap();
+        let phrase = "1, 22 and 333";
+        phrase.should_have_match_count(regex, 2);
+    }
+
+    #[test]
+    fn should_have_all_matches_satisfying() {
+        let regex = Regex::new(r"\d+").unwrap();
+        let phrase = "10, 22 and 333";
+        phrase.should_have_all_matches_satisfying(regex, have_atleast_same_length(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "but match 2 \"3\" did not")]
+    fn should_have_all_matches_satisfying_but_it_did_not() {
+        let regex = Regex::new(r"\d+").unwrap();
+        let phrase = "10, 22 and 3";
+        phrase.should_have_all_matches_satisfying(regex, have_atleast_same_length(2));
+    }
+
+    #[test]
+    fn should_capture() {
+        let regex = Regex::new(r"(?<year>\d{4})-(\d{2})-(\d{2})").unwrap();
+        let phrase = String::from("Started clearcheck on 2024-01-02.");
+        let captures = phrase.should_capture(regex);
+        (&captures["year"]).should_equal(&"2024");
+        (&captures[2]).should_equal(&"01");
+    }
+
+    #[test]
+    #[should_panic(expected = "should match the regular expression")]
+    fn should_capture_but_it_did_not() {
+        let regex = Regex::new(r"(?<year>\d{4})-(\d{2})-(\d{2})").unwrap();
+        let phrase = "Started clearcheck on 02nd January 2024";
+        phrase.should_capture(regex);
+    }
+}
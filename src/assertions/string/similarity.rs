@@ -0,0 +1,146 @@
+use crate::matchers::{Should, ShouldNot};
+use crate::matchers::string::similarity::{be_similar_to, have_similarity_at_least};
+
+/// SimilarityAssertion enables assertions about how close a string (or str) value is to an expected string.
+///
+/// Useful when the exact value is not stable, like generated names or OCR output, but should stay close to the expected value.
+///
+/// # Example
+/// ```
+/// use clearcheck::assertions::string::similarity::SimilarityAssertion;
+///
+/// let name = "clearchek";
+/// name
+///     .should_be_similar_to("clearcheck", 1)
+///     .should_have_similarity_at_least("clearcheck", 0.9);
+/// ```
+pub trait SimilarityAssertion {
+    /// - Asserts that the string is within the given Levenshtein edit distance (single character insertions, deletions or substitutions) of the expected string.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::similarity::SimilarityAssertion;
+    ///
+    /// let name = "clearchek";
+    /// name.should_be_similar_to("clearcheck", 1);
+    /// ```
+    fn should_be_similar_to(&self, expected: &str, max_edit_distance: usize) -> &Self;
+
+    /// - Asserts that the string is not within the given Levenshtein edit distance of the expected string.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::similarity::SimilarityAssertion;
+    ///
+    /// let name = "assert4j";
+    /// name.should_not_be_similar_to("clearcheck", 2);
+    /// ```
+    fn should_not_be_similar_to(&self, expected: &str, max_edit_distance: usize) -> &Self;
+
+    /// - Asserts that the Jaro-Winkler similarity between the string and the expected string is at least the given ratio (between 0.0 and 1.0).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::similarity::SimilarityAssertion;
+    ///
+    /// let name = "marhta";
+    /// name.should_have_similarity_at_least("martha", 0.95);
+    /// ```
+    fn should_have_similarity_at_least(&self, expected: &str, ratio: f64) -> &Self;
+
+    /// - Asserts that the Jaro-Winkler similarity between the string and the expected string is below the given ratio (between 0.0 and 1.0).
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::string::similarity::SimilarityAssertion;
+    ///
+    /// let name = "assert4j";
+    /// name.should_not_have_similarity_at_least("clearcheck", 0.8);
+    /// ```
+    fn should_not_have_similarity_at_least(&self, expected: &str, ratio: f64) -> &Self;
+}
+
+impl<T> SimilarityAssertion for T
+    where T: AsRef<str> + ?Sized
+{
+    fn should_be_similar_to(&self, expected: &str, max_edit_distance: usize) -> &Self {
+        self.should(&be_similar_to(expected, max_edit_distance));
+        self
+    }
+
+    fn should_not_be_similar_to(&self, expected: &str, max_edit_distance: usize) -> &Self {
+        self.should_not(&be_similar_to(expected, max_edit_distance));
+        self
+    }
+
+    fn should_have_similarity_at_least(&self, expected: &str, ratio: f64) -> &Self {
+        self.should(&have_similarity_at_least(expected, ratio));
+        self
+    }
+
+    fn should_not_have_similarity_at_least(&self, expected: &str, ratio: f64) -> &Self {
+        self.should_not(&have_similarity_at_least(expected, ratio));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::string::similarity::SimilarityAssertion;
+
+    #[test]
+    fn should_be_similar_to() {
+        let name = "clearchek";
+        name.should_be_similar_to("clearcheck", 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "should be within edit distance 1 of \"clearcheck\" but was 2")]
+    fn should_be_similar_to_but_was_not() {
+        let name = "clearchk";
+        name.should_be_similar_to("clearcheck", 1);
+    }
+
+    #[test]
+    fn should_not_be_similar_to() {
+        let name = String::from("assert4j");
+        name.should_not_be_similar_to("clearcheck", 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_similar_to_but_was() {
+        let name = "clearcheck";
+        name.should_not_be_similar_to("clearcheck", 0);
+    }
+
+    #[test]
+    fn should_have_similarity_at_least() {
+        let name = "marhta";
+        name.should_have_similarity_at_least("martha", 0.95);
+    }
+
+    #[test]
+    #[should_panic(expected = "should have similarity at least 0.99 with \"martha\" but was 0.96")]
+    fn should_have_similarity_at_least_but_did_not() {
+        let name = "marhta";
+        name.should_have_similarity_at_least("martha", 0.99);
+    }
+
+    #[test]
+    fn should_not_have_similarity_at_least() {
+        let name = "assert4j";
+        name.should_not_have_similarity_at_least("clearcheck", 0.8);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_have_similarity_at_least_but_did() {
+        let name = "marhta";
+        name.should_not_have_similarity_at_least("martha", 0.9);
+    }
+}
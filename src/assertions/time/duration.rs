@@ -0,0 +1,184 @@
+use std::time::Duration;
+
+use crate::matchers::{Should, ShouldNot};
+use crate::matchers::time::duration::{be_at_most, be_close_to, be_zero_duration};
+
+/// DurationAssertion enables assertions about [`Duration`] values.
+///
+/// The failure messages carry the durations in a human-readable form, like `1s 250ms`.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use clearcheck::assertions::time::duration::DurationAssertion;
+///
+/// let duration = Duration::from_millis(1005);
+/// duration
+///     .should_be_close_to(Duration::from_secs(1), Duration::from_millis(10))
+///     .should_be_at_most_millis(1100);
+/// ```
+pub trait DurationAssertion {
+    /// - Asserts that the duration is within the tolerance of the expected duration.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use clearcheck::assertions::time::duration::DurationAssertion;
+    ///
+    /// let duration = Duration::from_millis(995);
+    /// duration.should_be_close_to(Duration::from_secs(1), Duration::from_millis(10));
+    /// ```
+    fn should_be_close_to(&self, expected: Duration, tolerance: Duration) -> &Self;
+
+    /// - Asserts that the duration is not within the tolerance of the expected duration.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use clearcheck::assertions::time::duration::DurationAssertion;
+    ///
+    /// let duration = Duration::from_millis(1200);
+    /// duration.should_not_be_close_to(Duration::from_secs(1), Duration::from_millis(10));
+    /// ```
+    fn should_not_be_close_to(&self, expected: Duration, tolerance: Duration) -> &Self;
+
+    /// - Asserts that the duration is less than or equal to the given number of milliseconds.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use clearcheck::assertions::time::duration::DurationAssertion;
+    ///
+    /// let duration = Duration::from_millis(40);
+    /// duration.should_be_at_most_millis(50);
+    /// ```
+    fn should_be_at_most_millis(&self, millis: u64) -> &Self;
+
+    /// - Asserts that the duration is zero.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use clearcheck::assertions::time::duration::DurationAssertion;
+    ///
+    /// let duration = Duration::ZERO;
+    /// duration.should_be_zero_duration();
+    /// ```
+    fn should_be_zero_duration(&self) -> &Self;
+
+    /// - Asserts that the duration is not zero.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use clearcheck::assertions::time::duration::DurationAssertion;
+    ///
+    /// let duration = Duration::from_nanos(1);
+    /// duration.should_not_be_zero_duration();
+    /// ```
+    fn should_not_be_zero_duration(&self) -> &Self;
+}
+
+impl DurationAssertion for Duration {
+    fn should_be_close_to(&self, expected: Duration, tolerance: Duration) -> &Self {
+        self.should(&be_close_to(expected, tolerance));
+        self
+    }
+
+    fn should_not_be_close_to(&self, expected: Duration, tolerance: Duration) -> &Self {
+        self.should_not(&be_close_to(expected, tolerance));
+        self
+    }
+
+    fn should_be_at_most_millis(&self, millis: u64) -> &Self {
+        self.should(&be_at_most(Duration::from_millis(millis)));
+        self
+    }
+
+    fn should_be_zero_duration(&self) -> &Self {
+        self.should(&be_zero_duration());
+        self
+    }
+
+    fn should_not_be_zero_duration(&self) -> &Self {
+        self.should_not(&be_zero_duration());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::assertions::time::duration::DurationAssertion;
+
+    #[test]
+    fn should_be_close_to() {
+        let duration = Duration::from_millis(1005);
+        duration.should_be_close_to(Duration::from_secs(1), Duration::from_millis(10));
+    }
+
+    #[test]
+    #[should_panic(expected = "1s 20ms should be close to 1s within 10ms but differed by 20ms")]
+    fn should_be_close_to_but_was_not() {
+        let duration = Duration::from_millis(1020);
+        duration.should_be_close_to(Duration::from_secs(1), Duration::from_millis(10));
+    }
+
+    #[test]
+    fn should_not_be_close_to() {
+        let duration = Duration::from_millis(1020);
+        duration.should_not_be_close_to(Duration::from_secs(1), Duration::from_millis(10));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_close_to_but_was() {
+        let duration = Duration::from_millis(1005);
+        duration.should_not_be_close_to(Duration::from_secs(1), Duration::from_millis(10));
+    }
+
+    #[test]
+    fn should_be_at_most_millis() {
+        let duration = Duration::from_millis(50);
+        duration.should_be_at_most_millis(50);
+    }
+
+    #[test]
+    #[should_panic(expected = "2m 5s should be at most 50ms")]
+    fn should_be_at_most_millis_but_was_not() {
+        let duration = Duration::from_secs(125);
+        duration.should_be_at_most_millis(50);
+    }
+
+    #[test]
+    fn should_be_zero_duration() {
+        let duration = Duration::ZERO;
+        duration.should_be_zero_duration();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_zero_duration_but_was_not() {
+        let duration = Duration::from_micros(1);
+        duration.should_be_zero_duration();
+    }
+
+    #[test]
+    fn should_not_be_zero_duration() {
+        let duration = Duration::from_micros(1);
+        duration.should_not_be_zero_duration();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_zero_duration_but_was() {
+        let duration = Duration::ZERO;
+        duration.should_not_be_zero_duration();
+    }
+}
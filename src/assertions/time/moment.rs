@@ -0,0 +1,288 @@
+use std::time::Duration;
+
+use crate::matchers::{Should, ShouldNot};
+use crate::matchers::time::{Clock, Moment, SystemClock};
+use crate::matchers::time::moment::{
+    be_after, be_before, be_in_the_future, be_in_the_past, be_within,
+};
+
+/// MomentAssertion enables assertions about points in time, [`std::time::SystemTime`] and [`std::time::Instant`].
+///
+/// The assertions relative to "now" have a variant which accepts a [`Clock`], which allows injecting a fixed clock for determinism.
+///
+/// # Example
+/// ```
+/// use std::time::{Duration, SystemTime};
+/// use clearcheck::assertions::time::moment::MomentAssertion;
+///
+/// let created_at = SystemTime::now() - Duration::from_secs(5);
+/// created_at
+///     .should_be_in_the_past()
+///     .should_be_within(Duration::from_secs(60), SystemTime::now());
+/// ```
+pub trait MomentAssertion<T: Moment> {
+    /// - Asserts that the point in time is before the other.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use std::time::{Duration, SystemTime};
+    /// use clearcheck::assertions::time::moment::MomentAssertion;
+    ///
+    /// let now = SystemTime::now();
+    /// now.should_be_before(now + Duration::from_secs(1));
+    /// ```
+    fn should_be_before(&self, other: T) -> &Self;
+
+    /// - Asserts that the point in time is after the other.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use std::time::{Duration, Instant};
+    /// use clearcheck::assertions::time::moment::MomentAssertion;
+    ///
+    /// let now = Instant::now();
+    /// (now + Duration::from_secs(1)).should_be_after(now);
+    /// ```
+    fn should_be_after(&self, other: T) -> &Self;
+
+    /// - Asserts that the point in time is within the given duration (on either side) of the other.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use std::time::{Duration, SystemTime};
+    /// use clearcheck::assertions::time::moment::MomentAssertion;
+    ///
+    /// let now = SystemTime::now();
+    /// now.should_be_within(Duration::from_secs(2), now + Duration::from_secs(1));
+    /// ```
+    fn should_be_within(&self, duration: Duration, of: T) -> &Self;
+
+    /// - Asserts that the point in time is not within the given duration (on either side) of the other.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use std::time::{Duration, SystemTime};
+    /// use clearcheck::assertions::time::moment::MomentAssertion;
+    ///
+    /// let now = SystemTime::now();
+    /// now.should_not_be_within(Duration::from_secs(2), now + Duration::from_secs(5));
+    /// ```
+    fn should_not_be_within(&self, duration: Duration, of: T) -> &Self;
+
+    /// - Asserts that the point in time is before the current point in time of the system.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use std::time::{Duration, SystemTime};
+    /// use clearcheck::assertions::time::moment::MomentAssertion;
+    ///
+    /// let created_at = SystemTime::now() - Duration::from_secs(5);
+    /// created_at.should_be_in_the_past();
+    /// ```
+    fn should_be_in_the_past(&self) -> &Self;
+
+    /// - Asserts that the point in time is after the current point in time of the system.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use std::time::{Duration, SystemTime};
+    /// use clearcheck::assertions::time::moment::MomentAssertion;
+    ///
+    /// let expires_at = SystemTime::now() + Duration::from_secs(60);
+    /// expires_at.should_be_in_the_future();
+    /// ```
+    fn should_be_in_the_future(&self) -> &Self;
+
+    /// - Asserts that the point in time is before the current point in time of the given clock.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use std::time::{Duration, SystemTime};
+    /// use clearcheck::assertions::time::moment::MomentAssertion;
+    /// use clearcheck::matchers::time::FixedClock;
+    ///
+    /// let clock = FixedClock::new(SystemTime::UNIX_EPOCH + Duration::from_secs(100));
+    /// let created_at = SystemTime::UNIX_EPOCH + Duration::from_secs(50);
+    ///
+    /// created_at.should_be_in_the_past_with_clock(&clock);
+    /// ```
+    fn should_be_in_the_past_with_clock(&self, clock: &dyn Clock<T>) -> &Self;
+
+    /// - Asserts that the point in time is after the current point in time of the given clock.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use std::time::{Duration, SystemTime};
+    /// use clearcheck::assertions::time::moment::MomentAssertion;
+    /// use clearcheck::matchers::time::FixedClock;
+    ///
+    /// let clock = FixedClock::new(SystemTime::UNIX_EPOCH + Duration::from_secs(100));
+    /// let expires_at = SystemTime::UNIX_EPOCH + Duration::from_secs(150);
+    ///
+    /// expires_at.should_be_in_the_future_with_clock(&clock);
+    /// ```
+    fn should_be_in_the_future_with_clock(&self, clock: &dyn Clock<T>) -> &Self;
+}
+
+impl<T: Moment> MomentAssertion<T> for T {
+    fn should_be_before(&self, other: T) -> &Self {
+        self.should(&be_before(other));
+        self
+    }
+
+    fn should_be_after(&self, other: T) -> &Self {
+        self.should(&be_after(other));
+        self
+    }
+
+    fn should_be_within(&self, duration: Duration, of: T) -> &Self {
+        self.should(&be_within(duration, of));
+        self
+    }
+
+    fn should_not_be_within(&self, duration: Duration, of: T) -> &Self {
+        self.should_not(&be_within(duration, of));
+        self
+    }
+
+    fn should_be_in_the_past(&self) -> &Self {
+        self.should_be_in_the_past_with_clock(&SystemClock)
+    }
+
+    fn should_be_in_the_future(&self) -> &Self {
+        self.should_be_in_the_future_with_clock(&SystemClock)
+    }
+
+    fn should_be_in_the_past_with_clock(&self, clock: &dyn Clock<T>) -> &Self {
+        self.should(&be_in_the_past(clock.now()));
+        self
+    }
+
+    fn should_be_in_the_future_with_clock(&self, clock: &dyn Clock<T>) -> &Self {
+        self.should(&be_in_the_future(clock.now()));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant, SystemTime};
+
+    use crate::assertions::time::moment::MomentAssertion;
+    use crate::matchers::time::FixedClock;
+
+    #[test]
+    fn should_be_before() {
+        let now = SystemTime::now();
+        now.should_be_before(now + Duration::from_secs(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_before_but_was_not() {
+        let now = SystemTime::now();
+        now.should_be_before(now - Duration::from_secs(1));
+    }
+
+    #[test]
+    fn should_be_after() {
+        let now = Instant::now();
+        (now + Duration::from_secs(1)).should_be_after(now);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_after_but_was_not() {
+        let now = Instant::now();
+        now.should_be_after(now + Duration::from_secs(1));
+    }
+
+    #[test]
+    fn should_be_within() {
+        let now = SystemTime::now();
+        now.should_be_within(Duration::from_secs(2), now - Duration::from_secs(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "but was 3s before")]
+    fn should_be_within_but_was_not() {
+        let now = SystemTime::now();
+        now.should_be_within(Duration::from_secs(2), now + Duration::from_secs(3));
+    }
+
+    #[test]
+    fn should_not_be_within() {
+        let now = Instant::now();
+        now.should_not_be_within(Duration::from_secs(2), now + Duration::from_secs(3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_be_within_but_was() {
+        let now = Instant::now();
+        now.should_not_be_within(Duration::from_secs(2), now + Duration::from_secs(1));
+    }
+
+    #[test]
+    fn should_be_in_the_past() {
+        let created_at = SystemTime::now() - Duration::from_secs(5);
+        created_at.should_be_in_the_past();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_in_the_past_but_was_not() {
+        let created_at = SystemTime::now() + Duration::from_secs(60);
+        created_at.should_be_in_the_past();
+    }
+
+    #[test]
+    fn should_be_in_the_future() {
+        let expires_at = Instant::now() + Duration::from_secs(60);
+        expires_at.should_be_in_the_future();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_in_the_future_but_was_not() {
+        let expires_at = Instant::now() - Duration::from_millis(1);
+        expires_at.should_be_in_the_future();
+    }
+
+    #[test]
+    fn should_be_in_the_past_with_clock() {
+        let clock = FixedClock::new(SystemTime::UNIX_EPOCH + Duration::from_secs(100));
+        let created_at = SystemTime::UNIX_EPOCH + Duration::from_secs(50);
+        created_at.should_be_in_the_past_with_clock(&clock);
+    }
+
+    #[test]
+    #[should_panic(expected = "should be in the past but was 50s after now")]
+    fn should_be_in_the_past_with_clock_but_was_not() {
+        let clock = FixedClock::new(SystemTime::UNIX_EPOCH + Duration::from_secs(100));
+        let created_at = SystemTime::UNIX_EPOCH + Duration::from_secs(150);
+        created_at.should_be_in_the_past_with_clock(&clock);
+    }
+
+    #[test]
+    fn should_be_in_the_future_with_clock() {
+        let clock = FixedClock::new(SystemTime::UNIX_EPOCH + Duration::from_secs(100));
+        let expires_at = SystemTime::UNIX_EPOCH + Duration::from_secs(150);
+        expires_at.should_be_in_the_future_with_clock(&clock);
+    }
+
+    #[test]
+    #[should_panic(expected = "should be in the future but was 1m 40s before now")]
+    fn should_be_in_the_future_with_clock_but_was_not() {
+        let clock = FixedClock::new(SystemTime::UNIX_EPOCH + Duration::from_secs(100));
+        SystemTime::UNIX_EPOCH.should_be_in_the_future_with_clock(&clock);
+    }
+}
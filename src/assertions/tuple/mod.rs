@@ -0,0 +1,192 @@
+use crate::matchers::{Matcher, Should, ShouldNot};
+use crate::matchers::tuple::{have_first, have_second};
+
+/// TupleAssertion enables assertions about the first and the second elements of tuples (of arity 2 to 8).
+///
+/// It is handy for asserting on the entries of `HashMap::iter()` or on the outputs of `zip`.
+///
+/// # Example
+/// ```
+/// use std::collections::HashMap;
+/// use clearcheck::assertions::tuple::TupleAssertion;
+/// use clearcheck::matchers::ordered::be_greater_than;
+/// use clearcheck::matchers::string::boundary::begin_with;
+///
+/// let mut rank_by_library = HashMap::new();
+/// rank_by_library.insert("clearcheck", 1);
+///
+/// rank_by_library.iter().for_each(|entry| {
+///     entry
+///         .should_have_first(begin_with("clear"))
+///         .should_have_second(be_greater_than(&0));
+/// });
+/// ```
+pub trait TupleAssertion<A, B> {
+    /// - Asserts that the first element of the tuple matches the given matcher.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::tuple::TupleAssertion;
+    /// use clearcheck::matchers::string::boundary::begin_with;
+    ///
+    /// let value = ("clearcheck", 10);
+    /// value.should_have_first(begin_with("clear"));
+    /// ```
+    fn should_have_first<M: Matcher<A>>(&self, matcher: M) -> &Self;
+
+    /// - Asserts that the first element of the tuple does not match the given matcher.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::tuple::TupleAssertion;
+    /// use clearcheck::matchers::string::boundary::begin_with;
+    ///
+    /// let value = ("clearcheck", 10);
+    /// value.should_not_have_first(begin_with("junit"));
+    /// ```
+    fn should_not_have_first<M: Matcher<A>>(&self, matcher: M) -> &Self;
+
+    /// - Asserts that the second element of the tuple matches the given matcher.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::tuple::TupleAssertion;
+    /// use clearcheck::matchers::ordered::be_greater_than;
+    ///
+    /// let value = ("clearcheck", 10);
+    /// value.should_have_second(be_greater_than(5));
+    /// ```
+    fn should_have_second<M: Matcher<B>>(&self, matcher: M) -> &Self;
+
+    /// - Asserts that the second element of the tuple does not match the given matcher.
+    /// - Returns a reference to self for fluent chaining.
+    /// - Panics if the assertion fails.
+    /// # Example
+    /// ```
+    /// use clearcheck::assertions::tuple::TupleAssertion;
+    /// use clearcheck::matchers::ordered::be_greater_than;
+    ///
+    /// let value = ("clearcheck", 10);
+    /// value.should_not_have_second(be_greater_than(50));
+    /// ```
+    fn should_not_have_second<M: Matcher<B>>(&self, matcher: M) -> &Self;
+}
+
+macro_rules! tuple_assertion {
+    ($first:ident, $second:ident $(, $rest:ident)*) => {
+        impl<$first, $second $(, $rest)*> TupleAssertion<$first, $second> for ($first, $second, $($rest,)*) {
+            fn should_have_first<M: Matcher<$first>>(&self, matcher: M) -> &Self {
+                self.should(&have_first(matcher));
+                self
+            }
+
+            fn should_not_have_first<M: Matcher<$first>>(&self, matcher: M) -> &Self {
+                self.should_not(&have_first(matcher));
+                self
+            }
+
+            fn should_have_second<M: Matcher<$second>>(&self, matcher: M) -> &Self {
+                self.should(&have_second(matcher));
+                self
+            }
+
+            fn should_not_have_second<M: Matcher<$second>>(&self, matcher: M) -> &Self {
+                self.should_not(&have_second(matcher));
+                self
+            }
+        }
+    };
+}
+
+tuple_assertion!(T0, T1);
+tuple_assertion!(T0, T1, T2);
+tuple_assertion!(T0, T1, T2, T3);
+tuple_assertion!(T0, T1, T2, T3, T4);
+tuple_assertion!(T0, T1, T2, T3, T4, T5);
+tuple_assertion!(T0, T1, T2, T3, T4, T5, T6);
+tuple_assertion!(T0, T1, T2, T3, T4, T5, T6, T7);
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::tuple::TupleAssertion;
+    use crate::matchers::equal::be_equal;
+    use crate::matchers::ordered::be_greater_than;
+    use crate::matchers::Should;
+    use crate::matchers::string::boundary::begin_with;
+
+    #[test]
+    fn should_have_first() {
+        let value = ("clearcheck", 10);
+        value.should_have_first(begin_with("clear"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_first_but_did_not() {
+        let value = ("junit", 10);
+        value.should_have_first(begin_with("clear"));
+    }
+
+    #[test]
+    fn should_not_have_first() {
+        let value = ("junit", 10);
+        value.should_not_have_first(begin_with("clear"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_have_first_but_did() {
+        let value = ("clearcheck", 10);
+        value.should_not_have_first(begin_with("clear"));
+    }
+
+    #[test]
+    fn should_have_second() {
+        let value = ("clearcheck", 10, 'c');
+        value.should_have_second(be_greater_than(5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_second_but_did_not() {
+        let value = ("clearcheck", 1, 'c');
+        value.should_have_second(be_greater_than(5));
+    }
+
+    #[test]
+    fn should_not_have_second() {
+        let value = ("clearcheck", 1);
+        value.should_not_have_second(be_greater_than(5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_have_second_but_did() {
+        let value = ("clearcheck", 10);
+        value.should_not_have_second(be_greater_than(5));
+    }
+
+    #[test]
+    fn should_match_zipped_values() {
+        let libraries = ["clearcheck", "junit"];
+        let ranks = [1, 2];
+
+        libraries.iter().zip(ranks.iter()).for_each(|pair| {
+            pair.should(&(begin_with(""), be_greater_than(&0)));
+        });
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_match_zipped_values_but_did_not() {
+        let libraries = ["clearcheck", "junit"];
+        let ranks = [1, 2];
+
+        libraries.iter().zip(ranks.iter()).for_each(|pair| {
+            pair.should(&(begin_with("clear"), be_equal(&1)));
+        });
+    }
+}
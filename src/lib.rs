@@ -28,10 +28,15 @@
 //! # Rust features
 //!
 //! clearcheck crate supports the following features:
+//! - csv enables [assertions on csv files](assertions::file::csv::CsvFileAssertion) (implies file)
 //! - date enables [assertions on date](assertions::date::DateAssertion)
 //! - file enables [assertions on filepath](assertions::file::FileAssertion)
+//! - json enables [assertions on json files](assertions::file::json::JsonFileAssertion) (implies file)
 //! - num enables [assertions on float](assertions::float::FloatAssertion) and [assertions on integer](assertions::int::IntAssertion)
 //! - regex enables [regular expression assertions on string](assertions::string::regex)
+//! - toml enables [assertions on toml files](assertions::file::toml::TomlFileAssertion) (implies file)
+//! - unicode enables [grapheme count and display width assertions on string](assertions::string::length::UnicodeLengthAssertion)
+//! - url enables [url assertions on string](assertions::string::formats::StringFormatAssertion)
 //!
 //! # Assertions vs Matchers
 //!
@@ -61,6 +61,12 @@ impl<T: PartialOrd + Debug> Matcher<&[T]> for BoundMatcher<T> {
     }
 }
 
+impl<T: PartialOrd + Debug> Matcher<[T]> for BoundMatcher<T> {
+    fn test(&self, collection: &[T]) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
 /// Creates a BoundMatcher that asserts whether a value has the given upper bound.
 pub fn have_upper_bound<T: PartialOrd + Debug>(bound: T) -> BoundMatcher<T> {
     BoundMatcher::Upper(bound)
@@ -0,0 +1,349 @@
+use crate::matchers::{Matcher, MatcherResult};
+
+/// BytesMatcher offers a flexible way to assert the contents of a byte collection, like the payload of a binary protocol.
+///
+/// clearcheck implements BytesMatcher for `Vec<u8>`, `[u8; N]`, `&[u8]` and `[u8]`.
+///
+/// Failure messages show bytes in hex (instead of hundreds of decimal numbers), and Equal shows a hexdump-style
+/// side-by-side diff of the 16-byte rows that differ (expected on the left, actual on the right, `--` for a missing byte).
+///
+/// - Equal: the bytes are equal to the expected bytes.
+/// - StartWith: the bytes start with the given prefix.
+/// - ContainSequence: the bytes contain the given sequence of consecutive bytes.
+/// - ValidUtf8: the bytes are valid UTF-8.
+/// - Crc32: the CRC-32 (IEEE) checksum of the bytes is the given checksum.
+/// - Zeroed: every byte is zero.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::collection::bytes::start_with_bytes;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = start_with_bytes(&[0x89, b'P', b'N', b'G']);
+/// assert!(matcher.test(&vec![0x89, b'P', b'N', b'G', 0x0d, 0x0a]).passed());
+/// ```
+pub enum BytesMatcher {
+    Equal(Vec<u8>),
+    StartWith(Vec<u8>),
+    ContainSequence(Vec<u8>),
+    ValidUtf8,
+    Crc32(u32),
+    Zeroed,
+}
+
+const BYTES_PER_ROW: usize = 16;
+const MAX_DIFFERING_ROWS: usize = 8;
+
+impl BytesMatcher {
+    fn test(&self, bytes: &[u8]) -> MatcherResult {
+        match self {
+            BytesMatcher::Equal(expected) => MatcherResult::formatted(
+                bytes == expected.as_slice(),
+                format!(
+                    "{} should equal {}\n{}",
+                    preview(bytes),
+                    preview(expected),
+                    hexdump_diff(expected, bytes)
+                ),
+                format!("{} should not equal {}", preview(bytes), preview(expected)),
+            ),
+            BytesMatcher::StartWith(prefix) => MatcherResult::formatted(
+                bytes.starts_with(prefix),
+                format!(
+                    "{} should start with [{}] but started with [{}]",
+                    preview(bytes),
+                    hex(prefix),
+                    hex(&bytes[..prefix.len().min(bytes.len())])
+                ),
+                format!("{} should not start with [{}]", preview(bytes), hex(prefix)),
+            ),
+            BytesMatcher::ContainSequence(sequence) => {
+                let offset = find_sequence(bytes, sequence);
+                let found = offset
+                    .map(|offset| format!(" but found it at offset {:#x}", offset))
+                    .unwrap_or_default();
+                MatcherResult::formatted(
+                    offset.is_some(),
+                    format!("{} should contain the byte sequence [{}]", preview(bytes), hex(sequence)),
+                    format!(
+                        "{} should not contain the byte sequence [{}]{}",
+                        preview(bytes),
+                        hex(sequence),
+                        found
+                    ),
+                )
+            }
+            BytesMatcher::ValidUtf8 => {
+                let validation = std::str::from_utf8(bytes);
+                let failure = match &validation {
+                    Ok(_) => String::new(),
+                    Err(err) => format!(" but had an {}", err),
+                };
+                MatcherResult::formatted(
+                    validation.is_ok(),
+                    format!("{} should be valid utf-8{}", preview(bytes), failure),
+                    format!("{} should not be valid utf-8", preview(bytes)),
+                )
+            }
+            BytesMatcher::Crc32(checksum) => {
+                let actual = crc32(bytes);
+                MatcherResult::formatted(
+                    actual == *checksum,
+                    format!(
+                        "{} should have crc32 checksum {:#010x} but was {:#010x}",
+                        preview(bytes), checksum, actual
+                    ),
+                    format!("{} should not have crc32 checksum {:#010x}", preview(bytes), checksum),
+                )
+            }
+            BytesMatcher::Zeroed => {
+                let non_zero = bytes.iter().position(|byte| *byte != 0);
+                let found = non_zero
+                    .map(|offset| format!(" but found {:#04x} at offset {:#x}", bytes[offset], offset))
+                    .unwrap_or_default();
+                MatcherResult::formatted(
+                    non_zero.is_none(),
+                    format!("{} should be zeroed{}", preview(bytes), found),
+                    format!("{} should not be zeroed", preview(bytes)),
+                )
+            }
+        }
+    }
+}
+
+impl Matcher<Vec<u8>> for BytesMatcher {
+    fn test(&self, collection: &Vec<u8>) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+impl<const N: usize> Matcher<[u8; N]> for BytesMatcher {
+    fn test(&self, collection: &[u8; N]) -> MatcherResult {
+        self.test(collection as &[u8])
+    }
+}
+
+impl Matcher<&[u8]> for BytesMatcher {
+    fn test(&self, collection: &&[u8]) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+impl Matcher<[u8]> for BytesMatcher {
+    fn test(&self, collection: &[u8]) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+/// Creates a BytesMatcher that asserts whether the bytes are equal to the expected bytes.
+pub fn equal_bytes(expected: &[u8]) -> BytesMatcher {
+    BytesMatcher::Equal(expected.to_vec())
+}
+
+/// Creates a BytesMatcher that asserts whether the bytes start with the given prefix.
+pub fn start_with_bytes(prefix: &[u8]) -> BytesMatcher {
+    BytesMatcher::StartWith(prefix.to_vec())
+}
+
+/// Creates a BytesMatcher that asserts whether the bytes contain the given sequence of consecutive bytes.
+pub fn contain_byte_sequence(sequence: &[u8]) -> BytesMatcher {
+    BytesMatcher::ContainSequence(sequence.to_vec())
+}
+
+/// Creates a BytesMatcher that asserts whether the bytes are valid UTF-8.
+pub fn be_valid_utf8() -> BytesMatcher {
+    BytesMatcher::ValidUtf8
+}
+
+/// Creates a BytesMatcher that asserts whether the CRC-32 (IEEE, as used by zip, png and ethernet) checksum of the bytes is the given checksum.
+pub fn have_checksum_crc32(checksum: u32) -> BytesMatcher {
+    BytesMatcher::Crc32(checksum)
+}
+
+/// Creates a BytesMatcher that asserts whether every byte is zero.
+pub fn be_zeroed() -> BytesMatcher {
+    BytesMatcher::Zeroed
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect::<Vec<_>>().join(" ")
+}
+
+fn preview(bytes: &[u8]) -> String {
+    if bytes.len() <= BYTES_PER_ROW {
+        return format!("[{}]", hex(bytes));
+    }
+    format!("[{} ...] ({} bytes)", hex(&bytes[..BYTES_PER_ROW]), bytes.len())
+}
+
+fn find_sequence(bytes: &[u8], sequence: &[u8]) -> Option<usize> {
+    if sequence.is_empty() {
+        return Some(0);
+    }
+    bytes.windows(sequence.len()).position(|window| window == sequence)
+}
+
+fn hex_row(bytes: &[u8], offset: usize) -> String {
+    (offset..offset + BYTES_PER_ROW)
+        .map(|index| bytes.get(index).map_or("--".to_string(), |byte| format!("{:02x}", byte)))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn hexdump_diff(expected: &[u8], actual: &[u8]) -> String {
+    let length = expected.len().max(actual.len());
+    let differing_rows: Vec<usize> = (0..length)
+        .step_by(BYTES_PER_ROW)
+        .filter(|offset| {
+            let end = offset + BYTES_PER_ROW;
+            expected.get(*offset..end.min(expected.len())) != actual.get(*offset..end.min(actual.len()))
+        })
+        .collect();
+
+    let mut diff = format!(
+        "{:<8}  {:<47}   {}\n",
+        "offset", "expected", "actual"
+    );
+    for offset in differing_rows.iter().take(MAX_DIFFERING_ROWS) {
+        diff.push_str(&format!(
+            "{:08x}  {} | {}\n",
+            offset,
+            hex_row(expected, *offset),
+            hex_row(actual, *offset)
+        ));
+    }
+    if differing_rows.len() > MAX_DIFFERING_ROWS {
+        diff.push_str(&format!("... {} more differing rows\n", differing_rows.len() - MAX_DIFFERING_ROWS));
+    }
+    diff
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xffff_ffffu32;
+    for byte in bytes {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::string::membership::MembershipAssertion;
+    use crate::matchers::collection::bytes::{be_valid_utf8, be_zeroed, contain_byte_sequence, crc32, equal_bytes, have_checksum_crc32, start_with_bytes};
+
+    #[test]
+    fn should_compute_crc32() {
+        (crc32(b"123456789") == 0xcbf4_3926).should_be_true();
+        (crc32(b"") == 0).should_be_true();
+    }
+
+    #[test]
+    fn should_equal_bytes() {
+        let matcher = equal_bytes(b"clearcheck");
+        matcher.test(b"clearcheck").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_equal_bytes_with_a_hexdump_diff() {
+        let expected: Vec<u8> = (0..40).collect();
+        let mut actual = expected.clone();
+        actual[18] = 0xff;
+        actual.truncate(36);
+
+        let matcher = equal_bytes(&expected);
+        let result = matcher.test(&actual);
+        result.passed.should_be_false();
+        result.failure_message.should_contain("[00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f ...] (36 bytes) should equal");
+        result.failure_message.should_contain(
+            "00000010  10 11 12 13 14 15 16 17 18 19 1a 1b 1c 1d 1e 1f | 10 11 ff 13 14 15 16 17 18 19 1a 1b 1c 1d 1e 1f\n\
+             00000020  20 21 22 23 24 25 26 27 -- -- -- -- -- -- -- -- | 20 21 22 23 -- -- -- -- -- -- -- -- -- -- -- --\n"
+        );
+    }
+
+    #[test]
+    fn should_not_show_equal_rows_in_the_hexdump_diff() {
+        let expected: Vec<u8> = (0..32).collect();
+        let mut actual = expected.clone();
+        actual[31] = 0;
+
+        let matcher = equal_bytes(&expected);
+        let result = matcher.test(&actual);
+        result.failure_message.contains("00000000  ").should_be_false();
+    }
+
+    #[test]
+    fn should_start_with_bytes() {
+        let matcher = start_with_bytes(&[0x89, b'P', b'N', b'G']);
+        matcher.test(&[0x89, b'P', b'N', b'G', 0x0d, 0x0a]).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_start_with_bytes() {
+        let matcher = start_with_bytes(&[0x89, b'P', b'N', b'G']);
+        let result = matcher.test(&[0xff, 0xd8, 0xff]);
+        result.passed.should_be_false();
+        result.failure_message.should_contain("[ff d8 ff] should start with [89 50 4e 47] but started with [ff d8 ff]");
+    }
+
+    #[test]
+    fn should_contain_byte_sequence() {
+        let matcher = contain_byte_sequence(&[0x0d, 0x0a]);
+        matcher.test(b"HTTP/1.1 200 OK\r\n").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_contain_byte_sequence() {
+        let matcher = contain_byte_sequence(&[0x0d, 0x0a]);
+        let result = matcher.test(b"HTTP/1.1 200 OK\r\n");
+        result.inverted_failure_message.should_contain("should not contain the byte sequence [0d 0a] but found it at offset 0xf");
+    }
+
+    #[test]
+    fn should_be_valid_utf8() {
+        let matcher = be_valid_utf8();
+        matcher.test("héllo".as_bytes()).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_valid_utf8() {
+        let matcher = be_valid_utf8();
+        let result = matcher.test(&[b'h', 0xc3, b'l']);
+        result.passed.should_be_false();
+        result.failure_message.should_contain("[68 c3 6c] should be valid utf-8 but had an invalid utf-8 sequence of 1 bytes from index 1");
+    }
+
+    #[test]
+    fn should_have_checksum_crc32() {
+        let matcher = have_checksum_crc32(0xcbf4_3926);
+        matcher.test(b"123456789").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_have_checksum_crc32() {
+        let matcher = have_checksum_crc32(0x1234_5678);
+        let result = matcher.test(b"123456789");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should have crc32 checksum 0x12345678 but was 0xcbf43926");
+    }
+
+    #[test]
+    fn should_be_zeroed() {
+        let matcher = be_zeroed();
+        matcher.test(&[0u8; 64]).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_zeroed() {
+        let mut bytes = vec![0u8; 64];
+        bytes[40] = 1;
+        let matcher = be_zeroed();
+        let result = matcher.test(&bytes);
+        result.passed.should_be_false();
+        result.failure_message.should_contain("(64 bytes) should be zeroed but found 0x01 at offset 0x28");
+    }
+}
@@ -0,0 +1,279 @@
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::ops::{RangeInclusive, Sub};
+
+use chrono::{Datelike, TimeDelta};
+
+use crate::matchers::{Matcher, MatcherResult};
+use crate::matchers::date::relative::describe_distance;
+
+/// DateCollectionMatcher offers a flexible way to make assertions about time series, collections of dates.
+///
+/// Works with chrono types like `NaiveDate`, `NaiveDateTime` and `DateTime<Tz>`.
+/// The gap and spacing variants compare consecutive elements, so they are meant for chronologically ordered collections.
+///
+/// clearcheck implements DateCollectionMatcher for collection types including vector, arrays and reference to slices.
+///
+/// # Example
+///```
+/// use chrono::{NaiveDate, TimeDelta};
+/// use clearcheck::matchers::collection::date::have_no_gaps_greater_than;
+/// use clearcheck::matchers::Matcher;
+///
+/// let collection = vec![
+///     NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+///     NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
+///     NaiveDate::from_ymd_opt(2024, 1, 4).unwrap(),
+/// ];
+/// let matcher = have_no_gaps_greater_than(TimeDelta::days(2));
+///
+/// assert!(matcher.test(&collection).passed());
+/// ```
+pub enum DateCollectionMatcher<T> {
+    NoGapsGreaterThan(TimeDelta),
+    EvenlySpaced(TimeDelta),
+    AllWithin(RangeInclusive<T>),
+    NoDuplicateDays,
+}
+
+impl<T> DateCollectionMatcher<T>
+    where
+        T: Datelike + Clone + PartialOrd + Debug + Sub<Output=TimeDelta>,
+{
+    fn test(&self, collection: &[T]) -> MatcherResult {
+        match self {
+            DateCollectionMatcher::NoGapsGreaterThan(gap) => {
+                let offending = collection
+                    .windows(2)
+                    .find(|window| window[1].clone() - window[0].clone() > *gap);
+                MatcherResult::formatted(
+                    offending.is_none(),
+                    format!(
+                        "{:?} should have no gaps greater than {} but {}",
+                        collection,
+                        describe_distance(*gap),
+                        describe_gap(offending)
+                    ),
+                    format!(
+                        "{:?} should have a gap greater than {}",
+                        collection,
+                        describe_distance(*gap)
+                    ),
+                )
+            }
+            DateCollectionMatcher::EvenlySpaced(step) => {
+                let offending = collection
+                    .windows(2)
+                    .find(|window| window[1].clone() - window[0].clone() != *step);
+                MatcherResult::formatted(
+                    offending.is_none(),
+                    format!(
+                        "{:?} should be evenly spaced by {} but {}",
+                        collection,
+                        describe_distance(*step),
+                        describe_gap(offending)
+                    ),
+                    format!(
+                        "{:?} should not be evenly spaced by {}",
+                        collection,
+                        describe_distance(*step)
+                    ),
+                )
+            }
+            DateCollectionMatcher::AllWithin(range) => {
+                let outside = collection
+                    .iter()
+                    .filter(|element| !range.contains(element))
+                    .collect::<Vec<_>>();
+                MatcherResult::formatted(
+                    outside.is_empty(),
+                    format!(
+                        "{:?} should all fall within {:?} but {:?} did not",
+                        collection, range, outside
+                    ),
+                    format!("{:?} should not all fall within {:?}", collection, range),
+                )
+            }
+            DateCollectionMatcher::NoDuplicateDays => {
+                let mut days = HashSet::new();
+                let duplicate = collection
+                    .iter()
+                    .find(|element| !days.insert((element.year(), element.ordinal())));
+                MatcherResult::formatted(
+                    duplicate.is_none(),
+                    format!(
+                        "{:?} should have no duplicate days but {} fell on an already seen day",
+                        collection,
+                        duplicate.map(|element| format!("{:?}", element)).unwrap_or_default()
+                    ),
+                    format!("{:?} should have duplicate days", collection),
+                )
+            }
+        }
+    }
+}
+
+fn describe_gap<T>(window: Option<&[T]>) -> String
+    where
+        T: Clone + Debug + Sub<Output=TimeDelta>,
+{
+    match window {
+        Some(window) => format!(
+            "the gap between {:?} and {:?} was {}",
+            window[0],
+            window[1],
+            describe_distance((window[1].clone() - window[0].clone()).abs())
+        ),
+        None => "all the gaps matched".to_string(),
+    }
+}
+
+impl<T> Matcher<Vec<T>> for DateCollectionMatcher<T>
+    where
+        T: Datelike + Clone + PartialOrd + Debug + Sub<Output=TimeDelta>,
+{
+    fn test(&self, collection: &Vec<T>) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+impl<T, const N: usize> Matcher<[T; N]> for DateCollectionMatcher<T>
+    where
+        T: Datelike + Clone + PartialOrd + Debug + Sub<Output=TimeDelta>,
+{
+    fn test(&self, collection: &[T; N]) -> MatcherResult {
+        self.test(collection as &[T])
+    }
+}
+
+impl<T> Matcher<&[T]> for DateCollectionMatcher<T>
+    where
+        T: Datelike + Clone + PartialOrd + Debug + Sub<Output=TimeDelta>,
+{
+    fn test(&self, collection: &&[T]) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+impl<T> Matcher<[T]> for DateCollectionMatcher<T>
+    where
+        T: Datelike + Clone + PartialOrd + Debug + Sub<Output=TimeDelta>,
+{
+    fn test(&self, collection: &[T]) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
+/// Creates a DateCollectionMatcher that asserts whether no two consecutive dates are further apart than the given gap.
+pub fn have_no_gaps_greater_than<T>(gap: TimeDelta) -> DateCollectionMatcher<T> {
+    DateCollectionMatcher::NoGapsGreaterThan(gap)
+}
+
+/// Creates a DateCollectionMatcher that asserts whether every two consecutive dates are exactly step apart.
+pub fn be_evenly_spaced<T>(step: TimeDelta) -> DateCollectionMatcher<T> {
+    DateCollectionMatcher::EvenlySpaced(step)
+}
+
+/// Creates a DateCollectionMatcher that asserts whether all the dates fall within the given inclusive range.
+pub fn all_fall_within<T>(range: RangeInclusive<T>) -> DateCollectionMatcher<T> {
+    DateCollectionMatcher::AllWithin(range)
+}
+
+/// Creates a DateCollectionMatcher that asserts whether no two dates fall on the same calendar day.
+pub fn have_no_duplicate_days<T>() -> DateCollectionMatcher<T> {
+    DateCollectionMatcher::NoDuplicateDays
+}
+
+#[cfg(all(test, feature = "date"))]
+mod tests {
+    use chrono::{NaiveDate, TimeDelta, TimeZone, Utc};
+
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::equal::EqualityAssertion;
+    use crate::matchers::collection::date::{
+        all_fall_within, be_evenly_spaced, have_no_duplicate_days, have_no_gaps_greater_than,
+    };
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn should_have_no_gaps_greater_than() {
+        let collection = vec![date(2024, 1, 1), date(2024, 1, 2), date(2024, 1, 4)];
+        let matcher = have_no_gaps_greater_than(TimeDelta::days(2));
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_no_gaps_greater_than_but_had() {
+        let collection = vec![date(2024, 1, 1), date(2024, 1, 2), date(2024, 1, 5)];
+        let matcher = have_no_gaps_greater_than(TimeDelta::days(2));
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_report_offending_gap() {
+        let collection = [date(2024, 1, 1), date(2024, 1, 2), date(2024, 1, 5)];
+        let matcher = have_no_gaps_greater_than(TimeDelta::days(2));
+        matcher.test(&collection).failure_message.should_equal(
+            "[2024-01-01, 2024-01-02, 2024-01-05] should have no gaps greater than 2 days but the gap between 2024-01-02 and 2024-01-05 was 3 days",
+        );
+    }
+
+    #[test]
+    fn should_be_evenly_spaced() {
+        let collection = vec![
+            Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 1, 11, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap(),
+        ];
+        let matcher = be_evenly_spaced(TimeDelta::hours(1));
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_evenly_spaced_but_was_not() {
+        let collection = vec![date(2024, 1, 1), date(2024, 1, 8), date(2024, 1, 14)];
+        let matcher = be_evenly_spaced(TimeDelta::weeks(1));
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_all_fall_within() {
+        let collection = vec![date(2024, 1, 1), date(2024, 1, 31)];
+        let matcher = all_fall_within(date(2024, 1, 1)..=date(2024, 1, 31));
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_all_fall_within_but_did_not() {
+        let collection = vec![date(2024, 1, 1), date(2024, 2, 1)];
+        let matcher = all_fall_within(date(2024, 1, 1)..=date(2024, 1, 31));
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_no_duplicate_days() {
+        let collection = vec![
+            Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 2, 10, 0, 0).unwrap(),
+        ];
+        let matcher = have_no_duplicate_days();
+        matcher.test(&collection).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_no_duplicate_days_but_had() {
+        let collection = vec![
+            Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 1, 18, 0, 0).unwrap(),
+        ];
+        let matcher = have_no_duplicate_days();
+        matcher.test(&collection).passed.should_be_true();
+    }
+}
@@ -53,6 +53,12 @@ impl<T: Eq + Debug> Matcher<&[T]> for DuplicateContentMatcher {
     }
 }
 
+impl<T: Eq + Debug> Matcher<[T]> for DuplicateContentMatcher {
+    fn test(&self, collection: &[T]) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
 /// Creates a DuplicateContentMatcher that asserts whether the underlying collection contains any duplicates.
 pub fn contain_duplicates() -> DuplicateContentMatcher {
     DuplicateContentMatcher
@@ -37,6 +37,12 @@ impl<T> Matcher<&[T]> for CollectionEmptyMatcher {
     }
 }
 
+impl<T> Matcher<[T]> for CollectionEmptyMatcher {
+    fn test(&self, collection: &[T]) -> MatcherResult {
+        self.test_length(collection)
+    }
+}
+
 impl CollectionEmptyMatcher {
     pub fn test_length<T>(&self, collection: &[T]) -> MatcherResult {
         match self {
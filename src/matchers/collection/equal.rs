@@ -92,6 +92,15 @@ where
     }
 }
 
+impl<T> Matcher<[T]> for IgnoreCaseEqualityMatcher<&[T]>
+where
+    T: AsRef<str> + Debug + Eq,
+{
+    fn test(&self, collection: &[T]) -> MatcherResult {
+        self.test(&collection)
+    }
+}
+
 #[cfg(test)]
 mod vector_tests {
     use crate::assertions::bool::TrueFalseAssertion;
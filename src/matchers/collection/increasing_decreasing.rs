@@ -68,6 +68,12 @@ impl<T: PartialOrd + Debug> Matcher<&[T]> for IncreasingDecreasingMatcher {
     }
 }
 
+impl<T: PartialOrd + Debug> Matcher<[T]> for IncreasingDecreasingMatcher {
+    fn test(&self, collection: &[T]) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
 /// Creates an IncreasingDecreasingMatcher that asserts whether the elements in a collection are in non-decreasing order (allowing consecutive equal elements).
 pub fn be_monotonically_increasing() -> IncreasingDecreasingMatcher {
     IncreasingDecreasingMatcher::MonotonicallyIncreasing
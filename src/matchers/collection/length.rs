@@ -38,6 +38,12 @@ impl<T> Matcher<&[T]> for CollectionLengthMatcher {
     }
 }
 
+impl<T> Matcher<[T]> for CollectionLengthMatcher {
+    fn test(&self, collection: &[T]) -> MatcherResult {
+        self.test_length(collection.len())
+    }
+}
+
 impl CollectionLengthMatcher {
     fn test_length(&self, input_length: usize) -> MatcherResult {
         let message_prefix = "Collection";
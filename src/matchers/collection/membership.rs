@@ -85,6 +85,15 @@ impl<T> Matcher<&[T]> for MembershipMatcher<T>
     }
 }
 
+impl<T> Matcher<[T]> for MembershipMatcher<T>
+    where
+        T: Eq + Debug,
+{
+    fn test(&self, collection: &[T]) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
 /// Creates a MembershipMatcher that asserts whether a collection contains the given element.
 pub fn contain<T>(element: T) -> MembershipMatcher<T>
     where
@@ -113,6 +122,7 @@ pub fn contain_any<T>(elements: Vec<T>) -> MembershipMatcher<T>
 mod tests {
     use crate::assertions::bool::TrueFalseAssertion;
     use crate::matchers::collection::membership::{contain, contain_all, contain_any};
+    use crate::matchers::Matcher;
 
     #[test]
     fn should_contain() {
@@ -121,6 +131,13 @@ mod tests {
         matcher.test(&collection).passed.should_be_true();
     }
 
+    #[test]
+    fn should_contain_for_slice() {
+        let collection: &[&str] = &["junit", "testify"];
+        let matcher = contain("junit");
+        Matcher::<[&str]>::test(&matcher, collection).passed.should_be_true();
+    }
+
     #[test]
     #[should_panic]
     fn should_contain_but_id_did_not() {
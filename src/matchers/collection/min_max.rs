@@ -81,6 +81,12 @@ impl<T: Ord + Debug> Matcher<&[T]> for MinMaxMatcher<T> {
     }
 }
 
+impl<T: Ord + Debug> Matcher<[T]> for MinMaxMatcher<T> {
+    fn test(&self, collection: &[T]) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
 /// Creates a MinMaxMatcher that asserts whether the minimum value in the underlying collection equals the given minimum value.
 pub fn have_min<T: Ord>(min: T) -> MinMaxMatcher<T> {
     MinMaxMatcher::Min(min)
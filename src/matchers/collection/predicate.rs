@@ -77,6 +77,16 @@ impl<F, T> Matcher<&[T]> for PredicateMatcher<F, T>
     }
 }
 
+impl<F, T> Matcher<[T]> for PredicateMatcher<F, T>
+    where
+        F: Fn(&T) -> bool,
+        T: Eq + Debug,
+{
+    fn test(&self, collection: &[T]) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
 /// Creates a PredicateMatcher that asserts whether any of the elements in a collection satisfy the given predicate.
 pub fn satisfy_for_any<F, T>(predicate: F) -> PredicateMatcher<F, T>
     where
@@ -56,6 +56,12 @@ impl<T: PartialOrd + Debug> Matcher<&[T]> for SortMatcher {
     }
 }
 
+impl<T: PartialOrd + Debug> Matcher<[T]> for SortMatcher {
+    fn test(&self, collection: &[T]) -> MatcherResult {
+        self.test(collection)
+    }
+}
+
 /// Creates an SortMatcher that asserts whether the elements in a collection are sorted in ascending order.
 pub fn be_sorted_ascending() -> SortMatcher {
     SortMatcher::Ascending
@@ -8,12 +8,12 @@ enum Kind {
 }
 
 /// MatcherBehavior encapsulates a matcher and an inversion flag, governing how it's applied in assertions.
-pub struct MatcherBehavior<T> {
+pub struct MatcherBehavior<T: ?Sized> {
     matcher: Box<dyn Matcher<T>>,
     inverted: bool,
 }
 
-impl<T: Debug> MatcherBehavior<T> {
+impl<T: Debug + ?Sized> MatcherBehavior<T> {
     /// Creates a new instance of MatcherBehavior encapsulating the given matcher.
     pub fn new(matcher: Box<dyn Matcher<T>>) -> Self {
         MatcherBehavior {
@@ -33,7 +33,7 @@ impl<T: Debug> MatcherBehavior<T> {
     /// Runs the underlying matcher.
     pub fn run_matcher(&self, value: &T) -> MatcherResult {
         let matcher_result = self.matcher.test(value);
-        if self.inverted {
+        if self.inverted && !matcher_result.errored {
             return MatcherResult::formatted(
                 !matcher_result.passed,
                 matcher_result.inverted_failure_message,
@@ -67,11 +67,11 @@ impl<T: Debug> MatcherBehavior<T> {
 /// let password = "P@@sw0rd9082";
 /// assert!(matchers.test(&password).passed());
 /// ```
-pub struct MatchersBuilder<T> {
+pub struct MatchersBuilder<T: ?Sized> {
     matchers_behaviors: Vec<MatcherBehavior<T>>,
 }
 
-impl<T: Debug> MatchersBuilder<T> {
+impl<T: Debug + ?Sized> MatchersBuilder<T> {
     /// Creates an instance of MatchersBuilder with the given matcher.
     pub fn start_building(matcher: Box<dyn Matcher<T>>) -> Self {
         MatchersBuilder {
@@ -114,12 +114,12 @@ impl<T: Debug> MatchersBuilder<T> {
 /// Matchers provides a way to combine various matchers using AND or OR operators.
 /// If an instance of Matchers is created using AND operator, all the underlying matchers MUST pass for Matchers to pass.
 /// If an instance of Matchers is created using OR operator, any of the underlying matchers MUST pass for Matchers to pass.
-pub struct Matchers<T> {
+pub struct Matchers<T: ?Sized> {
     matcher_behaviors: Vec<MatcherBehavior<T>>,
     kind: Kind,
 }
 
-impl<T: Debug> Matchers<T> {
+impl<T: Debug + ?Sized> Matchers<T> {
     fn and(matchers: Vec<MatcherBehavior<T>>) -> Self {
         Matchers {
             matcher_behaviors: matchers,
@@ -136,7 +136,7 @@ impl<T: Debug> Matchers<T> {
 }
 
 /// Matchers implement the [`crate::matchers::Matcher`] trait.
-impl<T: Debug> Matcher<T> for Matchers<T> {
+impl<T: Debug + ?Sized> Matcher<T> for Matchers<T> {
     fn test(&self, value: &T) -> MatcherResult {
         let results = self
             .matcher_behaviors
@@ -144,6 +144,13 @@ impl<T: Debug> Matcher<T> for Matchers<T> {
             .map(|matcher_behavior| matcher_behavior.run_matcher(value))
             .collect::<Vec<_>>();
 
+        if results.iter().any(|result| result.errored) {
+            return MatcherResult::errored(messages(
+                &results,
+                |result| result.errored,
+                |result| result.failure_message.clone(),
+            ));
+        }
         match self.kind {
             Kind::And => MatcherResult::formatted(
                 results.iter().all(|result| result.passed),
@@ -1,9 +1,17 @@
-use chrono::{Datelike, NaiveDate};
+use std::fmt::Debug;
+
+use chrono::{Datelike, Weekday};
 
 use crate::matchers::{Matcher, MatcherResult};
 
+pub mod offset;
+pub mod relative;
+pub mod time_of_day;
+
 /// DateMatcher offers a flexible way to make assertions about specific date attributes.
 ///
+/// Works with any chrono type that implements the Datelike trait, like `NaiveDate`, `NaiveDateTime` and `DateTime<Tz>`.
+///
 /// # Example
 ///```
 /// use chrono::NaiveDate;
@@ -20,10 +28,16 @@ pub enum DateMatcher {
     SameMonth(u32),
     SameDay(u32),
     LeapYear,
+    Weekday,
+    Weekend,
+    FallOn(Weekday),
+    IsoWeek(u32),
+    Quarter(u32),
+    LastDayOfMonth,
 }
 
-impl Matcher<NaiveDate> for DateMatcher {
-    fn test(&self, value: &NaiveDate) -> MatcherResult {
+impl<T: Datelike + Debug> Matcher<T> for DateMatcher {
+    fn test(&self, value: &T) -> MatcherResult {
         match self {
             DateMatcher::SameYear(other) => MatcherResult::formatted(
                 value.year() == *other,
@@ -41,14 +55,81 @@ impl Matcher<NaiveDate> for DateMatcher {
                 format!("{:?} should not have the same day as {:?}", value, other),
             ),
             DateMatcher::LeapYear => MatcherResult::formatted(
-                value.leap_year(),
+                is_leap_year(value.year()),
                 format!("{:?} should be a leap year", value),
                 format!("{:?} should not be a leap year", value),
             ),
+            DateMatcher::Weekday => MatcherResult::formatted(
+                !is_weekend(value.weekday()),
+                format!("{:?} should be a weekday but fell on {:?}", value, value.weekday()),
+                format!("{:?} should not be a weekday but fell on {:?}", value, value.weekday()),
+            ),
+            DateMatcher::Weekend => MatcherResult::formatted(
+                is_weekend(value.weekday()),
+                format!("{:?} should be a weekend but fell on {:?}", value, value.weekday()),
+                format!("{:?} should not be a weekend but fell on {:?}", value, value.weekday()),
+            ),
+            DateMatcher::FallOn(weekday) => MatcherResult::formatted(
+                value.weekday() == *weekday,
+                format!("{:?} should fall on {:?} but fell on {:?}", value, weekday, value.weekday()),
+                format!("{:?} should not fall on {:?}", value, weekday),
+            ),
+            DateMatcher::IsoWeek(week) => MatcherResult::formatted(
+                value.iso_week().week() == *week,
+                format!(
+                    "{:?} should be in the ISO week {:?} but was in the ISO week {:?}",
+                    value,
+                    week,
+                    value.iso_week().week()
+                ),
+                format!("{:?} should not be in the ISO week {:?}", value, week),
+            ),
+            DateMatcher::Quarter(quarter) => MatcherResult::formatted(
+                quarter_of(value.month()) == *quarter,
+                format!(
+                    "{:?} should be in the quarter {:?} but was in the quarter {:?}",
+                    value,
+                    quarter,
+                    quarter_of(value.month())
+                ),
+                format!("{:?} should not be in the quarter {:?}", value, quarter),
+            ),
+            DateMatcher::LastDayOfMonth => {
+                let last_day = days_in_month(value.year(), value.month());
+                MatcherResult::formatted(
+                    value.day() == last_day,
+                    format!(
+                        "{:?} should be the last day of the month but the month ends on the day {:?}",
+                        value, last_day
+                    ),
+                    format!("{:?} should not be the last day of the month", value),
+                )
+            }
         }
     }
 }
 
+fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn is_weekend(weekday: Weekday) -> bool {
+    weekday == Weekday::Sat || weekday == Weekday::Sun
+}
+
+fn quarter_of(month: u32) -> u32 {
+    (month - 1) / 3 + 1
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
 /// Creates a DateMatcher that asserts whether a date has the same year as the given year.
 pub fn have_same_year(year: i32) -> DateMatcher {
     DateMatcher::SameYear(year)
@@ -69,12 +150,45 @@ pub fn be_a_leap_year() -> DateMatcher {
     DateMatcher::LeapYear
 }
 
+/// Creates a DateMatcher that asserts whether a date falls on a weekday (Monday to Friday).
+pub fn be_a_weekday() -> DateMatcher {
+    DateMatcher::Weekday
+}
+
+/// Creates a DateMatcher that asserts whether a date falls on a weekend (Saturday or Sunday).
+pub fn be_a_weekend() -> DateMatcher {
+    DateMatcher::Weekend
+}
+
+/// Creates a DateMatcher that asserts whether a date falls on the given weekday.
+pub fn fall_on(weekday: Weekday) -> DateMatcher {
+    DateMatcher::FallOn(weekday)
+}
+
+/// Creates a DateMatcher that asserts whether a date is in the given ISO week.
+pub fn be_in_iso_week(week: u32) -> DateMatcher {
+    DateMatcher::IsoWeek(week)
+}
+
+/// Creates a DateMatcher that asserts whether a date is in the given quarter (1 to 4) of its year.
+pub fn be_in_quarter(quarter: u32) -> DateMatcher {
+    DateMatcher::Quarter(quarter)
+}
+
+/// Creates a DateMatcher that asserts whether a date is the last day of its month.
+pub fn be_the_last_day_of_month() -> DateMatcher {
+    DateMatcher::LastDayOfMonth
+}
+
 #[cfg(all(test, feature = "date"))]
 mod tests {
     use crate::assertions::bool::TrueFalseAssertion;
-    use crate::matchers::date::{be_a_leap_year, have_same_day, have_same_month, have_same_year};
+    use crate::matchers::date::{
+        be_a_leap_year, be_a_weekday, be_a_weekend, be_in_iso_week, be_in_quarter,
+        be_the_last_day_of_month, fall_on, have_same_day, have_same_month, have_same_year,
+    };
     use crate::matchers::Matcher;
-    use chrono::NaiveDate;
+    use chrono::{NaiveDate, TimeZone, Utc, Weekday};
 
     #[test]
     fn should_have_same_year() {
@@ -103,4 +217,123 @@ mod tests {
         let matcher = be_a_leap_year();
         matcher.test(&date).passed.should_be_true();
     }
+
+    #[test]
+    #[should_panic]
+    fn should_be_a_leap_year_but_was_not() {
+        let date = NaiveDate::from_ymd_opt(1900, 1, 10).unwrap();
+        let matcher = be_a_leap_year();
+        matcher.test(&date).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_same_year_for_date_time() {
+        let date_time = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap().and_hms_opt(10, 20, 30).unwrap();
+        let matcher = have_same_year(2024);
+        matcher.test(&date_time).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_same_day_for_utc_date_time() {
+        let date_time = Utc.with_ymd_and_hms(2024, 1, 10, 10, 20, 30).unwrap();
+        let matcher = have_same_day(10);
+        matcher.test(&date_time).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_a_leap_year_for_utc_date_time() {
+        let date_time = Utc.with_ymd_and_hms(2000, 1, 10, 10, 20, 30).unwrap();
+        let matcher = be_a_leap_year();
+        matcher.test(&date_time).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_a_weekday() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+        let matcher = be_a_weekday();
+        matcher.test(&date).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_a_weekday_but_was_not() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 13).unwrap();
+        let matcher = be_a_weekday();
+        matcher.test(&date).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_a_weekend() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 14).unwrap();
+        let matcher = be_a_weekend();
+        matcher.test(&date).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_a_weekend_but_was_not() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let matcher = be_a_weekend();
+        matcher.test(&date).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_fall_on() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+        let matcher = fall_on(Weekday::Wed);
+        matcher.test(&date).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_fall_on_but_did_not() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+        let matcher = fall_on(Weekday::Mon);
+        matcher.test(&date).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_in_iso_week() {
+        let date = NaiveDate::from_ymd_opt(2024, 12, 30).unwrap();
+        let matcher = be_in_iso_week(1);
+        matcher.test(&date).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_in_iso_week_but_was_not() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+        let matcher = be_in_iso_week(1);
+        matcher.test(&date).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_in_quarter() {
+        let date = Utc.with_ymd_and_hms(2024, 6, 30, 10, 0, 0).unwrap();
+        let matcher = be_in_quarter(2);
+        matcher.test(&date).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_in_quarter_but_was_not() {
+        let date = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+        let matcher = be_in_quarter(2);
+        matcher.test(&date).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_the_last_day_of_leap_february() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let matcher = be_the_last_day_of_month();
+        matcher.test(&date).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_the_last_day_of_month_but_was_not() {
+        let date = NaiveDate::from_ymd_opt(2024, 4, 30).unwrap().pred_opt().unwrap();
+        let matcher = be_the_last_day_of_month();
+        matcher.test(&date).passed.should_be_true();
+    }
 }
@@ -0,0 +1,102 @@
+use std::fmt::Debug;
+
+use chrono::{DateTime, FixedOffset, Offset, TimeZone};
+
+use crate::matchers::{Matcher, MatcherResult};
+
+/// OffsetMatcher offers a flexible way to make assertions about the timezone offset of `DateTime<Tz>`.
+///
+/// # Example
+///```
+/// use chrono::{FixedOffset, TimeZone};
+/// use clearcheck::matchers::date::offset::have_offset;
+/// use clearcheck::matchers::Matcher;
+///
+/// let offset = FixedOffset::east_opt(3600).unwrap();
+/// let date_time = offset.with_ymd_and_hms(2024, 1, 10, 10, 20, 30).unwrap();
+/// let matcher = have_offset(offset);
+///
+/// assert!(matcher.test(&date_time).passed());
+/// ```
+pub enum OffsetMatcher {
+    Offset(FixedOffset),
+    Utc,
+}
+
+impl<Tz: TimeZone> Matcher<DateTime<Tz>> for OffsetMatcher
+    where
+        Tz::Offset: Debug,
+{
+    fn test(&self, value: &DateTime<Tz>) -> MatcherResult {
+        let actual = value.offset().fix();
+        match self {
+            OffsetMatcher::Offset(offset) => MatcherResult::formatted(
+                actual == *offset,
+                format!("{:?} should have the offset {} but was {}", value, offset, actual),
+                format!("{:?} should not have the offset {}", value, offset),
+            ),
+            OffsetMatcher::Utc => MatcherResult::formatted(
+                actual.local_minus_utc() == 0,
+                format!("{:?} should be UTC but had the offset {}", value, actual),
+                format!("{:?} should not be UTC", value),
+            ),
+        }
+    }
+}
+
+/// Creates an OffsetMatcher that asserts whether a date time has the given offset.
+pub fn have_offset(offset: FixedOffset) -> OffsetMatcher {
+    OffsetMatcher::Offset(offset)
+}
+
+/// Creates an OffsetMatcher that asserts whether a date time is in UTC (has a zero offset).
+pub fn be_utc() -> OffsetMatcher {
+    OffsetMatcher::Utc
+}
+
+#[cfg(all(test, feature = "date"))]
+mod tests {
+    use chrono::{FixedOffset, TimeZone, Utc};
+
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::date::offset::{be_utc, have_offset};
+    use crate::matchers::Matcher;
+
+    #[test]
+    fn should_have_offset() {
+        let offset = FixedOffset::east_opt(3600).unwrap();
+        let date_time = offset.with_ymd_and_hms(2024, 1, 10, 10, 20, 30).unwrap();
+        let matcher = have_offset(offset);
+        matcher.test(&date_time).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_offset_but_did_not() {
+        let date_time = FixedOffset::west_opt(3600).unwrap().with_ymd_and_hms(2024, 1, 10, 10, 20, 30).unwrap();
+        let matcher = have_offset(FixedOffset::east_opt(3600).unwrap());
+        matcher.test(&date_time).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_utc() {
+        let date_time = Utc.with_ymd_and_hms(2024, 1, 10, 10, 20, 30).unwrap();
+        let matcher = be_utc();
+        matcher.test(&date_time).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_utc_with_zero_fixed_offset() {
+        let date_time = FixedOffset::east_opt(0).unwrap().with_ymd_and_hms(2024, 1, 10, 10, 20, 30).unwrap();
+        let matcher = be_utc();
+        matcher.test(&date_time).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_utc_but_was_not() {
+        let date_time = FixedOffset::east_opt(1800).unwrap().with_ymd_and_hms(2024, 1, 10, 10, 20, 30).unwrap();
+        let matcher = be_utc();
+        matcher.test(&date_time).passed.should_be_true();
+    }
+}
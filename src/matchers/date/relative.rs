@@ -0,0 +1,264 @@
+use std::fmt::Debug;
+use std::ops::Sub;
+
+use chrono::TimeDelta;
+
+use crate::matchers::{Matcher, MatcherResult};
+use crate::matchers::time::humanize;
+
+/// RelativeDateMatcher offers a flexible way to make assertions about a date relative to other dates.
+///
+/// Works with chrono types that can be subtracted into a `TimeDelta`, like `NaiveDate`, `NaiveDateTime` and `DateTime<Tz>`.
+/// The failure messages carry the actual distance between the dates.
+///
+/// # Example
+///```
+/// use chrono::NaiveDate;
+/// use clearcheck::matchers::date::relative::be_before;
+/// use clearcheck::matchers::Matcher;
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+/// let matcher = be_before(NaiveDate::from_ymd_opt(2024, 1, 20).unwrap());
+///
+/// assert!(matcher.test(&date).passed());
+/// ```
+pub enum RelativeDateMatcher<T> {
+    Before(T),
+    After(T),
+    WithinDays(T, i64),
+    Between(T, T),
+}
+
+impl<T> Matcher<T> for RelativeDateMatcher<T>
+    where
+        T: Clone + PartialOrd + Debug + Sub<Output=TimeDelta>,
+{
+    fn test(&self, value: &T) -> MatcherResult {
+        match self {
+            RelativeDateMatcher::Before(other) => MatcherResult::formatted(
+                value < other,
+                format!(
+                    "{:?} should be before {:?} but was {}",
+                    value,
+                    other,
+                    relative_position(value, other)
+                ),
+                format!(
+                    "{:?} should not be before {:?} but was {}",
+                    value,
+                    other,
+                    relative_position(value, other)
+                ),
+            ),
+            RelativeDateMatcher::After(other) => MatcherResult::formatted(
+                value > other,
+                format!(
+                    "{:?} should be after {:?} but was {}",
+                    value,
+                    other,
+                    relative_position(value, other)
+                ),
+                format!(
+                    "{:?} should not be after {:?} but was {}",
+                    value,
+                    other,
+                    relative_position(value, other)
+                ),
+            ),
+            RelativeDateMatcher::WithinDays(other, days) => match TimeDelta::try_days(*days) {
+                Some(window) => MatcherResult::formatted(
+                    (value.clone() - other.clone()).abs() <= window,
+                    format!(
+                        "{:?} should be within {} of {:?} but was {}",
+                        value,
+                        plural_days(*days),
+                        other,
+                        relative_position(value, other)
+                    ),
+                    format!(
+                        "{:?} should not be within {} of {:?} but was {}",
+                        value,
+                        plural_days(*days),
+                        other,
+                        relative_position(value, other)
+                    ),
+                ),
+                None => MatcherResult::errored(format!(
+                    "{:?} should be within {} of {:?} but {} is out of range for a duration",
+                    value,
+                    plural_days(*days),
+                    other,
+                    plural_days(*days)
+                )),
+            },
+            RelativeDateMatcher::Between(start, end) => {
+                let nearest = if value > end { end } else { start };
+                let position = format!("{} {:?}", relative_position(value, nearest), nearest);
+                MatcherResult::formatted(
+                    value >= start && value <= end,
+                    format!(
+                        "{:?} should be between {:?} and {:?} but was {}",
+                        value, start, end, position
+                    ),
+                    format!(
+                        "{:?} should not be between {:?} and {:?} but was {}",
+                        value, start, end, position
+                    ),
+                )
+            }
+        }
+    }
+}
+
+fn relative_position<T>(value: &T, other: &T) -> String
+    where
+        T: Clone + PartialOrd + Sub<Output=TimeDelta>,
+{
+    let distance = describe_distance((value.clone() - other.clone()).abs());
+    if value < other {
+        format!("{} before", distance)
+    } else if value > other {
+        format!("{} after", distance)
+    } else {
+        "the same as".to_string()
+    }
+}
+
+pub(crate) fn describe_distance(distance: TimeDelta) -> String {
+    let days = distance.num_days();
+    let remainder = (distance - TimeDelta::days(days)).to_std().unwrap_or_default();
+    match (days, remainder.is_zero()) {
+        (0, _) => humanize(&remainder),
+        (_, true) => plural_days(days),
+        (_, false) => format!("{} {}", plural_days(days), humanize(&remainder)),
+    }
+}
+
+fn plural_days(days: i64) -> String {
+    if days == 1 {
+        "1 day".to_string()
+    } else {
+        format!("{} days", days)
+    }
+}
+
+/// Creates a RelativeDateMatcher that asserts whether a date is before the other.
+pub fn be_before<T>(other: T) -> RelativeDateMatcher<T> {
+    RelativeDateMatcher::Before(other)
+}
+
+/// Creates a RelativeDateMatcher that asserts whether a date is after the other.
+pub fn be_after<T>(other: T) -> RelativeDateMatcher<T> {
+    RelativeDateMatcher::After(other)
+}
+
+/// Creates a RelativeDateMatcher that asserts whether a date is within the given number of days (on either side) of the other.
+pub fn be_within_days_of<T>(other: T, days: i64) -> RelativeDateMatcher<T> {
+    RelativeDateMatcher::WithinDays(other, days)
+}
+
+/// Creates a RelativeDateMatcher that asserts whether a date is between start and end (both inclusive).
+pub fn be_between<T>(start: T, end: T) -> RelativeDateMatcher<T> {
+    RelativeDateMatcher::Between(start, end)
+}
+
+#[cfg(all(test, feature = "date"))]
+mod tests {
+    use chrono::{NaiveDate, TimeZone, Utc};
+
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::equal::EqualityAssertion;
+    use crate::assertions::string::membership::MembershipAssertion;
+    use crate::matchers::date::relative::{be_after, be_before, be_between, be_within_days_of};
+    use crate::matchers::Matcher;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn should_be_before() {
+        let matcher = be_before(date(2024, 1, 20));
+        matcher.test(&date(2024, 1, 10)).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_before_but_was_not() {
+        let matcher = be_before(date(2024, 1, 10));
+        matcher.test(&date(2024, 1, 10)).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_report_distance_in_before() {
+        let matcher = be_before(date(2024, 1, 5));
+        let result = matcher.test(&date(2024, 1, 10));
+        result.failure_message.should_equal("2024-01-10 should be before 2024-01-05 but was 5 days after");
+    }
+
+    #[test]
+    fn should_be_after() {
+        let matcher = be_after(Utc.with_ymd_and_hms(2024, 1, 10, 10, 0, 0).unwrap());
+        matcher.test(&Utc.with_ymd_and_hms(2024, 1, 10, 10, 0, 1).unwrap()).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_after_but_was_not() {
+        let matcher = be_after(date(2024, 1, 20));
+        matcher.test(&date(2024, 1, 10)).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_within_days_of() {
+        let matcher = be_within_days_of(date(2024, 1, 20), 3);
+        matcher.test(&date(2024, 1, 17)).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_within_days_of_but_was_not() {
+        let matcher = be_within_days_of(date(2024, 1, 20), 3);
+        matcher.test(&date(2024, 1, 24)).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_fail_both_ways_given_days_out_of_range() {
+        let matcher = be_within_days_of(date(2024, 1, 20), i64::MAX);
+        let result = matcher.test(&date(2024, 1, 17));
+        result.passed.should_be_false();
+        result.errored.should_be_true();
+        result.failure_message.should_contain("is out of range for a duration");
+    }
+
+    #[test]
+    fn should_report_distance_with_time_in_within_days_of() {
+        let matcher = be_within_days_of(Utc.with_ymd_and_hms(2024, 1, 10, 10, 0, 0).unwrap(), 1);
+        let result = matcher.test(&Utc.with_ymd_and_hms(2024, 1, 12, 12, 30, 0).unwrap());
+        result.failure_message.should_equal(
+            "2024-01-12T12:30:00Z should be within 1 day of 2024-01-10T10:00:00Z but was 2 days 2h 30m after",
+        );
+    }
+
+    #[test]
+    fn should_be_between() {
+        let matcher = be_between(date(2024, 1, 1), date(2024, 1, 31));
+        matcher.test(&date(2024, 1, 31)).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_between_but_was_not() {
+        let matcher = be_between(date(2024, 1, 1), date(2024, 1, 31));
+        matcher.test(&date(2024, 2, 1)).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_report_distance_in_between() {
+        let matcher = be_between(date(2024, 1, 1), date(2024, 1, 31));
+        let result = matcher.test(&date(2023, 12, 30));
+        result.failure_message.should_equal(
+            "2023-12-30 should be between 2024-01-01 and 2024-01-31 but was 2 days before 2024-01-01",
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use std::fmt::Debug;
+
+use chrono::Timelike;
+
+use crate::matchers::{Matcher, MatcherResult};
+
+/// TimeOfDayMatcher offers a flexible way to make assertions about the time of day.
+///
+/// Works with any chrono type that implements the Timelike trait, like `NaiveTime`, `NaiveDateTime` and `DateTime<Tz>`.
+///
+/// # Example
+///```
+/// use chrono::NaiveTime;
+/// use clearcheck::matchers::date::time_of_day::have_hour;
+/// use clearcheck::matchers::Matcher;
+///
+/// let time = NaiveTime::from_hms_opt(10, 20, 30).unwrap();
+/// let matcher = have_hour(10);
+///
+/// assert!(matcher.test(&time).passed());
+/// ```
+pub enum TimeOfDayMatcher {
+    Hour(u32),
+    Minute(u32),
+    Midnight,
+}
+
+impl<T: Timelike + Debug> Matcher<T> for TimeOfDayMatcher {
+    fn test(&self, value: &T) -> MatcherResult {
+        match self {
+            TimeOfDayMatcher::Hour(hour) => MatcherResult::formatted(
+                value.hour() == *hour,
+                format!("{:?} should have the hour {:?}", value, hour),
+                format!("{:?} should not have the hour {:?}", value, hour),
+            ),
+            TimeOfDayMatcher::Minute(minute) => MatcherResult::formatted(
+                value.minute() == *minute,
+                format!("{:?} should have the minute {:?}", value, minute),
+                format!("{:?} should not have the minute {:?}", value, minute),
+            ),
+            TimeOfDayMatcher::Midnight => MatcherResult::formatted(
+                value.num_seconds_from_midnight() == 0 && value.nanosecond() == 0,
+                format!("{:?} should be midnight", value),
+                format!("{:?} should not be midnight", value),
+            ),
+        }
+    }
+}
+
+/// Creates a TimeOfDayMatcher that asserts whether a time has the given hour.
+pub fn have_hour(hour: u32) -> TimeOfDayMatcher {
+    TimeOfDayMatcher::Hour(hour)
+}
+
+/// Creates a TimeOfDayMatcher that asserts whether a time has the given minute.
+pub fn have_minute(minute: u32) -> TimeOfDayMatcher {
+    TimeOfDayMatcher::Minute(minute)
+}
+
+/// Creates a TimeOfDayMatcher that asserts whether a time is exactly midnight (00:00:00).
+pub fn be_midnight() -> TimeOfDayMatcher {
+    TimeOfDayMatcher::Midnight
+}
+
+#[cfg(all(test, feature = "date"))]
+mod tests {
+    use chrono::{NaiveDate, NaiveTime, TimeZone, Utc};
+
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::date::time_of_day::{be_midnight, have_hour, have_minute};
+    use crate::matchers::Matcher;
+
+    #[test]
+    fn should_have_hour() {
+        let time = NaiveTime::from_hms_opt(10, 20, 30).unwrap();
+        let matcher = have_hour(10);
+        matcher.test(&time).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_hour_but_did_not() {
+        let time = NaiveTime::from_hms_opt(10, 20, 30).unwrap();
+        let matcher = have_hour(11);
+        matcher.test(&time).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_minute() {
+        let date_time = Utc.with_ymd_and_hms(2024, 1, 10, 10, 20, 30).unwrap();
+        let matcher = have_minute(20);
+        matcher.test(&date_time).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_minute_but_did_not() {
+        let date_time = Utc.with_ymd_and_hms(2024, 1, 10, 10, 20, 30).unwrap();
+        let matcher = have_minute(30);
+        matcher.test(&date_time).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_midnight() {
+        let date_time = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        let matcher = be_midnight();
+        matcher.test(&date_time).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_midnight_but_was_not() {
+        let time = NaiveTime::from_hms_milli_opt(0, 0, 0, 1).unwrap();
+        let matcher = be_midnight();
+        matcher.test(&time).passed.should_be_true();
+    }
+}
@@ -0,0 +1,217 @@
+use std::cmp::max;
+
+const CONTEXT_LINES: usize = 3;
+
+#[derive(Debug, PartialEq)]
+enum Edit<'a> {
+    Equal(&'a str),
+    Delete(&'a str),
+    Insert(&'a str),
+}
+
+impl Edit<'_> {
+    fn in_old(&self) -> bool {
+        !matches!(self, Edit::Insert(_))
+    }
+
+    fn in_new(&self) -> bool {
+        !matches!(self, Edit::Delete(_))
+    }
+}
+
+/// Returns a unified diff (with 3 lines of context) of the lines of old and new, or an empty string if they have the same lines.
+pub(crate) fn unified_diff(old_name: &str, new_name: &str, old: &str, new: &str) -> String {
+    let old_lines = old.lines().collect::<Vec<_>>();
+    let new_lines = new.lines().collect::<Vec<_>>();
+    let edits = edits(&old_lines, &new_lines);
+
+    let hunks = hunks(&edits);
+    if hunks.is_empty() {
+        return String::new();
+    }
+
+    let mut diff = format!("--- {}\n+++ {}\n", old_name, new_name);
+    for (start, end) in hunks {
+        let old_start = edits[..start].iter().filter(|edit| edit.in_old()).count() + 1;
+        let new_start = edits[..start].iter().filter(|edit| edit.in_new()).count() + 1;
+        let old_count = edits[start..end].iter().filter(|edit| edit.in_old()).count();
+        let new_count = edits[start..end].iter().filter(|edit| edit.in_new()).count();
+
+        diff.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            old_start, old_count, new_start, new_count
+        ));
+        for edit in &edits[start..end] {
+            let line = match edit {
+                Edit::Equal(line) => format!(" {}\n", line),
+                Edit::Delete(line) => format!("-{}\n", line),
+                Edit::Insert(line) => format!("+{}\n", line),
+            };
+            diff.push_str(&line);
+        }
+    }
+    diff
+}
+
+fn edits<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<Edit<'a>> {
+    let mut edits = Vec::with_capacity(max(old.len(), new.len()));
+    push_edits(old, new, &mut edits);
+    edits
+}
+
+/// Pushes the edits turning old into new, using the linear space variant of Myers' diff:
+/// the common prefix and suffix are stripped, and the rest is split around the middle snake
+/// (the edit in the middle of a shortest edit script) until one side is empty.
+fn push_edits<'a>(old: &[&'a str], new: &[&'a str], edits: &mut Vec<Edit<'a>>) {
+    let prefix = old.iter().zip(new).take_while(|(old_line, new_line)| old_line == new_line).count();
+    edits.extend(old[..prefix].iter().map(|line| Edit::Equal(line)));
+    let (old, new) = (&old[prefix..], &new[prefix..]);
+
+    let suffix = old.iter().rev().zip(new.iter().rev()).take_while(|(old_line, new_line)| old_line == new_line).count();
+    let (old_middle, new_middle) = (&old[..old.len() - suffix], &new[..new.len() - suffix]);
+
+    if old_middle.is_empty() || new_middle.is_empty() {
+        edits.extend(old_middle.iter().map(|line| Edit::Delete(line)));
+        edits.extend(new_middle.iter().map(|line| Edit::Insert(line)));
+    } else {
+        let ((start_old, start_new), (finish_old, finish_new)) = middle_snake(old_middle, new_middle);
+        push_edits(&old_middle[..start_old], &new_middle[..start_new], edits);
+        push_edits(&old_middle[start_old..finish_old], &new_middle[start_new..finish_new], edits);
+        push_edits(&old_middle[finish_old..], &new_middle[finish_new..], edits);
+    }
+    edits.extend(old[old.len() - suffix..].iter().map(|line| Edit::Equal(line)));
+}
+
+/// Returns the start and the finish (as (old index, new index)) of the middle snake, found by running Myers' search
+/// forward from the start and backward from the end until the two overlap.
+/// Both old and new must be non-empty and differ in their first and last lines.
+fn middle_snake(old: &[&str], new: &[&str]) -> ((usize, usize), (usize, usize)) {
+    let (width, height) = (old.len() as isize, new.len() as isize);
+    let delta = width - height;
+    let max_distance = (width + height + 1) / 2;
+    let offset = max_distance + 1;
+    let at = |diagonal: isize| (diagonal + offset) as usize;
+
+    let mut forward = vec![0isize; (2 * max_distance + 3) as usize];
+    let mut backward = vec![0isize; (2 * max_distance + 3) as usize];
+    backward[at(1)] = height;
+
+    for distance in 0..=max_distance {
+        for k in (-distance..=distance).rev().step_by(2) {
+            let (previous_x, mut x) =
+                if k == -distance || (k != distance && forward[at(k - 1)] < forward[at(k + 1)]) {
+                    (forward[at(k + 1)], forward[at(k + 1)])
+                } else {
+                    (forward[at(k - 1)], forward[at(k - 1)] + 1)
+                };
+            let mut y = x - k;
+            let previous_y = if distance == 0 || x != previous_x { y } else { y - 1 };
+            while x < width && y < height && old[x as usize] == new[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            forward[at(k)] = x;
+
+            let c = k - delta;
+            if delta % 2 != 0 && (-(distance - 1)..=(distance - 1)).contains(&c) && y >= backward[at(c)] {
+                return ((previous_x as usize, previous_y as usize), (x as usize, y as usize));
+            }
+        }
+        for c in (-distance..=distance).rev().step_by(2) {
+            let (previous_y, mut y) =
+                if c == -distance || (c != distance && backward[at(c - 1)] > backward[at(c + 1)]) {
+                    (backward[at(c + 1)], backward[at(c + 1)])
+                } else {
+                    (backward[at(c - 1)], backward[at(c - 1)] - 1)
+                };
+            let k = c + delta;
+            let mut x = y + k;
+            let previous_x = if distance == 0 || y != previous_y { x } else { x + 1 };
+            while x > 0 && y > 0 && old[(x - 1) as usize] == new[(y - 1) as usize] {
+                x -= 1;
+                y -= 1;
+            }
+            backward[at(c)] = y;
+
+            if delta % 2 == 0 && (-distance..=distance).contains(&k) && x <= forward[at(k)] {
+                return ((x as usize, y as usize), (previous_x as usize, previous_y as usize));
+            }
+        }
+    }
+    unreachable!("the forward and backward searches always meet within half of the maximum edit distance")
+}
+
+fn hunks(edits: &[Edit]) -> Vec<(usize, usize)> {
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    let changes = edits
+        .iter()
+        .enumerate()
+        .filter(|(_, edit)| !matches!(edit, Edit::Equal(_)))
+        .map(|(index, _)| index);
+
+    for change in changes {
+        let start = change.saturating_sub(CONTEXT_LINES);
+        let end = (change + CONTEXT_LINES + 1).min(edits.len());
+        match hunks.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+    hunks
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::equal::EqualityAssertion;
+    use crate::matchers::diff::unified_diff;
+
+    #[test]
+    fn should_have_no_diff_for_same_lines() {
+        unified_diff("a", "b", "one\ntwo", "one\ntwo\n").is_empty().should_be_true();
+    }
+
+    #[test]
+    fn should_diff_changed_line() {
+        let diff = unified_diff("expected", "actual", "one\ntwo\nthree", "one\n2\nthree");
+        diff.should_equal("--- expected\n+++ actual\n@@ -1,3 +1,3 @@\n one\n-two\n+2\n three\n");
+    }
+
+    #[test]
+    fn should_diff_with_limited_context() {
+        let old = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10";
+        let new = "1\n2\n3\n4\n5\n6\n7\n8\n9\nten";
+        let diff = unified_diff("old", "new", old, new);
+        diff.should_equal("--- old\n+++ new\n@@ -7,4 +7,4 @@\n 7\n 8\n 9\n-10\n+ten\n");
+    }
+
+    #[test]
+    fn should_diff_into_separate_hunks() {
+        let old = "a\n1\n2\n3\n4\n5\n6\n7\n8\nb";
+        let new = "A\n1\n2\n3\n4\n5\n6\n7\n8\nB";
+        let diff = unified_diff("old", "new", old, new);
+        diff.should_equal(
+            "--- old\n+++ new\n@@ -1,4 +1,4 @@\n-a\n+A\n 1\n 2\n 3\n@@ -7,4 +7,4 @@\n 6\n 7\n 8\n-b\n+B\n",
+        );
+    }
+
+    #[test]
+    fn should_diff_interleaved_changes() {
+        let diff = unified_diff("old", "new", "a\nb\nc\na\nb\nb\na", "c\nb\na\nb\na\nc");
+        diff.should_equal("--- old\n+++ new\n@@ -1,7 +1,6 @@\n-a\n-b\n c\n-a\n b\n+a\n b\n a\n+c\n");
+    }
+
+    #[test]
+    fn should_diff_large_inputs() {
+        let old = (0..20_000).map(|line| line.to_string()).collect::<Vec<_>>().join("\n");
+        let new = old.replace("\n10000\n", "\nten thousand\n");
+        let diff = unified_diff("old", "new", &old, &new);
+        diff.should_equal("--- old\n+++ new\n@@ -9998,7 +9998,7 @@\n 9997\n 9998\n 9999\n-10000\n+ten thousand\n 10001\n 10002\n 10003\n");
+    }
+
+    #[test]
+    fn should_diff_added_lines() {
+        let diff = unified_diff("old", "new", "", "one");
+        diff.should_equal("--- old\n+++ new\n@@ -1,0 +1,1 @@\n+one\n");
+    }
+}
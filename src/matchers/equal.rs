@@ -6,7 +6,7 @@ use crate::matchers::{Matcher, MatcherResult};
 ///
 /// Works with any data type that implements the Eq trait.
 ///
-/// clearcheck implements EqualityMatcher for any T: Eq + Debug.
+/// clearcheck implements EqualityMatcher for any T: Eq + Debug, and `EqualityMatcher<&T>` for any (possibly unsized) T: Eq + Debug.
 ///
 /// # Example
 ///```
@@ -76,6 +76,16 @@ impl<T: Eq + Debug> Matcher<T> for EqualityMatcher<T> {
     }
 }
 
+impl<T: Eq + Debug + ?Sized> Matcher<T> for EqualityMatcher<&T> {
+    fn test(&self, value: &T) -> MatcherResult {
+        MatcherResult::formatted(
+            value == self.other,
+            format!("{:?} should equal {:?}", value, self.other),
+            format!("{:?} should not equal {:?}", value, self.other),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::assertions::bool::TrueFalseAssertion;
@@ -126,4 +136,17 @@ mod tests {
         let matcher = be_equal(target);
         matcher.test(&books).passed.should_be_true();
     }
+
+    #[test]
+    fn should_equal_unsized_value() {
+        let matcher = be_equal("clearcheck");
+        matcher.test("clearcheck").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_equal_unsized_value_but_was_not() {
+        let matcher = be_equal("clearcheck");
+        matcher.test("junit").passed.should_be_true();
+    }
 }
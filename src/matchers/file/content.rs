@@ -0,0 +1,426 @@
+use std::fmt::Debug;
+use std::fs;
+use std::ops::{Range, RangeInclusive};
+use std::path::{Path, PathBuf};
+
+use crate::matchers::{Matcher, MatcherResult};
+use crate::matchers::diff::unified_diff;
+
+/// FileContentMatcher offers a flexible way to make assertions about the content and the size of a file.
+///
+/// A file that can not be read fails both the assertion and its negation, with the read error in the message.
+///
+/// # Example
+///```
+/// use std::fs;
+/// use tempdir::TempDir;
+/// use clearcheck::matchers::file::content::have_content;
+/// use clearcheck::matchers::Matcher;
+///
+/// let temporary_directory = TempDir::new(".").unwrap();
+/// let file_path = temporary_directory.path().join("clearcheck.txt");
+/// fs::write(&file_path, "assert4rs").unwrap();
+///
+/// let matcher = have_content("assert4rs");
+/// assert!(matcher.test(&file_path).passed());
+/// ```
+pub enum FileContentMatcher {
+    Content(String),
+    Bytes(Vec<u8>),
+    LineCount(usize),
+    ContainLine(String),
+    SameContentAs(PathBuf),
+    Size(u64),
+    SizeInInclusiveRange(RangeInclusive<u64>),
+    SizeInExclusiveRange(Range<u64>),
+}
+
+/// ContentMatcher offers a flexible way to assert that the (text) content of a file matches the given string matcher.
+///
+/// Any string matcher, like the ones in [`crate::matchers::string`], can be used.
+///
+/// # Example
+///```
+/// use std::fs;
+/// use tempdir::TempDir;
+/// use clearcheck::matchers::file::content::have_content_matching;
+/// use clearcheck::matchers::string::boundary::begin_with;
+/// use clearcheck::matchers::Matcher;
+///
+/// let temporary_directory = TempDir::new(".").unwrap();
+/// let file_path = temporary_directory.path().join("clearcheck.txt");
+/// fs::write(&file_path, "assert4rs").unwrap();
+///
+/// let matcher = have_content_matching(begin_with("assert"));
+/// assert!(matcher.test(&file_path).passed());
+/// ```
+pub struct ContentMatcher<M: Matcher<str>> {
+    matcher: M,
+}
+
+impl<T: AsRef<Path> + Debug + ?Sized> Matcher<T> for FileContentMatcher {
+    fn test(&self, value: &T) -> MatcherResult {
+        match self {
+            FileContentMatcher::Content(expected) => match read_text(value) {
+                Ok(content) => MatcherResult::formatted(
+                    &content == expected,
+                    format!(
+                        "{:?} should have the content {:?} but was {:?}",
+                        value, expected, content
+                    ),
+                    format!("{:?} should not have the content {:?}", value, expected),
+                ),
+                Err(result) => result,
+            },
+            FileContentMatcher::Bytes(expected) => match fs::read(value) {
+                Ok(content) => MatcherResult::formatted(
+                    &content == expected,
+                    format!(
+                        "{:?} should have the bytes {:?} but was {:?}",
+                        value, expected, content
+                    ),
+                    format!("{:?} should not have the bytes {:?}", value, expected),
+                ),
+                Err(error) => unreadable(value, error),
+            },
+            FileContentMatcher::LineCount(expected) => match read_text(value) {
+                Ok(content) => {
+                    let line_count = content.lines().count();
+                    MatcherResult::formatted(
+                        line_count == *expected,
+                        format!(
+                            "{:?} should have {} lines but had {} lines",
+                            value, expected, line_count
+                        ),
+                        format!("{:?} should not have {} lines", value, expected),
+                    )
+                }
+                Err(result) => result,
+            },
+            FileContentMatcher::ContainLine(line) => match read_text(value) {
+                Ok(content) => MatcherResult::formatted(
+                    content.lines().any(|source| source == line),
+                    format!("{:?} should contain the line {:?}", value, line),
+                    format!("{:?} should not contain the line {:?}", value, line),
+                ),
+                Err(result) => result,
+            },
+            FileContentMatcher::SameContentAs(other) => match (read_text(value), read_text(other)) {
+                (Ok(content), Ok(other_content)) => MatcherResult::formatted(
+                    content == other_content,
+                    format!(
+                        "{:?} should have the same content as {:?}\n{}",
+                        value,
+                        other,
+                        unified_diff(
+                            &other.display().to_string(),
+                            &value.as_ref().display().to_string(),
+                            &other_content,
+                            &content
+                        )
+                    ),
+                    format!("{:?} should not have the same content as {:?}", value, other),
+                ),
+                (Err(result), _) | (_, Err(result)) => result,
+            },
+            FileContentMatcher::Size(expected) => match fs::metadata(value) {
+                Ok(metadata) => MatcherResult::formatted(
+                    metadata.len() == *expected,
+                    format!(
+                        "{:?} should have the size {} bytes but was {} bytes",
+                        value,
+                        expected,
+                        metadata.len()
+                    ),
+                    format!("{:?} should not have the size {} bytes", value, expected),
+                ),
+                Err(error) => unreadable(value, error),
+            },
+            FileContentMatcher::SizeInInclusiveRange(range) => match fs::metadata(value) {
+                Ok(metadata) => MatcherResult::formatted(
+                    range.contains(&metadata.len()),
+                    format!(
+                        "{:?} should have the size in the range {:?} bytes but was {} bytes",
+                        value,
+                        range,
+                        metadata.len()
+                    ),
+                    format!(
+                        "{:?} should not have the size in the range {:?} bytes but was {} bytes",
+                        value,
+                        range,
+                        metadata.len()
+                    ),
+                ),
+                Err(error) => unreadable(value, error),
+            },
+            FileContentMatcher::SizeInExclusiveRange(range) => match fs::metadata(value) {
+                Ok(metadata) => MatcherResult::formatted(
+                    range.contains(&metadata.len()),
+                    format!(
+                        "{:?} should have the size in the range {:?} bytes but was {} bytes",
+                        value,
+                        range,
+                        metadata.len()
+                    ),
+                    format!(
+                        "{:?} should not have the size in the range {:?} bytes but was {} bytes",
+                        value,
+                        range,
+                        metadata.len()
+                    ),
+                ),
+                Err(error) => unreadable(value, error),
+            },
+        }
+    }
+}
+
+impl<T: AsRef<Path> + Debug + ?Sized, M: Matcher<str>> Matcher<T> for ContentMatcher<M> {
+    fn test(&self, value: &T) -> MatcherResult {
+        match read_text(value) {
+            Ok(content) => {
+                let result = self.matcher.test(&content);
+                MatcherResult::formatted(
+                    result.passed,
+                    format!("{:?} content did not match: {}", value, result.failure_message),
+                    format!("{:?} content matched: {}", value, result.inverted_failure_message),
+                )
+            }
+            Err(result) => result,
+        }
+    }
+}
+
+pub(crate) fn read_text<T: AsRef<Path> + Debug + ?Sized>(value: &T) -> Result<String, MatcherResult> {
+    fs::read_to_string(value).map_err(|error| unreadable(value, error))
+}
+
+pub(crate) fn unreadable<T: Debug + ?Sized>(value: &T, error: std::io::Error) -> MatcherResult {
+    MatcherResult::errored(format!("{:?} should be readable but failed with: {}", value, error))
+}
+
+/// Creates a FileContentMatcher that asserts whether the (text) content of a file is the expected content.
+pub fn have_content(expected: &str) -> FileContentMatcher {
+    FileContentMatcher::Content(expected.to_string())
+}
+
+/// Creates a FileContentMatcher that asserts whether the content of a file is the expected bytes.
+pub fn have_bytes(expected: &[u8]) -> FileContentMatcher {
+    FileContentMatcher::Bytes(expected.to_vec())
+}
+
+/// Creates a FileContentMatcher that asserts whether a file has the given number of lines.
+pub fn have_line_count(line_count: usize) -> FileContentMatcher {
+    FileContentMatcher::LineCount(line_count)
+}
+
+/// Creates a FileContentMatcher that asserts whether a file contains the given line (without the line terminator).
+pub fn contain_line(line: &str) -> FileContentMatcher {
+    FileContentMatcher::ContainLine(line.to_string())
+}
+
+/// Creates a FileContentMatcher that asserts whether a file has the same (text) content as the other file, failing with a unified diff.
+pub fn have_same_content_as<P: AsRef<Path>>(other: P) -> FileContentMatcher {
+    FileContentMatcher::SameContentAs(other.as_ref().to_path_buf())
+}
+
+/// Creates a FileContentMatcher that asserts whether a file has the given size in bytes.
+pub fn have_size_in_bytes(size: u64) -> FileContentMatcher {
+    FileContentMatcher::Size(size)
+}
+
+/// Creates a FileContentMatcher that asserts whether the size of a file (in bytes) falls within the given inclusive range.
+pub fn have_size_in_inclusive_range(range: RangeInclusive<u64>) -> FileContentMatcher {
+    FileContentMatcher::SizeInInclusiveRange(range)
+}
+
+/// Creates a FileContentMatcher that asserts whether the size of a file (in bytes) falls within the given exclusive range.
+pub fn have_size_in_exclusive_range(range: Range<u64>) -> FileContentMatcher {
+    FileContentMatcher::SizeInExclusiveRange(range)
+}
+
+/// Creates a ContentMatcher that asserts whether the (text) content of a file matches the given string matcher.
+pub fn have_content_matching<M: Matcher<str>>(matcher: M) -> ContentMatcher<M> {
+    ContentMatcher { matcher }
+}
+
+#[cfg(all(test, feature = "file"))]
+mod tests {
+    use tempdir::TempDir;
+
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::string::membership::MembershipAssertion;
+    use crate::matchers::file::content::{
+        contain_line, have_bytes, have_content, have_content_matching, have_line_count,
+        have_same_content_as, have_size_in_bytes, have_size_in_exclusive_range,
+        have_size_in_inclusive_range,
+    };
+    use crate::matchers::Matcher;
+    use crate::matchers::string::membership::contain;
+    use crate::matchers::file::test_support::file_with;
+
+    #[test]
+    fn should_have_content() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "assert4rs");
+
+        let matcher = have_content("assert4rs");
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_content_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "assert4rs");
+
+        let matcher = have_content("junit");
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_fail_for_missing_file() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = directory.path().join("missing.txt");
+
+        let result = have_content("junit").test(&file_path);
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should be readable but failed with");
+    }
+
+    #[test]
+    fn should_have_content_matching() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "assert4rs");
+
+        let matcher = have_content_matching(contain("4rs"));
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_content_matching_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "assert4rs");
+
+        let matcher = have_content_matching(contain("junit"));
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_bytes() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "ab");
+
+        let matcher = have_bytes(&[97, 98]);
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_bytes_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "ab");
+
+        let matcher = have_bytes(&[97]);
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_line_count() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "one\ntwo\nthree\n");
+
+        let matcher = have_line_count(3);
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_line_count_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "one\ntwo");
+
+        let matcher = have_line_count(3);
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_contain_line() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "one\ntwo\r\nthree");
+
+        let matcher = contain_line("two");
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_contain_line_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "one\ntwo words");
+
+        let matcher = contain_line("two");
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_same_content_as() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "one\ntwo");
+        let other_path = file_with(&directory, "other.txt", "one\ntwo");
+
+        let matcher = have_same_content_as(other_path);
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_report_diff_for_different_content() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "one\n2\nthree");
+        let other_path = file_with(&directory, "other.txt", "one\ntwo\nthree");
+
+        let result = have_same_content_as(&other_path).test(&file_path);
+        result.passed.should_be_false();
+        result.failure_message.should_contain("@@ -1,3 +1,3 @@\n one\n-two\n+2\n three\n");
+    }
+
+    #[test]
+    fn should_have_size_in_bytes() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "assert4rs");
+
+        let matcher = have_size_in_bytes(9);
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_size_in_bytes_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "assert4rs");
+
+        let matcher = have_size_in_bytes(10);
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_size_in_inclusive_range() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "assert4rs");
+
+        let matcher = have_size_in_inclusive_range(1..=9);
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_size_in_exclusive_range_but_did_not() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "clearcheck.txt", "assert4rs");
+
+        let matcher = have_size_in_exclusive_range(1..9);
+        matcher.test(&file_path).passed.should_be_true();
+    }
+}
@@ -0,0 +1,150 @@
+use std::fmt::Debug;
+use std::path::Path;
+
+use csv::{Reader, StringRecord};
+
+use crate::matchers::{Matcher, MatcherResult};
+
+/// CsvFileMatcher offers a flexible way to make assertions about files containing CSV with a header row.
+///
+/// A file that can not be read or parsed fails both the assertion and its negation, with the error in the message.
+///
+/// # Example
+///```
+/// use std::fs;
+/// use tempdir::TempDir;
+/// use clearcheck::matchers::file::csv::have_csv_rows;
+/// use clearcheck::matchers::Matcher;
+///
+/// let temporary_directory = TempDir::new(".").unwrap();
+/// let file_path = temporary_directory.path().join("users.csv");
+/// fs::write(&file_path, "id,name\n1,clearcheck\n2,junit\n").unwrap();
+///
+/// let matcher = have_csv_rows(2);
+/// assert!(matcher.test(&file_path).passed());
+/// ```
+pub enum CsvFileMatcher {
+    Rows(usize),
+    Header(Vec<String>),
+}
+
+impl<T: AsRef<Path> + Debug + ?Sized> Matcher<T> for CsvFileMatcher {
+    fn test(&self, value: &T) -> MatcherResult {
+        let (header, rows) = match read_csv(value.as_ref()) {
+            Ok(csv) => csv,
+            Err(error) => {
+                let message = format!(
+                    "{:?} should contain valid csv but failed with: {}",
+                    value, error
+                );
+                return MatcherResult::errored(message);
+            }
+        };
+        match self {
+            CsvFileMatcher::Rows(expected) => MatcherResult::formatted(
+                rows == *expected,
+                format!(
+                    "{:?} should have {} csv rows (excluding the header) but had {}",
+                    value, expected, rows
+                ),
+                format!(
+                    "{:?} should not have {} csv rows (excluding the header)",
+                    value, expected
+                ),
+            ),
+            CsvFileMatcher::Header(expected) => MatcherResult::formatted(
+                header.iter().eq(expected.iter()),
+                format!(
+                    "{:?} should have the csv header {:?} but was {:?}",
+                    value,
+                    expected,
+                    header.iter().collect::<Vec<_>>()
+                ),
+                format!("{:?} should not have the csv header {:?}", value, expected),
+            ),
+        }
+    }
+}
+
+fn read_csv(path: &Path) -> Result<(StringRecord, usize), csv::Error> {
+    let mut reader = Reader::from_path(path)?;
+    let header = reader.headers()?.clone();
+    let mut rows = 0;
+    for record in reader.records() {
+        record?;
+        rows += 1;
+    }
+    Ok((header, rows))
+}
+
+/// Creates a CsvFileMatcher that asserts whether the file has the given number of csv rows, excluding the header row.
+pub fn have_csv_rows(rows: usize) -> CsvFileMatcher {
+    CsvFileMatcher::Rows(rows)
+}
+
+/// Creates a CsvFileMatcher that asserts whether the header row of the csv file is exactly the given columns.
+pub fn have_csv_header(columns: &[&str]) -> CsvFileMatcher {
+    CsvFileMatcher::Header(columns.iter().map(|column| column.to_string()).collect())
+}
+
+#[cfg(all(test, feature = "csv"))]
+mod tests {
+    use tempdir::TempDir;
+
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::string::membership::MembershipAssertion;
+    use crate::matchers::file::csv::{have_csv_header, have_csv_rows};
+    use crate::matchers::Matcher;
+    use crate::matchers::file::test_support::file_with;
+
+    #[test]
+    fn should_have_csv_rows() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "users.csv", "id,name\n1,clearcheck\n2,junit\n");
+
+        let matcher = have_csv_rows(2);
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_have_csv_rows() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "users.csv", "id,name\n1,clearcheck\n");
+
+        let matcher = have_csv_rows(2);
+        let result = matcher.test(&file_path);
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should have 2 csv rows (excluding the header) but had 1");
+    }
+
+    #[test]
+    fn should_not_have_csv_rows_given_a_malformed_row() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "users.csv", "id,name\n1,clearcheck,extra\n");
+
+        let matcher = have_csv_rows(1);
+        let result = matcher.test(&file_path);
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should contain valid csv but failed with");
+    }
+
+    #[test]
+    fn should_have_csv_header() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "users.csv", "id,name\n1,clearcheck\n");
+
+        let matcher = have_csv_header(&["id", "name"]);
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_have_csv_header() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "users.csv", "id,name\n1,clearcheck\n");
+
+        let matcher = have_csv_header(&["name", "id"]);
+        let result = matcher.test(&file_path);
+        result.passed.should_be_false();
+        result.failure_message.should_contain("but was [\"id\", \"name\"]");
+    }
+}
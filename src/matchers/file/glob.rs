@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+
+// A minimal glob implementation used to match slash-separated relative paths.
+// `*` matches any sequence within a path component, `?` matches a single character within a path component,
+// and `**` matches any sequence across path components (`a/**/b` also matches `a/b`).
+pub(crate) fn matches_glob(pattern: &str, path: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let path = path.chars().collect::<Vec<_>>();
+    matches(&pattern, &path, &mut HashMap::new())
+}
+
+// A pattern without a separator applies to any path component, otherwise it applies to the whole path.
+// Matching any of the ancestors also matches the path, so that a directory pattern covers everything beneath it.
+pub(crate) fn matches_path_or_ancestor(pattern: &str, relative_path: &str) -> bool {
+    let components = relative_path.split('/').collect::<Vec<_>>();
+    (0..components.len()).any(|index| {
+        matches_glob(pattern, &components[..=index].join("/"))
+            || (!pattern.contains('/') && matches_glob(pattern, components[index]))
+    })
+}
+
+// Both slices are always suffixes of the original pattern and path, so the results are memoized on their lengths.
+// This keeps patterns with several `*` or `**` segments from backtracking exponentially.
+fn matches(pattern: &[char], path: &[char], memo: &mut HashMap<(usize, usize), bool>) -> bool {
+    if let Some(matched) = memo.get(&(pattern.len(), path.len())) {
+        return *matched;
+    }
+    let matched = match pattern {
+        [] => path.is_empty(),
+        ['*', '*', '/', rest @ ..] => {
+            matches(rest, path, memo)
+                || (0..=path.len()).any(|index| matches(&pattern[2..], &path[index..], memo))
+        }
+        ['*', '*', rest @ ..] => (0..=path.len()).any(|index| matches(rest, &path[index..], memo)),
+        ['*', rest @ ..] => {
+            let component_length = path.iter().position(|ch| *ch == '/').unwrap_or(path.len());
+            (0..=component_length).any(|index| matches(rest, &path[index..], memo))
+        }
+        ['?', rest @ ..] => matches!(path, [ch, ..] if *ch != '/') && matches(rest, &path[1..], memo),
+        [expected, rest @ ..] => {
+            matches!(path, [ch, ..] if ch == expected) && matches(rest, &path[1..], memo)
+        }
+    };
+    memo.insert((pattern.len(), path.len()), matched);
+    matched
+}
+
+#[cfg(all(test, feature = "file"))]
+mod tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::file::glob::{matches_glob, matches_path_or_ancestor};
+
+    #[test]
+    fn should_match_literal() {
+        matches_glob("src/lib.rs", "src/lib.rs").should_be_true();
+    }
+
+    #[test]
+    fn should_match_single_star_within_component() {
+        matches_glob("src/*.rs", "src/lib.rs").should_be_true();
+    }
+
+    #[test]
+    fn should_not_match_single_star_across_components() {
+        matches_glob("src/*.rs", "src/matchers/mod.rs").should_be_false();
+    }
+
+    #[test]
+    fn should_match_double_star_across_components() {
+        matches_glob("src/**/*.rs", "src/matchers/file/mod.rs").should_be_true();
+    }
+
+    #[test]
+    fn should_match_double_star_with_no_components() {
+        matches_glob("src/**/*.rs", "src/lib.rs").should_be_true();
+    }
+
+    #[test]
+    fn should_match_question_mark() {
+        matches_glob("file?.txt", "file1.txt").should_be_true();
+    }
+
+    #[test]
+    fn should_not_match_question_mark_against_separator() {
+        matches_glob("src?lib.rs", "src/lib.rs").should_be_false();
+    }
+
+    #[test]
+    fn should_not_match_many_stars_without_backtracking_exponentially() {
+        let path = "a".repeat(200);
+        matches_glob("*a*a*a*a*a*a*a*a*a*a*b", &path).should_be_false();
+    }
+
+    #[test]
+    fn should_not_match_many_double_stars_without_backtracking_exponentially() {
+        let path = vec!["a"; 100].join("/");
+        matches_glob("**/a/**/a/**/a/**/a/**/a/**/b", &path).should_be_false();
+    }
+
+    #[test]
+    fn should_match_ancestor_directory() {
+        matches_path_or_ancestor("target", "target/debug/build").should_be_true();
+    }
+
+    #[test]
+    fn should_match_any_component_for_pattern_without_separator() {
+        matches_path_or_ancestor("*.log", "logs/server.log").should_be_true();
+    }
+
+    #[test]
+    fn should_not_match_unrelated_path() {
+        matches_path_or_ancestor("target", "src/target.rs").should_be_false();
+    }
+}
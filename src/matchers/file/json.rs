@@ -0,0 +1,150 @@
+use std::fmt::Debug;
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::matchers::{Matcher, MatcherResult};
+use crate::matchers::diff::unified_diff;
+use crate::matchers::file::content::read_text;
+
+/// JsonFileMatcher offers a flexible way to make assertions about files containing JSON.
+///
+/// A file that can not be read, or can not be parsed when compared with the expected json, fails both the assertion and its negation,
+/// with the error in the message.
+/// JSON values are compared structurally, so the formatting and the order of object keys do not matter.
+///
+/// # Example
+///```
+/// use std::fs;
+/// use serde_json::json;
+/// use tempdir::TempDir;
+/// use clearcheck::matchers::file::json::contain_json_equal_to;
+/// use clearcheck::matchers::Matcher;
+///
+/// let temporary_directory = TempDir::new(".").unwrap();
+/// let file_path = temporary_directory.path().join("config.json");
+/// fs::write(&file_path, r#"{"name": "clearcheck", "features": ["json"]}"#).unwrap();
+///
+/// let matcher = contain_json_equal_to(json!({"features": ["json"], "name": "clearcheck"}));
+/// assert!(matcher.test(&file_path).passed());
+/// ```
+pub enum JsonFileMatcher {
+    Valid,
+    EqualTo(Value),
+}
+
+impl<T: AsRef<Path> + Debug + ?Sized> Matcher<T> for JsonFileMatcher {
+    fn test(&self, value: &T) -> MatcherResult {
+        let content = match read_text(value) {
+            Ok(content) => content,
+            Err(result) => return result,
+        };
+        let parsed = serde_json::from_str::<Value>(&content);
+        match self {
+            JsonFileMatcher::Valid => MatcherResult::formatted(
+                parsed.is_ok(),
+                format!(
+                    "{:?} should contain valid json but failed to parse: {}",
+                    value,
+                    parsed.as_ref().err().map(|error| error.to_string()).unwrap_or_default()
+                ),
+                format!("{:?} should not contain valid json", value),
+            ),
+            JsonFileMatcher::EqualTo(expected) => match parsed {
+                Ok(actual) => MatcherResult::formatted(
+                    &actual == expected,
+                    format!(
+                        "{:?} should contain json equal to the expected json\n{}",
+                        value,
+                        unified_diff("expected", "actual", &pretty(expected), &pretty(&actual))
+                    ),
+                    format!("{:?} should not contain json equal to {}", value, expected),
+                ),
+                Err(error) => {
+                    let message = format!(
+                        "{:?} should contain valid json but failed to parse: {}",
+                        value, error
+                    );
+                    MatcherResult::errored(message)
+                }
+            },
+        }
+    }
+}
+
+fn pretty(value: &Value) -> String {
+    serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string())
+}
+
+/// Creates a JsonFileMatcher that asserts whether the file contains valid JSON.
+pub fn contain_valid_json() -> JsonFileMatcher {
+    JsonFileMatcher::Valid
+}
+
+/// Creates a JsonFileMatcher that asserts whether the file contains JSON structurally equal to the expected value.
+pub fn contain_json_equal_to(expected: Value) -> JsonFileMatcher {
+    JsonFileMatcher::EqualTo(expected)
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use serde_json::json;
+    use tempdir::TempDir;
+
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::string::membership::MembershipAssertion;
+    use crate::matchers::file::json::{contain_json_equal_to, contain_valid_json};
+    use crate::matchers::Matcher;
+    use crate::matchers::file::test_support::file_with;
+
+    #[test]
+    fn should_contain_valid_json() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "config.json", r#"{"name": "clearcheck"}"#);
+
+        let matcher = contain_valid_json();
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_contain_valid_json() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "config.json", r#"{"name": }"#);
+
+        let matcher = contain_valid_json();
+        let result = matcher.test(&file_path);
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should contain valid json but failed to parse: expected value at line 1");
+    }
+
+    #[test]
+    fn should_contain_json_equal_to_ignoring_key_order() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "config.json", r#"{"name": "clearcheck", "version": 2}"#);
+
+        let matcher = contain_json_equal_to(json!({"version": 2, "name": "clearcheck"}));
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_contain_json_equal_to_with_a_diff() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "config.json", r#"{"name": "clearcheck", "version": 2}"#);
+
+        let matcher = contain_json_equal_to(json!({"name": "clearcheck", "version": 3}));
+        let result = matcher.test(&file_path);
+        result.passed.should_be_false();
+        result.failure_message.should_contain("-  \"version\": 3\n+  \"version\": 2");
+    }
+
+    #[test]
+    fn should_not_contain_json_equal_to_given_invalid_json() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = file_with(&directory, "config.json", "name = 'clearcheck'");
+
+        let matcher = contain_json_equal_to(json!({"name": "clearcheck"}));
+        let result = matcher.test(&file_path);
+        result.passed.should_be_false();
+        result.failure_message.should_contain("failed to parse");
+    }
+}
@@ -0,0 +1,326 @@
+use std::fmt::Debug;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::matchers::{Matcher, MatcherResult};
+
+/// FileMetadataMatcher offers a flexible way to make assertions about timestamps, visibility, emptiness and link targets of files and directories.
+///
+/// A path whose metadata can not be read fails both the assertion and its negation, with the error in the message.
+///
+/// # Example
+///```
+/// use std::fs::File;
+/// use std::time::{Duration, SystemTime};
+/// use tempdir::TempDir;
+/// use clearcheck::matchers::file::metadata::have_been_modified_after;
+/// use clearcheck::matchers::Matcher;
+///
+/// let temporary_directory = TempDir::new(".").unwrap();
+/// let file_path = temporary_directory.path().join("clearcheck.txt");
+/// let _ = File::create(&file_path).unwrap();
+///
+/// let matcher = have_been_modified_after(SystemTime::now() - Duration::from_secs(60));
+/// assert!(matcher.test(&file_path).passed());
+/// ```
+pub enum FileMetadataMatcher {
+    ModifiedAfter(SystemTime),
+    ModifiedBefore(SystemTime),
+    NewerThan(PathBuf),
+    Hidden,
+    EmptyDirectory,
+    SymlinkTo(PathBuf),
+}
+
+impl<T: AsRef<Path> + Debug + ?Sized> Matcher<T> for FileMetadataMatcher {
+    fn test(&self, value: &T) -> MatcherResult {
+        match self {
+            FileMetadataMatcher::ModifiedAfter(time) => match modified(value) {
+                Ok(modified) => MatcherResult::formatted(
+                    modified > *time,
+                    format!(
+                        "{:?} should have been modified after {:?} but was modified at {:?}",
+                        value, time, modified
+                    ),
+                    format!(
+                        "{:?} should not have been modified after {:?} but was modified at {:?}",
+                        value, time, modified
+                    ),
+                ),
+                Err(result) => result,
+            },
+            FileMetadataMatcher::ModifiedBefore(time) => match modified(value) {
+                Ok(modified) => MatcherResult::formatted(
+                    modified < *time,
+                    format!(
+                        "{:?} should have been modified before {:?} but was modified at {:?}",
+                        value, time, modified
+                    ),
+                    format!(
+                        "{:?} should not have been modified before {:?} but was modified at {:?}",
+                        value, time, modified
+                    ),
+                ),
+                Err(result) => result,
+            },
+            FileMetadataMatcher::NewerThan(other) => match (modified(value), modified(other)) {
+                (Ok(modified), Ok(other_modified)) => MatcherResult::formatted(
+                    modified > other_modified,
+                    format!(
+                        "{:?} should be newer than {:?} but was modified at {:?} and {:?} at {:?}",
+                        value, other, modified, other, other_modified
+                    ),
+                    format!(
+                        "{:?} should not be newer than {:?} but was modified at {:?} and {:?} at {:?}",
+                        value, other, modified, other, other_modified
+                    ),
+                ),
+                (Err(result), _) | (_, Err(result)) => result,
+            },
+            FileMetadataMatcher::Hidden => MatcherResult::formatted(
+                value
+                    .as_ref()
+                    .file_name()
+                    .is_some_and(|file_name| file_name.to_string_lossy().starts_with('.')),
+                format!("{:?} should be hidden", value),
+                format!("{:?} should not be hidden", value),
+            ),
+            FileMetadataMatcher::EmptyDirectory => match fs::read_dir(value) {
+                Ok(mut entries) => MatcherResult::formatted(
+                    entries.next().is_none(),
+                    format!("{:?} should be an empty directory", value),
+                    format!("{:?} should not be an empty directory", value),
+                ),
+                Err(error) => MatcherResult::errored(format!(
+                    "{:?} should be a readable directory but failed with: {}",
+                    value, error
+                )),
+            },
+            FileMetadataMatcher::SymlinkTo(target) => match fs::read_link(value) {
+                Ok(actual_target) => MatcherResult::formatted(
+                    &actual_target == target,
+                    format!(
+                        "{:?} should be a symbolic link to {:?} but was a link to {:?}",
+                        value, target, actual_target
+                    ),
+                    format!("{:?} should not be a symbolic link to {:?}", value, target),
+                ),
+                Err(_) => MatcherResult::formatted(
+                    false,
+                    format!(
+                        "{:?} should be a symbolic link to {:?} but was not a symbolic link",
+                        value, target
+                    ),
+                    format!("{:?} should not be a symbolic link to {:?}", value, target),
+                ),
+            },
+        }
+    }
+}
+
+fn modified<T: AsRef<Path> + Debug + ?Sized>(value: &T) -> Result<SystemTime, MatcherResult> {
+    fs::metadata(value)
+        .and_then(|metadata| metadata.modified())
+        .map_err(|error| {
+            let message = format!(
+                "{:?} should have a readable modification time but failed with: {}",
+                value, error
+            );
+            MatcherResult::errored(message)
+        })
+}
+
+/// Creates a FileMetadataMatcher that asserts whether the path was last modified after the given time.
+pub fn have_been_modified_after(time: SystemTime) -> FileMetadataMatcher {
+    FileMetadataMatcher::ModifiedAfter(time)
+}
+
+/// Creates a FileMetadataMatcher that asserts whether the path was last modified before the given time.
+pub fn have_been_modified_before(time: SystemTime) -> FileMetadataMatcher {
+    FileMetadataMatcher::ModifiedBefore(time)
+}
+
+/// Creates a FileMetadataMatcher that asserts whether the path was last modified after the other path.
+pub fn be_newer_than<P: AsRef<Path>>(other: P) -> FileMetadataMatcher {
+    FileMetadataMatcher::NewerThan(other.as_ref().to_path_buf())
+}
+
+/// Creates a FileMetadataMatcher that asserts whether the path is hidden, following the unix convention of a file name starting with a `.`.
+pub fn be_hidden() -> FileMetadataMatcher {
+    FileMetadataMatcher::Hidden
+}
+
+/// Creates a FileMetadataMatcher that asserts whether the path is a directory without any entries.
+pub fn be_an_empty_directory() -> FileMetadataMatcher {
+    FileMetadataMatcher::EmptyDirectory
+}
+
+/// Creates a FileMetadataMatcher that asserts whether the path is a symbolic link pointing to the given target (as stored in the link, without resolving it).
+pub fn be_a_symlink_to<P: AsRef<Path>>(target: P) -> FileMetadataMatcher {
+    FileMetadataMatcher::SymlinkTo(target.as_ref().to_path_buf())
+}
+
+#[cfg(all(test, feature = "file"))]
+mod tests {
+    use std::fs;
+    use std::fs::File;
+    use std::time::{Duration, SystemTime};
+
+    use tempdir::TempDir;
+
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::string::membership::MembershipAssertion;
+    use crate::matchers::file::metadata::{
+        be_a_symlink_to, be_an_empty_directory, be_hidden, be_newer_than,
+        have_been_modified_after, have_been_modified_before,
+    };
+    use crate::matchers::Matcher;
+
+    #[test]
+    fn should_have_been_modified_after() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = directory.path().join("clearcheck.txt");
+        let _ = File::create(&file_path).unwrap();
+
+        let matcher = have_been_modified_after(SystemTime::now() - Duration::from_secs(60));
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_have_been_modified_after() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = directory.path().join("clearcheck.txt");
+        let _ = File::create(&file_path).unwrap();
+
+        let matcher = have_been_modified_after(SystemTime::now() + Duration::from_secs(60));
+        matcher.test(&file_path).passed.should_be_false();
+    }
+
+    #[test]
+    fn should_have_been_modified_before() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = directory.path().join("clearcheck.txt");
+        let _ = File::create(&file_path).unwrap();
+
+        let matcher = have_been_modified_before(SystemTime::now() + Duration::from_secs(60));
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_fail_with_the_error_for_a_missing_file() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = directory.path().join("missing.txt");
+
+        let matcher = have_been_modified_after(SystemTime::UNIX_EPOCH);
+        let result = matcher.test(&file_path);
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should have a readable modification time but failed with");
+    }
+
+    #[test]
+    fn should_be_newer_than() {
+        let directory = TempDir::new(".").unwrap();
+        let older = directory.path().join("older.txt");
+        let newer = directory.path().join("newer.txt");
+        File::create(&older)
+            .unwrap()
+            .set_modified(SystemTime::now() - Duration::from_secs(3600))
+            .unwrap();
+        let _ = File::create(&newer).unwrap();
+
+        let matcher = be_newer_than(&older);
+        matcher.test(&newer).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_newer_than() {
+        let directory = TempDir::new(".").unwrap();
+        let older = directory.path().join("older.txt");
+        let newer = directory.path().join("newer.txt");
+        File::create(&older)
+            .unwrap()
+            .set_modified(SystemTime::now() - Duration::from_secs(3600))
+            .unwrap();
+        let _ = File::create(&newer).unwrap();
+
+        let matcher = be_newer_than(&newer);
+        matcher.test(&older).passed.should_be_false();
+    }
+
+    #[test]
+    fn should_be_hidden() {
+        let matcher = be_hidden();
+        matcher.test(".gitignore").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_hidden() {
+        let matcher = be_hidden();
+        matcher.test("src/.config/lib.rs").passed.should_be_false();
+    }
+
+    #[test]
+    fn should_be_an_empty_directory() {
+        let directory = TempDir::new(".").unwrap();
+        let matcher = be_an_empty_directory();
+        matcher.test(&directory.path()).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_an_empty_directory() {
+        let directory = TempDir::new(".").unwrap();
+        let _ = File::create(directory.path().join("clearcheck.txt")).unwrap();
+
+        let matcher = be_an_empty_directory();
+        matcher.test(&directory.path()).passed.should_be_false();
+    }
+
+    #[test]
+    fn should_not_be_an_empty_directory_given_a_file() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = directory.path().join("clearcheck.txt");
+        let _ = File::create(&file_path).unwrap();
+
+        let matcher = be_an_empty_directory();
+        let result = matcher.test(&file_path);
+        result.passed.should_be_false();
+        result.errored.should_be_true();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn should_be_a_symlink_to() {
+        let directory = TempDir::new(".").unwrap();
+        let link = directory.path().join("link");
+        std::os::unix::fs::symlink("target.txt", &link).unwrap();
+
+        let matcher = be_a_symlink_to("target.txt");
+        matcher.test(&link).passed.should_be_true();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn should_not_be_a_symlink_to() {
+        let directory = TempDir::new(".").unwrap();
+        let link = directory.path().join("link");
+        std::os::unix::fs::symlink("target.txt", &link).unwrap();
+
+        let matcher = be_a_symlink_to("other.txt");
+        let result = matcher.test(&link);
+        result.passed.should_be_false();
+        result.failure_message.should_contain("but was a link to \"target.txt\"");
+    }
+
+    #[test]
+    fn should_not_be_a_symlink_to_given_a_regular_file() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = directory.path().join("clearcheck.txt");
+        fs::write(&file_path, "").unwrap();
+
+        let matcher = be_a_symlink_to("target.txt");
+        let result = matcher.test(&file_path);
+        result.passed.should_be_false();
+        result.failure_message.should_contain("but was not a symbolic link");
+    }
+}
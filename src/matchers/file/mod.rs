@@ -2,12 +2,27 @@ use std::collections::HashSet;
 use std::ffi::OsStr;
 use std::fmt::Debug;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use walkdir::WalkDir;
 
 use crate::matchers::{Matcher, MatcherResult};
 
+pub mod content;
+#[cfg(feature = "csv")]
+pub mod csv;
+pub(crate) mod glob;
+#[cfg(feature = "json")]
+pub mod json;
+pub mod metadata;
+#[cfg(test)]
+pub(crate) mod test_support;
+#[cfg(feature = "toml")]
+pub mod toml;
+pub mod tree;
+#[cfg(unix)]
+pub mod unix;
+
 /// FileTypeMatcher offers a flexible way to make assertions about various file type properties like: regular file, directory, symbolic link etc.
 pub enum FileTypeMatcher {
     File,
@@ -19,10 +34,21 @@ pub enum FileTypeMatcher {
 }
 
 /// FilePathMatcher offers a flexible way to make assertions about various properties related to file paths.
+///
+/// Except for `Canonical` and `Exist`, the assertions only look at the path itself and do not touch the file system.
 pub enum FilePathMatcher {
     Absolute,
     Relative,
     Extension(&'static str),
+    ExtensionIgnoringCase(String),
+    FileName(String),
+    Stem(String),
+    Parent(PathBuf),
+    StartWith(PathBuf),
+    EndWith(PathBuf),
+    ComponentCount(usize),
+    Canonical,
+    Exist,
 }
 
 /// TreeMatcher offers a flexible way to make assertions about presence or absence of files or directories within a tree structure.
@@ -49,7 +75,7 @@ pub enum TreeMatcher {
     ContainAny(Vec<&'static str>),
 }
 
-impl<T: AsRef<Path> + Debug> Matcher<T> for FileTypeMatcher {
+impl<T: AsRef<Path> + Debug + ?Sized> Matcher<T> for FileTypeMatcher {
     fn test(&self, value: &T) -> MatcherResult {
         let metadata = fs::metadata(value);
         match &self {
@@ -87,7 +113,7 @@ impl<T: AsRef<Path> + Debug> Matcher<T> for FileTypeMatcher {
     }
 }
 
-impl<T: AsRef<Path> + Debug> Matcher<T> for FilePathMatcher {
+impl<T: AsRef<Path> + Debug + ?Sized> Matcher<T> for FilePathMatcher {
     fn test(&self, value: &T) -> MatcherResult {
         match self {
             FilePathMatcher::Absolute => MatcherResult::formatted(
@@ -107,13 +133,116 @@ impl<T: AsRef<Path> + Debug> Matcher<T> for FilePathMatcher {
                     .filter(|source| source == extension)
                     .is_some(),
                 format!("{:?} should have extension {:?}", value, extension),
-                format!("{:?} should not be have extension {:?}", value, extension),
+                format!("{:?} should not have extension {:?}", value, extension),
+            ),
+            FilePathMatcher::ExtensionIgnoringCase(extension) => {
+                let actual = value.as_ref().extension();
+                MatcherResult::formatted(
+                    actual.is_some_and(|source| {
+                        source.to_string_lossy().to_lowercase() == extension.to_lowercase()
+                    }),
+                    format!(
+                        "{:?} should have extension {:?} (ignoring case) but had {}",
+                        value,
+                        extension,
+                        describe(actual)
+                    ),
+                    format!(
+                        "{:?} should not have extension {:?} (ignoring case)",
+                        value, extension
+                    ),
+                )
+            }
+            FilePathMatcher::FileName(file_name) => {
+                let actual = value.as_ref().file_name();
+                MatcherResult::formatted(
+                    actual.is_some_and(|source| source == file_name.as_str()),
+                    format!(
+                        "{:?} should have file name {:?} but had {}",
+                        value,
+                        file_name,
+                        describe(actual)
+                    ),
+                    format!("{:?} should not have file name {:?}", value, file_name),
+                )
+            }
+            FilePathMatcher::Stem(stem) => {
+                let actual = value.as_ref().file_stem();
+                MatcherResult::formatted(
+                    actual.is_some_and(|source| source == stem.as_str()),
+                    format!(
+                        "{:?} should have stem {:?} but had {}",
+                        value,
+                        stem,
+                        describe(actual)
+                    ),
+                    format!("{:?} should not have stem {:?}", value, stem),
+                )
+            }
+            FilePathMatcher::Parent(parent) => {
+                let actual = value.as_ref().parent();
+                MatcherResult::formatted(
+                    actual.is_some_and(|source| source == parent),
+                    format!(
+                        "{:?} should have parent {:?} but had {}",
+                        value,
+                        parent,
+                        describe(actual.map(Path::as_os_str))
+                    ),
+                    format!("{:?} should not have parent {:?}", value, parent),
+                )
+            }
+            FilePathMatcher::StartWith(prefix) => MatcherResult::formatted(
+                value.as_ref().starts_with(prefix),
+                format!("{:?} should start with the path {:?}", value, prefix),
+                format!("{:?} should not start with the path {:?}", value, prefix),
+            ),
+            FilePathMatcher::EndWith(suffix) => MatcherResult::formatted(
+                value.as_ref().ends_with(suffix),
+                format!("{:?} should end with the path {:?}", value, suffix),
+                format!("{:?} should not end with the path {:?}", value, suffix),
+            ),
+            FilePathMatcher::ComponentCount(count) => {
+                let actual = value.as_ref().components().count();
+                MatcherResult::formatted(
+                    actual == *count,
+                    format!(
+                        "{:?} should have {} components but had {}",
+                        value, count, actual
+                    ),
+                    format!("{:?} should not have {} components", value, count),
+                )
+            }
+            FilePathMatcher::Canonical => match fs::canonicalize(value) {
+                Ok(canonical) => MatcherResult::formatted(
+                    canonical == value.as_ref(),
+                    format!(
+                        "{:?} should be canonical but the canonical path was {:?}",
+                        value, canonical
+                    ),
+                    format!("{:?} should not be canonical", value),
+                ),
+                Err(error) => MatcherResult::errored(format!(
+                    "{:?} should be canonical but failed to canonicalize with: {}",
+                    value, error
+                )),
+            },
+            FilePathMatcher::Exist => MatcherResult::formatted(
+                value.as_ref().exists(),
+                format!("{:?} should exist", value),
+                format!("{:?} should not exist", value),
             ),
         }
     }
 }
 
-impl<T: AsRef<Path> + Debug> Matcher<T> for TreeMatcher {
+fn describe(component: Option<&OsStr>) -> String {
+    component
+        .map(|component| format!("{:?}", component))
+        .unwrap_or_else(|| "none".to_string())
+}
+
+impl<T: AsRef<Path> + Debug + ?Sized> Matcher<T> for TreeMatcher {
     fn test(&self, value: &T) -> MatcherResult {
         match self {
             TreeMatcher::Contain(name) => {
@@ -217,6 +346,51 @@ pub fn have_extension(extension: &'static str) -> FilePathMatcher {
     FilePathMatcher::Extension(extension)
 }
 
+/// Creates a FilePathMatcher that asserts whether the path has the given extension, ignoring case.
+pub fn have_extension_ignoring_case(extension: &str) -> FilePathMatcher {
+    FilePathMatcher::ExtensionIgnoringCase(extension.to_string())
+}
+
+/// Creates a FilePathMatcher that asserts whether the final component of the path is the given file name.
+pub fn have_file_name(file_name: &str) -> FilePathMatcher {
+    FilePathMatcher::FileName(file_name.to_string())
+}
+
+/// Creates a FilePathMatcher that asserts whether the file name of the path, without its extension, is the given stem.
+pub fn have_stem(stem: &str) -> FilePathMatcher {
+    FilePathMatcher::Stem(stem.to_string())
+}
+
+/// Creates a FilePathMatcher that asserts whether the path has the given parent.
+pub fn have_parent<P: AsRef<Path>>(parent: P) -> FilePathMatcher {
+    FilePathMatcher::Parent(parent.as_ref().to_path_buf())
+}
+
+/// Creates a FilePathMatcher that asserts whether the path starts with the given path, comparing whole components.
+pub fn start_with_path<P: AsRef<Path>>(prefix: P) -> FilePathMatcher {
+    FilePathMatcher::StartWith(prefix.as_ref().to_path_buf())
+}
+
+/// Creates a FilePathMatcher that asserts whether the path ends with the given path, comparing whole components.
+pub fn end_with_path<P: AsRef<Path>>(suffix: P) -> FilePathMatcher {
+    FilePathMatcher::EndWith(suffix.as_ref().to_path_buf())
+}
+
+/// Creates a FilePathMatcher that asserts whether the path has the given number of components.
+pub fn have_component_count(count: usize) -> FilePathMatcher {
+    FilePathMatcher::ComponentCount(count)
+}
+
+/// Creates a FilePathMatcher that asserts whether the path exists and is already in its canonical form (absolute, without `.`, `..` or symbolic links).
+pub fn be_canonical() -> FilePathMatcher {
+    FilePathMatcher::Canonical
+}
+
+/// Creates a FilePathMatcher that asserts whether the path exists on the file system.
+pub fn exist() -> FilePathMatcher {
+    FilePathMatcher::Exist
+}
+
 /// Creates a TreeMatcher that asserts whether the path contains the given file name.
 pub fn contain_file_name(name: &'static str) -> TreeMatcher {
     TreeMatcher::Contain(name)
@@ -353,10 +527,18 @@ mod file_type_tests {
 
 #[cfg(all(test, feature = "file"))]
 mod file_path_tests {
-    use std::path::Path;
+    use std::ffi::OsStr;
+    use std::path::{Path, PathBuf};
+
+    use tempdir::TempDir;
 
     use crate::assertions::bool::TrueFalseAssertion;
-    use crate::matchers::file::{be_absolute, be_relative, have_extension};
+    use crate::assertions::string::membership::MembershipAssertion;
+    use crate::matchers::file::{
+        be_absolute, be_canonical, be_relative, end_with_path, exist, have_component_count,
+        have_extension, have_extension_ignoring_case, have_file_name, have_parent, have_stem,
+        start_with_path,
+    };
     use crate::matchers::Matcher;
 
     #[test]
@@ -379,6 +561,131 @@ mod file_path_tests {
         let matcher = have_extension("txt");
         matcher.test(&path).passed.should_be_true();
     }
+
+    #[test]
+    fn should_be_absolute_for_unsized_path() {
+        let path = Path::new("/etc/conf.d");
+        let matcher = be_absolute();
+        matcher.test(path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_extension_for_os_str() {
+        let path = OsStr::new("/etc/sample.txt");
+        let matcher = have_extension("txt");
+        matcher.test(path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_extension_ignoring_case() {
+        let path = Path::new("/etc/sample.TXT");
+        let matcher = have_extension_ignoring_case("txt");
+        matcher.test(&path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_have_extension_ignoring_case_given_no_extension() {
+        let path = Path::new("/etc/sample");
+        let matcher = have_extension_ignoring_case("txt");
+        let result = matcher.test(&path);
+        result.passed.should_be_false();
+        result.failure_message.should_contain("but had none");
+    }
+
+    #[test]
+    fn should_have_file_name() {
+        let path = Path::new("/etc/sample.txt");
+        let matcher = have_file_name("sample.txt");
+        matcher.test(&path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_have_file_name() {
+        let path = Path::new("/etc/sample.txt");
+        let matcher = have_file_name("sample");
+        let result = matcher.test(&path);
+        result.passed.should_be_false();
+        result.failure_message.should_contain("but had \"sample.txt\"");
+    }
+
+    #[test]
+    fn should_have_stem() {
+        let path = Path::new("/etc/archive.tar.gz");
+        let matcher = have_stem("archive.tar");
+        matcher.test(&path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_parent() {
+        let path = Path::new("/etc/conf.d/sample.txt");
+        let matcher = have_parent("/etc/conf.d");
+        matcher.test(&path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_have_parent_given_root() {
+        let path = Path::new("/");
+        let matcher = have_parent("/");
+        matcher.test(&path).passed.should_be_false();
+    }
+
+    #[test]
+    fn should_start_with_path() {
+        let path = Path::new("/etc/conf.d/sample.txt");
+        let matcher = start_with_path("/etc");
+        matcher.test(&path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_start_with_partial_component() {
+        let path = Path::new("/etc/conf.d/sample.txt");
+        let matcher = start_with_path("/et");
+        matcher.test(&path).passed.should_be_false();
+    }
+
+    #[test]
+    fn should_end_with_path() {
+        let path = Path::new("/etc/conf.d/sample.txt");
+        let matcher = end_with_path(PathBuf::from("conf.d/sample.txt"));
+        matcher.test(&path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_component_count() {
+        let path = Path::new("/etc/conf.d/sample.txt");
+        let matcher = have_component_count(4);
+        matcher.test(&path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_canonical() {
+        let directory = TempDir::new(".").unwrap();
+        let path = directory.path().canonicalize().unwrap();
+        let matcher = be_canonical();
+        matcher.test(&path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_canonical() {
+        let directory = TempDir::new(".").unwrap();
+        let path = directory.path().join("..").join(directory.path().file_name().unwrap());
+        let matcher = be_canonical();
+        matcher.test(&path).passed.should_be_false();
+    }
+
+    #[test]
+    fn should_exist() {
+        let directory = TempDir::new(".").unwrap();
+        let matcher = exist();
+        matcher.test(&directory.path()).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_exist() {
+        let directory = TempDir::new(".").unwrap();
+        let matcher = exist();
+        matcher.test(&directory.path().join("missing.txt")).passed.should_be_false();
+    }
 }
 
 #[cfg(all(test, feature = "file"))]
@@ -0,0 +1,13 @@
+//! provides the helpers shared by the tests of file matchers and file assertions.
+
+use std::fs;
+use std::path::PathBuf;
+
+use tempdir::TempDir;
+
+/// Writes the content to a file with the given name in the directory, and returns the path of the file.
+pub(crate) fn file_with(directory: &TempDir, name: &str, content: &str) -> PathBuf {
+    let file_path = directory.path().join(name);
+    fs::write(&file_path, content).unwrap();
+    file_path
+}
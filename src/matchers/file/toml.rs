@@ -0,0 +1,101 @@
+use std::fmt::Debug;
+use std::path::Path;
+
+use toml::Table;
+
+use crate::matchers::{Matcher, MatcherResult};
+use crate::matchers::file::content::read_text;
+
+/// TomlFileMatcher offers a flexible way to make assertions about files containing TOML.
+///
+/// A file that can not be read fails both the assertion and its negation, and a file that can not be parsed fails the assertion,
+/// with the error in the message.
+///
+/// # Example
+///```
+/// use std::fs;
+/// use tempdir::TempDir;
+/// use clearcheck::matchers::file::toml::contain_valid_toml;
+/// use clearcheck::matchers::Matcher;
+///
+/// let temporary_directory = TempDir::new(".").unwrap();
+/// let file_path = temporary_directory.path().join("Cargo.toml");
+/// fs::write(&file_path, "[package]\nname = \"clearcheck\"").unwrap();
+///
+/// let matcher = contain_valid_toml();
+/// assert!(matcher.test(&file_path).passed());
+/// ```
+pub enum TomlFileMatcher {
+    Valid,
+}
+
+impl<T: AsRef<Path> + Debug + ?Sized> Matcher<T> for TomlFileMatcher {
+    fn test(&self, value: &T) -> MatcherResult {
+        let content = match read_text(value) {
+            Ok(content) => content,
+            Err(result) => return result,
+        };
+        match self {
+            TomlFileMatcher::Valid => {
+                let parsed = content.parse::<Table>();
+                MatcherResult::formatted(
+                    parsed.is_ok(),
+                    format!(
+                        "{:?} should contain valid toml but failed to parse: {}",
+                        value,
+                        parsed.err().map(|error| error.to_string()).unwrap_or_default()
+                    ),
+                    format!("{:?} should not contain valid toml", value),
+                )
+            }
+        }
+    }
+}
+
+/// Creates a TomlFileMatcher that asserts whether the file contains a valid TOML document.
+pub fn contain_valid_toml() -> TomlFileMatcher {
+    TomlFileMatcher::Valid
+}
+
+#[cfg(all(test, feature = "toml"))]
+mod tests {
+    use std::fs;
+
+    use tempdir::TempDir;
+
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::string::membership::MembershipAssertion;
+    use crate::matchers::file::toml::contain_valid_toml;
+    use crate::matchers::Matcher;
+
+    #[test]
+    fn should_contain_valid_toml() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = directory.path().join("Cargo.toml");
+        fs::write(&file_path, "[package]\nname = \"clearcheck\"").unwrap();
+
+        let matcher = contain_valid_toml();
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_contain_valid_toml() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = directory.path().join("Cargo.toml");
+        fs::write(&file_path, "[package\nname = ").unwrap();
+
+        let matcher = contain_valid_toml();
+        let result = matcher.test(&file_path);
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should contain valid toml but failed to parse");
+    }
+
+    #[test]
+    fn should_not_contain_valid_toml_given_a_missing_file() {
+        let directory = TempDir::new(".").unwrap();
+        let matcher = contain_valid_toml();
+        let result = matcher.test(&directory.path().join("missing.toml"));
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should be readable");
+    }
+}
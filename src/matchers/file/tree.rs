@@ -0,0 +1,705 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Debug;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use walkdir::WalkDir;
+
+use crate::matchers::{Matcher, MatcherResult};
+use crate::matchers::file::glob::{matches_glob, matches_path_or_ancestor};
+
+/// TreeComparisonMatcher offers a flexible way to compare an entire directory tree, either against another directory or against an inline spec.
+///
+/// Entries are identified by their paths relative to the root, using `/` as the separator, with directories carrying a trailing `/`.
+/// The failure message lists the missing entries, the extra entries and the files whose content differs.
+///
+/// # Example
+///```
+/// use std::fs;
+/// use tempdir::TempDir;
+/// use clearcheck::matchers::file::tree::match_tree_spec;
+/// use clearcheck::matchers::Matcher;
+///
+/// let temporary_directory = TempDir::new(".").unwrap();
+/// fs::create_dir(temporary_directory.path().join("src")).unwrap();
+/// fs::write(temporary_directory.path().join("src").join("lib.rs"), "").unwrap();
+/// fs::write(temporary_directory.path().join("Cargo.toml"), "").unwrap();
+///
+/// let matcher = match_tree_spec(&["src/", "src/lib.rs", "Cargo.toml"]);
+/// assert!(matcher.test(&temporary_directory.path()).passed());
+/// ```
+pub enum TreeComparisonMatcher {
+    SameTreeAs(PathBuf, Vec<String>),
+    Spec(Vec<String>),
+}
+
+impl<T: AsRef<Path> + Debug + ?Sized> Matcher<T> for TreeComparisonMatcher {
+    fn test(&self, value: &T) -> MatcherResult {
+        match self {
+            TreeComparisonMatcher::SameTreeAs(other, ignored) => {
+                if !value.as_ref().is_dir() || !other.is_dir() {
+                    return MatcherResult::errored(format!(
+                        "{:?} and {:?} should both be directories",
+                        value, other
+                    ));
+                }
+                let (actual, expected) = match (entries(value.as_ref(), ignored), entries(other, ignored)) {
+                    (Ok(actual), Ok(expected)) => (actual, expected),
+                    (Err(error), _) => return walk_failure(value, error),
+                    (_, Err(error)) => return walk_failure(other, error),
+                };
+
+                let mut different = Vec::new();
+                for (relative_path, path) in actual
+                    .iter()
+                    .filter(|(relative_path, _)| !relative_path.ends_with('/'))
+                {
+                    if let Some(other_path) = expected.get(relative_path) {
+                        match same_bytes(path, other_path) {
+                            Ok(true) => {}
+                            Ok(false) => different.push(relative_path.as_str()),
+                            Err(message) => return MatcherResult::errored(message),
+                        }
+                    }
+                }
+
+                let differences = describe_differences(
+                    missing(expected.keys(), &actual),
+                    missing(actual.keys(), &expected),
+                    different,
+                );
+                MatcherResult::formatted(
+                    differences.is_empty(),
+                    format!(
+                        "{:?} should have the same tree as {:?} but {}",
+                        value, other, differences
+                    ),
+                    format!("{:?} should not have the same tree as {:?}", value, other),
+                )
+            }
+            TreeComparisonMatcher::Spec(spec) => {
+                if !value.as_ref().is_dir() {
+                    return MatcherResult::errored(format!("{:?} should be a directory", value));
+                }
+                let actual = match entries(value.as_ref(), &[]) {
+                    Ok(actual) => actual,
+                    Err(error) => return walk_failure(value, error),
+                };
+                let expected = expand_spec(spec);
+
+                let differences = describe_differences(
+                    expected
+                        .iter()
+                        .filter(|relative_path| !actual.contains_key(*relative_path))
+                        .map(|relative_path| relative_path.as_str())
+                        .collect(),
+                    actual
+                        .keys()
+                        .filter(|relative_path| !expected.contains(*relative_path))
+                        .map(|relative_path| relative_path.as_str())
+                        .collect(),
+                    Vec::new(),
+                );
+                MatcherResult::formatted(
+                    differences.is_empty(),
+                    format!(
+                        "{:?} should match the tree spec {:?} but {}",
+                        value, spec, differences
+                    ),
+                    format!("{:?} should not match the tree spec {:?}", value, spec),
+                )
+            }
+        }
+    }
+}
+
+/// PathTreeMatcher offers a flexible way to make assertions about the entries of a directory tree, keyed by their paths relative to the root.
+///
+/// Unlike [`crate::matchers::file::TreeMatcher`], which matches a bare file name at any depth, PathTreeMatcher distinguishes `a/config.toml` from `b/config.toml`.
+/// Glob patterns are matched against the relative path using `/` as the separator: `*` and `?` stay within a path component, while `**` spans components.
+///
+/// By default, the whole tree is walked without following symbolic links; use `with_max_depth` and `following_symlinks` to change that.
+///
+/// # Example
+///```
+/// use std::fs;
+/// use tempdir::TempDir;
+/// use clearcheck::matchers::file::tree::contain_glob;
+/// use clearcheck::matchers::Matcher;
+///
+/// let temporary_directory = TempDir::new(".").unwrap();
+/// fs::create_dir(temporary_directory.path().join("src")).unwrap();
+/// fs::write(temporary_directory.path().join("src").join("lib.rs"), "").unwrap();
+///
+/// let matcher = contain_glob("src/*.rs").with_max_depth(2);
+/// assert!(matcher.test(&temporary_directory.path()).passed());
+/// ```
+pub struct PathTreeMatcher {
+    kind: PathTreeMatcherKind,
+    max_depth: Option<usize>,
+    follow_symlinks: bool,
+}
+
+enum PathTreeMatcherKind {
+    ContainPath(PathBuf),
+    ContainGlob(String),
+    FilesMatchingCount(String, usize),
+}
+
+impl PathTreeMatcher {
+    fn new(kind: PathTreeMatcherKind) -> Self {
+        PathTreeMatcher {
+            kind,
+            max_depth: None,
+            follow_symlinks: false,
+        }
+    }
+
+    /// Limits the walk to the given depth, where the entries directly inside the root are at depth 1.
+    pub fn with_max_depth(self, max_depth: usize) -> Self {
+        PathTreeMatcher {
+            max_depth: Some(max_depth),
+            ..self
+        }
+    }
+
+    /// Follows symbolic links while walking the tree.
+    pub fn following_symlinks(self) -> Self {
+        PathTreeMatcher {
+            follow_symlinks: true,
+            ..self
+        }
+    }
+
+    fn walk(&self, root: &Path) -> Result<Vec<(String, bool)>, walkdir::Error> {
+        let mut walk_dir = WalkDir::new(root).min_depth(1).follow_links(self.follow_symlinks);
+        if let Some(max_depth) = self.max_depth {
+            walk_dir = walk_dir.max_depth(max_depth);
+        }
+        let mut walked = Vec::new();
+        for directory_entry in walk_dir {
+            let directory_entry = directory_entry?;
+            if let Some(relative_path) = relative_path_of(root, directory_entry.path()) {
+                walked.push((relative_path, directory_entry.file_type().is_dir()));
+            }
+        }
+        Ok(walked)
+    }
+}
+
+impl<T: AsRef<Path> + Debug + ?Sized> Matcher<T> for PathTreeMatcher {
+    fn test(&self, value: &T) -> MatcherResult {
+        let walked = match self.walk(value.as_ref()) {
+            Ok(walked) => walked,
+            Err(error) => return walk_failure(value, error),
+        };
+        match &self.kind {
+            PathTreeMatcherKind::ContainPath(path) => {
+                let expected = path
+                    .components()
+                    .filter(|component| *component != Component::CurDir)
+                    .collect::<PathBuf>();
+                MatcherResult::formatted(
+                    walked
+                        .iter()
+                        .any(|(relative_path, _)| Path::new(&relative_path) == expected),
+                    format!("{:?} should contain the path {:?}", value, path),
+                    format!("{:?} should not contain the path {:?}", value, path),
+                )
+            }
+            PathTreeMatcherKind::ContainGlob(pattern) => {
+                let matching = walked
+                    .into_iter()
+                    .find(|(relative_path, _)| matches_glob(pattern, relative_path));
+                MatcherResult::formatted(
+                    matching.is_some(),
+                    format!("{:?} should contain an entry matching {:?}", value, pattern),
+                    format!(
+                        "{:?} should not contain an entry matching {:?} but contained {:?}",
+                        value,
+                        pattern,
+                        matching.map(|(relative_path, _)| relative_path).unwrap_or_default()
+                    ),
+                )
+            }
+            PathTreeMatcherKind::FilesMatchingCount(pattern, count) => {
+                let actual_count = walked
+                    .iter()
+                    .filter(|(relative_path, is_directory)| {
+                        !is_directory && matches_glob(pattern, relative_path)
+                    })
+                    .count();
+                MatcherResult::formatted(
+                    actual_count == *count,
+                    format!(
+                        "{:?} should contain {} files matching {:?} but contained {}",
+                        value, count, pattern, actual_count
+                    ),
+                    format!(
+                        "{:?} should not contain {} files matching {:?}",
+                        value, count, pattern
+                    ),
+                )
+            }
+        }
+    }
+}
+
+fn entries(root: &Path, ignored: &[String]) -> Result<BTreeMap<String, PathBuf>, walkdir::Error> {
+    let mut entries = BTreeMap::new();
+    for directory_entry in WalkDir::new(root).min_depth(1) {
+        let directory_entry = directory_entry?;
+        let relative_path = match relative_path_of(root, directory_entry.path()) {
+            Some(relative_path) => relative_path,
+            None => continue,
+        };
+        if ignored
+            .iter()
+            .any(|pattern| matches_path_or_ancestor(pattern, &relative_path))
+        {
+            continue;
+        }
+        let key = if directory_entry.file_type().is_dir() {
+            format!("{}/", relative_path)
+        } else {
+            relative_path
+        };
+        entries.insert(key, directory_entry.into_path());
+    }
+    Ok(entries)
+}
+
+fn walk_failure<T: Debug + ?Sized>(value: &T, error: walkdir::Error) -> MatcherResult {
+    MatcherResult::errored(format!(
+        "{:?} should be walkable but failed with: {}",
+        value, error
+    ))
+}
+
+fn relative_path_of(root: &Path, path: &Path) -> Option<String> {
+    let components = path
+        .strip_prefix(root)
+        .ok()?
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy().into_owned())
+        .collect::<Vec<_>>();
+    Some(components.join("/"))
+}
+
+fn same_bytes(path: &Path, other: &Path) -> Result<bool, String> {
+    let read = |path: &Path| {
+        fs::read(path).map_err(|error| format!("{:?} should be readable but failed with: {}", path, error))
+    };
+    Ok(read(path)? == read(other)?)
+}
+
+fn missing<'a>(
+    relative_paths: impl Iterator<Item = &'a String>,
+    from: &BTreeMap<String, PathBuf>,
+) -> Vec<&'a str> {
+    relative_paths
+        .filter(|relative_path| !from.contains_key(*relative_path))
+        .map(|relative_path| relative_path.as_str())
+        .collect()
+}
+
+// Every spec entry implies its parent directories, so `src/lib.rs` also expects `src/`.
+fn expand_spec(spec: &[String]) -> BTreeSet<String> {
+    let mut expected = BTreeSet::new();
+    for entry in spec {
+        let entry = entry.trim_start_matches("./");
+        let is_directory = entry.ends_with('/');
+        let components = entry
+            .split('/')
+            .filter(|component| !component.is_empty())
+            .collect::<Vec<_>>();
+
+        for index in 0..components.len() {
+            let relative_path = components[..=index].join("/");
+            if index + 1 < components.len() || is_directory {
+                expected.insert(format!("{}/", relative_path));
+            } else {
+                expected.insert(relative_path);
+            }
+        }
+    }
+    expected
+}
+
+fn describe_differences(missing: Vec<&str>, extra: Vec<&str>, different: Vec<&str>) -> String {
+    let mut differences = Vec::new();
+    if !missing.is_empty() {
+        differences.push(format!("was missing {:?}", missing));
+    }
+    if !extra.is_empty() {
+        differences.push(format!("had extra {:?}", extra));
+    }
+    if !different.is_empty() {
+        differences.push(format!("had different content in {:?}", different));
+    }
+    differences.join(", ")
+}
+
+/// Creates a TreeComparisonMatcher that asserts whether the directory has the same tree (relative paths and file contents) as the other directory.
+pub fn have_same_tree_as<P: AsRef<Path>>(other: P) -> TreeComparisonMatcher {
+    TreeComparisonMatcher::SameTreeAs(other.as_ref().to_path_buf(), Vec::new())
+}
+
+/// Creates a TreeComparisonMatcher that asserts whether the directory has the same tree (relative paths and file contents) as the other directory,
+/// skipping the entries that match any of the given glob patterns (in either directory).
+///
+/// A pattern without `/` applies to any path component (`*.log`, `target`), otherwise it applies to the relative path (`src/**/*.bak`).
+/// Ignoring a directory ignores everything beneath it.
+pub fn have_same_tree_as_ignoring<P: AsRef<Path>>(other: P, patterns: &[&str]) -> TreeComparisonMatcher {
+    TreeComparisonMatcher::SameTreeAs(
+        other.as_ref().to_path_buf(),
+        patterns.iter().map(|pattern| pattern.to_string()).collect(),
+    )
+}
+
+/// Creates a TreeComparisonMatcher that asserts whether the directory contains exactly the entries in the given spec.
+///
+/// Directories end with `/` and every entry implies its parent directories, so `["src/lib.rs"]` expects both `src/` and `src/lib.rs`.
+pub fn match_tree_spec(spec: &[&str]) -> TreeComparisonMatcher {
+    TreeComparisonMatcher::Spec(spec.iter().map(|entry| entry.to_string()).collect())
+}
+
+/// Creates a PathTreeMatcher that asserts whether the directory contains the given path, relative to the directory.
+pub fn contain_path<P: Into<PathBuf>>(path: P) -> PathTreeMatcher {
+    PathTreeMatcher::new(PathTreeMatcherKind::ContainPath(path.into()))
+}
+
+/// Creates a PathTreeMatcher that asserts whether the directory contains an entry (file or directory) whose relative path matches the given glob pattern.
+pub fn contain_glob<S: Into<String>>(pattern: S) -> PathTreeMatcher {
+    PathTreeMatcher::new(PathTreeMatcherKind::ContainGlob(pattern.into()))
+}
+
+/// Creates a PathTreeMatcher that asserts whether the directory contains exactly the given number of files whose relative paths match the given glob pattern.
+pub fn contain_files_matching_count<S: Into<String>>(pattern: S, count: usize) -> PathTreeMatcher {
+    PathTreeMatcher::new(PathTreeMatcherKind::FilesMatchingCount(pattern.into(), count))
+}
+
+#[cfg(all(test, feature = "file"))]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use tempdir::TempDir;
+
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::string::membership::MembershipAssertion;
+    use crate::matchers::file::tree::{have_same_tree_as, have_same_tree_as_ignoring, match_tree_spec};
+    use crate::matchers::Matcher;
+
+    fn project_in(directory: &Path, lib_content: &str) {
+        fs::create_dir_all(directory.join("src")).unwrap();
+        fs::write(directory.join("src").join("lib.rs"), lib_content).unwrap();
+        fs::write(directory.join("Cargo.toml"), "[package]").unwrap();
+    }
+
+    #[test]
+    fn should_have_same_tree() {
+        let directory = TempDir::new(".").unwrap();
+        let other = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+        project_in(other.path(), "mod tree;");
+
+        let matcher = have_same_tree_as(other.path());
+        matcher.test(&directory.path()).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_have_same_tree_given_a_missing_file() {
+        let directory = TempDir::new(".").unwrap();
+        let other = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+        project_in(other.path(), "mod tree;");
+        fs::write(other.path().join("README.md"), "").unwrap();
+
+        let matcher = have_same_tree_as(other.path());
+        let result = matcher.test(&directory.path());
+        result.passed.should_be_false();
+        result.failure_message.should_contain("was missing [\"README.md\"]");
+    }
+
+    #[test]
+    fn should_not_have_same_tree_given_an_extra_directory() {
+        let directory = TempDir::new(".").unwrap();
+        let other = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+        project_in(other.path(), "mod tree;");
+        fs::create_dir(directory.path().join("target")).unwrap();
+
+        let matcher = have_same_tree_as(other.path());
+        let result = matcher.test(&directory.path());
+        result.passed.should_be_false();
+        result.failure_message.should_contain("had extra [\"target/\"]");
+    }
+
+    #[test]
+    fn should_not_have_same_tree_given_different_content() {
+        let directory = TempDir::new(".").unwrap();
+        let other = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+        project_in(other.path(), "mod file;");
+
+        let matcher = have_same_tree_as(other.path());
+        let result = matcher.test(&directory.path());
+        result.passed.should_be_false();
+        result
+            .failure_message
+            .should_contain("had different content in [\"src/lib.rs\"]");
+    }
+
+    #[test]
+    fn should_have_same_tree_ignoring_patterns() {
+        let directory = TempDir::new(".").unwrap();
+        let other = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+        project_in(other.path(), "mod tree;");
+        fs::create_dir_all(directory.path().join("target").join("debug")).unwrap();
+        fs::write(other.path().join("src").join("build.log"), "").unwrap();
+
+        let matcher = have_same_tree_as_ignoring(other.path(), &["target", "*.log"]);
+        matcher.test(&directory.path()).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_have_same_tree_given_the_other_is_not_a_directory() {
+        let directory = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+
+        let matcher = have_same_tree_as(directory.path().join("Cargo.toml"));
+        let result = matcher.test(&directory.path());
+        result.passed.should_be_false();
+        result.errored.should_be_true();
+    }
+
+    #[test]
+    fn should_fail_both_ways_given_a_missing_directory_for_tree_spec() {
+        let directory = TempDir::new(".").unwrap();
+
+        let matcher = match_tree_spec(&["src/lib.rs"]);
+        let result = matcher.test(&directory.path().join("missing"));
+        result.passed.should_be_false();
+        result.errored.should_be_true();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn should_fail_both_ways_given_an_unreadable_file_in_both_trees() {
+        let directory = TempDir::new(".").unwrap();
+        let other = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+        project_in(other.path(), "mod tree;");
+        std::os::unix::fs::symlink(directory.path().join("missing"), directory.path().join("link")).unwrap();
+        std::os::unix::fs::symlink(other.path().join("missing"), other.path().join("link")).unwrap();
+
+        let matcher = have_same_tree_as(other.path());
+        let result = matcher.test(&directory.path());
+        result.passed.should_be_false();
+        result.errored.should_be_true();
+        result.failure_message.should_contain("should be readable but failed with");
+    }
+
+    #[test]
+    fn should_match_tree_spec() {
+        let directory = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+
+        let matcher = match_tree_spec(&["src/", "src/lib.rs", "Cargo.toml"]);
+        matcher.test(&directory.path()).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_match_tree_spec_with_implied_parent_directories() {
+        let directory = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+
+        let matcher = match_tree_spec(&["src/lib.rs", "Cargo.toml"]);
+        matcher.test(&directory.path()).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_match_tree_spec() {
+        let directory = TempDir::new(".").unwrap();
+        project_in(directory.path(), "mod tree;");
+
+        let matcher = match_tree_spec(&["src/main.rs", "Cargo.toml"]);
+        let result = matcher.test(&directory.path());
+        result.passed.should_be_false();
+        result
+            .failure_message
+            .should_contain("was missing [\"src/main.rs\"], had extra [\"src/lib.rs\"]");
+    }
+}
+
+#[cfg(all(test, feature = "file"))]
+mod path_tree_tests {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    use tempdir::TempDir;
+
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::string::membership::MembershipAssertion;
+    use crate::matchers::file::tree::{contain_files_matching_count, contain_glob, contain_path};
+    use crate::matchers::Matcher;
+
+    fn configs_in(directory: &Path) {
+        fs::create_dir_all(directory.join("a")).unwrap();
+        fs::create_dir_all(directory.join("b").join("nested")).unwrap();
+        fs::write(directory.join("a").join("config.toml"), "").unwrap();
+        fs::write(directory.join("b").join("nested").join("lib.rs"), "").unwrap();
+        fs::write(directory.join("main.rs"), "").unwrap();
+    }
+
+    #[test]
+    fn should_contain_path() {
+        let directory = TempDir::new(".").unwrap();
+        configs_in(directory.path());
+
+        let matcher = contain_path("a/config.toml");
+        matcher.test(&directory.path()).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_contain_owned_path() {
+        let directory = TempDir::new(".").unwrap();
+        configs_in(directory.path());
+
+        let matcher = contain_path(PathBuf::from("b").join("nested"));
+        matcher.test(&directory.path()).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_contain_path_starting_with_the_current_directory() {
+        let directory = TempDir::new(".").unwrap();
+        configs_in(directory.path());
+
+        let matcher = contain_path("./a/config.toml");
+        matcher.test(&directory.path()).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_contain_path_with_the_same_file_name_in_another_directory() {
+        let directory = TempDir::new(".").unwrap();
+        configs_in(directory.path());
+
+        let matcher = contain_path("b/config.toml");
+        matcher.test(&directory.path()).passed.should_be_false();
+    }
+
+    #[test]
+    fn should_not_contain_path_beyond_max_depth() {
+        let directory = TempDir::new(".").unwrap();
+        configs_in(directory.path());
+
+        let matcher = contain_path("b/nested/lib.rs").with_max_depth(2);
+        matcher.test(&directory.path()).passed.should_be_false();
+    }
+
+    #[test]
+    fn should_contain_glob() {
+        let directory = TempDir::new(".").unwrap();
+        configs_in(directory.path());
+
+        let matcher = contain_glob("**/*.toml");
+        matcher.test(&directory.path()).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_contain_owned_glob() {
+        let directory = TempDir::new(".").unwrap();
+        configs_in(directory.path());
+
+        let matcher = contain_glob(String::from("b/*/lib.rs"));
+        matcher.test(&directory.path()).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_contain_glob() {
+        let directory = TempDir::new(".").unwrap();
+        configs_in(directory.path());
+
+        let matcher = contain_glob("*.toml");
+        let result = matcher.test(&directory.path());
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should contain an entry matching \"*.toml\"");
+    }
+
+    #[test]
+    fn should_contain_files_matching_count() {
+        let directory = TempDir::new(".").unwrap();
+        configs_in(directory.path());
+
+        let matcher = contain_files_matching_count("**/*.rs", 2);
+        matcher.test(&directory.path()).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_contain_files_matching_count_within_max_depth() {
+        let directory = TempDir::new(".").unwrap();
+        configs_in(directory.path());
+
+        let matcher = contain_files_matching_count("**/*.rs", 1).with_max_depth(1);
+        matcher.test(&directory.path()).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_contain_files_matching_count() {
+        let directory = TempDir::new(".").unwrap();
+        configs_in(directory.path());
+
+        let matcher = contain_files_matching_count("**/*.rs", 3);
+        let result = matcher.test(&directory.path());
+        result.passed.should_be_false();
+        result.failure_message.should_contain("but contained 2");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn should_contain_path_through_symlink_only_when_following_symlinks() {
+        let directory = TempDir::new(".").unwrap();
+        let linked = TempDir::new(".").unwrap();
+        fs::write(linked.path().join("linked.txt"), "").unwrap();
+        std::os::unix::fs::symlink(
+            linked.path().canonicalize().unwrap(),
+            directory.path().join("link"),
+        )
+        .unwrap();
+
+        let matcher = contain_path("link/linked.txt");
+        matcher.test(&directory.path()).passed.should_be_false();
+
+        let matcher = contain_path("link/linked.txt").following_symlinks();
+        matcher.test(&directory.path()).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_fail_both_ways_given_a_missing_directory() {
+        let directory = TempDir::new(".").unwrap();
+
+        let matcher = contain_path("a/config.toml");
+        let result = matcher.test(&directory.path().join("missing"));
+        result.passed.should_be_false();
+        result.errored.should_be_true();
+        result.failure_message.should_contain("should be walkable but failed with");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn should_fail_both_ways_given_a_broken_symlink_when_following_symlinks() {
+        let directory = TempDir::new(".").unwrap();
+        configs_in(directory.path());
+        std::os::unix::fs::symlink(directory.path().join("missing"), directory.path().join("link")).unwrap();
+
+        let matcher = contain_files_matching_count("**/*.rs", 2);
+        matcher.test(&directory.path()).passed.should_be_true();
+
+        let matcher = contain_files_matching_count("**/*.rs", 2).following_symlinks();
+        let result = matcher.test(&directory.path());
+        result.passed.should_be_false();
+        result.errored.should_be_true();
+    }
+}
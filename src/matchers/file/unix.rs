@@ -0,0 +1,205 @@
+use std::fmt::Debug;
+use std::fs;
+use std::os::unix::fs::{MetadataExt, PermissionsExt};
+use std::path::Path;
+
+use crate::matchers::{Matcher, MatcherResult};
+
+/// UnixPermissionMatcher offers a flexible way to make assertions about unix permission bits and ownership of files and directories.
+///
+/// The metadata is read following symbolic links. A path whose metadata can not be read fails both the assertion and its negation, with the error in the message.
+///
+/// # Example
+///```
+/// use std::fs;
+/// use std::fs::File;
+/// use std::os::unix::fs::PermissionsExt;
+/// use tempdir::TempDir;
+/// use clearcheck::matchers::file::unix::have_mode;
+/// use clearcheck::matchers::Matcher;
+///
+/// let temporary_directory = TempDir::new(".").unwrap();
+/// let file_path = temporary_directory.path().join("clearcheck.sh");
+/// let _ = File::create(&file_path).unwrap();
+/// fs::set_permissions(&file_path, fs::Permissions::from_mode(0o755)).unwrap();
+///
+/// let matcher = have_mode(0o755);
+/// assert!(matcher.test(&file_path).passed());
+/// ```
+pub enum UnixPermissionMatcher {
+    Mode(u32),
+    Executable,
+    OwnedByUid(u32),
+    OwnedByGid(u32),
+}
+
+impl<T: AsRef<Path> + Debug + ?Sized> Matcher<T> for UnixPermissionMatcher {
+    fn test(&self, value: &T) -> MatcherResult {
+        let metadata = match fs::metadata(value) {
+            Ok(metadata) => metadata,
+            Err(error) => {
+                let message = format!(
+                    "{:?} should have readable metadata but failed with: {}",
+                    value, error
+                );
+                return MatcherResult::errored(message);
+            }
+        };
+        let mode = metadata.permissions().mode() & 0o7777;
+        match self {
+            UnixPermissionMatcher::Mode(expected) => MatcherResult::formatted(
+                mode == *expected,
+                format!("{:?} should have mode {:#o} but was {:#o}", value, expected, mode),
+                format!("{:?} should not have mode {:#o}", value, expected),
+            ),
+            UnixPermissionMatcher::Executable => MatcherResult::formatted(
+                metadata.is_file() && mode & 0o111 != 0,
+                format!("{:?} should be an executable file but had mode {:#o}", value, mode),
+                format!("{:?} should not be an executable file but had mode {:#o}", value, mode),
+            ),
+            UnixPermissionMatcher::OwnedByUid(uid) => MatcherResult::formatted(
+                metadata.uid() == *uid,
+                format!(
+                    "{:?} should be owned by uid {} but was owned by uid {}",
+                    value,
+                    uid,
+                    metadata.uid()
+                ),
+                format!("{:?} should not be owned by uid {}", value, uid),
+            ),
+            UnixPermissionMatcher::OwnedByGid(gid) => MatcherResult::formatted(
+                metadata.gid() == *gid,
+                format!(
+                    "{:?} should be owned by gid {} but was owned by gid {}",
+                    value,
+                    gid,
+                    metadata.gid()
+                ),
+                format!("{:?} should not be owned by gid {}", value, gid),
+            ),
+        }
+    }
+}
+
+/// Creates a UnixPermissionMatcher that asserts whether the permission bits (including setuid, setgid and sticky bits) of the path are exactly the given mode.
+pub fn have_mode(mode: u32) -> UnixPermissionMatcher {
+    UnixPermissionMatcher::Mode(mode)
+}
+
+/// Creates a UnixPermissionMatcher that asserts whether the path is a file with at least one of the execute bits set.
+pub fn be_executable() -> UnixPermissionMatcher {
+    UnixPermissionMatcher::Executable
+}
+
+/// Creates a UnixPermissionMatcher that asserts whether the path is owned by the given user id.
+pub fn be_owned_by_uid(uid: u32) -> UnixPermissionMatcher {
+    UnixPermissionMatcher::OwnedByUid(uid)
+}
+
+/// Creates a UnixPermissionMatcher that asserts whether the path is owned by the given group id.
+pub fn be_owned_by_gid(gid: u32) -> UnixPermissionMatcher {
+    UnixPermissionMatcher::OwnedByGid(gid)
+}
+
+#[cfg(all(test, feature = "file"))]
+mod tests {
+    use std::fs;
+    use std::fs::File;
+    use std::os::unix::fs::{MetadataExt, PermissionsExt};
+
+    use tempdir::TempDir;
+
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::string::membership::MembershipAssertion;
+    use crate::matchers::file::unix::{be_executable, be_owned_by_gid, be_owned_by_uid, have_mode};
+    use crate::matchers::Matcher;
+
+    #[test]
+    fn should_have_mode() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = directory.path().join("clearcheck.txt");
+        let _ = File::create(&file_path).unwrap();
+        fs::set_permissions(&file_path, fs::Permissions::from_mode(0o644)).unwrap();
+
+        let matcher = have_mode(0o644);
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_have_mode() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = directory.path().join("clearcheck.txt");
+        let _ = File::create(&file_path).unwrap();
+        fs::set_permissions(&file_path, fs::Permissions::from_mode(0o600)).unwrap();
+
+        let matcher = have_mode(0o644);
+        let result = matcher.test(&file_path);
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should have mode 0o644 but was 0o600");
+    }
+
+    #[test]
+    fn should_be_executable() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = directory.path().join("clearcheck.sh");
+        let _ = File::create(&file_path).unwrap();
+        fs::set_permissions(&file_path, fs::Permissions::from_mode(0o744)).unwrap();
+
+        let matcher = be_executable();
+        matcher.test(&file_path).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_executable() {
+        let directory = TempDir::new(".").unwrap();
+        let file_path = directory.path().join("clearcheck.sh");
+        let _ = File::create(&file_path).unwrap();
+        fs::set_permissions(&file_path, fs::Permissions::from_mode(0o644)).unwrap();
+
+        let matcher = be_executable();
+        matcher.test(&file_path).passed.should_be_false();
+    }
+
+    #[test]
+    fn should_not_be_executable_given_a_directory() {
+        let directory = TempDir::new(".").unwrap();
+        let matcher = be_executable();
+        matcher.test(&directory.path()).passed.should_be_false();
+    }
+
+    #[test]
+    fn should_be_owned_by_uid() {
+        let directory = TempDir::new(".").unwrap();
+        let uid = fs::metadata(directory.path()).unwrap().uid();
+
+        let matcher = be_owned_by_uid(uid);
+        matcher.test(&directory.path()).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_owned_by_uid() {
+        let directory = TempDir::new(".").unwrap();
+        let uid = fs::metadata(directory.path()).unwrap().uid();
+
+        let matcher = be_owned_by_uid(uid + 1);
+        matcher.test(&directory.path()).passed.should_be_false();
+    }
+
+    #[test]
+    fn should_be_owned_by_gid() {
+        let directory = TempDir::new(".").unwrap();
+        let gid = fs::metadata(directory.path()).unwrap().gid();
+
+        let matcher = be_owned_by_gid(gid);
+        matcher.test(&directory.path()).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_fail_with_the_error_for_a_missing_file() {
+        let directory = TempDir::new(".").unwrap();
+        let matcher = have_mode(0o644);
+        let result = matcher.test(&directory.path().join("missing.txt"));
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should have readable metadata but failed with");
+    }
+}
@@ -223,7 +223,7 @@ impl<K, V> Matcher<HashMap<K, V>> for KeyValueMembershipMatcher<K, V>
         V: Eq + Debug,
 {
     fn test(&self, collection: &HashMap<K, V>) -> MatcherResult {
-        return match self {
+        match self {
             KeyValueMembershipMatcher::KeyValue(key, value) => MatcherResult::formatted(
                 Self::contains_key_value(collection, key, value),
                 format!(
@@ -268,7 +268,7 @@ impl<K, V> Matcher<HashMap<K, V>> for KeyValueMembershipMatcher<K, V>
                     collection, key_values
                 ),
             ),
-        };
+        }
     }
 }
 
@@ -20,6 +20,7 @@ pub mod collection;
 pub mod compose;
 #[cfg(feature = "date")]
 pub mod date;
+pub(crate) mod diff;
 pub mod equal;
 #[cfg(feature = "file")]
 pub mod file;
@@ -30,26 +31,32 @@ pub mod int;
 pub mod map;
 pub mod option;
 pub mod ordered;
+pub mod pattern;
+pub mod performance;
 pub mod range;
 pub mod result;
 pub mod string;
+pub mod time;
+pub mod tuple;
+
+use std::ops::Deref;
 
 /// Should provides a convenient way to express positive assertions within tests, indicating that a value should meet a certain condition.
-pub trait Should<T> {
+pub trait Should<T: ?Sized> {
     /// - Takes a matcher as input and performs an assertion against the value itself.
     /// - Panics if the assertion fails, indicating that the value did not match the matcher's expectations.
     fn should(&self, matcher: &dyn Matcher<T>);
 }
 
 /// ShouldNot provides a convenient way to express negative assertions within tests, indicating that a value should not meet a certain condition.
-pub trait ShouldNot<T> {
+pub trait ShouldNot<T: ?Sized> {
     /// - Takes a matcher as input and performs an inverted assertion against the value itself.
     /// - Inverts the result of the matcher's test method, ensuring the value does not match.
     /// - Panics if the inverted assertion fails, indicating that the value unexpectedly matched the matcher.
     fn should_not(&self, matcher: &dyn Matcher<T>);
 }
 
-impl<T> Should<T> for T {
+impl<T: ?Sized> Should<T> for T {
     fn should(&self, matcher: &dyn Matcher<T>) {
         let matcher_result = matcher.test(self);
         if !matcher_result.passed {
@@ -58,10 +65,10 @@ impl<T> Should<T> for T {
     }
 }
 
-impl<T> ShouldNot<T> for T {
+impl<T: ?Sized> ShouldNot<T> for T {
     fn should_not(&self, matcher: &dyn Matcher<T>) {
         let matcher_result = matcher.test(self);
-        let passed = !matcher_result.passed;
+        let passed = !matcher_result.passed && !matcher_result.errored;
         if !passed {
             panic!(
                 "assertion failed: {}",
@@ -72,7 +79,9 @@ impl<T> ShouldNot<T> for T {
 }
 
 /// Matcher defines the core functionality of matchers. All the matchers implement `Matcher<T>` trait.
-pub trait Matcher<T> {
+///
+/// T may be an unsized type, which allows matchers to be implemented directly for `str`, `[T]`, `Path` and `OsStr`.
+pub trait Matcher<T: ?Sized> {
     fn test(&self, value: &T) -> MatcherResult;
 }
 
@@ -81,19 +90,71 @@ pub trait Matcher<T> {
 /// It is used to compose matchers in [`crate::matchers::compose::Matchers`].
 ///
 /// BoxWrap is implemented for any `T: Matcher<M>`.
-pub trait BoxWrap<W> {
+pub trait BoxWrap<W: ?Sized> {
     fn boxed(self) -> Box<dyn Matcher<W>>;
 }
 
-impl<M, T: Matcher<M> + 'static> BoxWrap<M> for T {
+impl<M: ?Sized, T: Matcher<M> + 'static> BoxWrap<M> for T {
     fn boxed(self) -> Box<dyn Matcher<M>> {
         Box::new(self)
     }
 }
 
+/// ForwardWrap provides a `forwarded` method to wrap a `Matcher<T>` into a [`ForwardingMatcher`].
+///
+/// ForwardWrap is implemented for any matcher `M`.
+pub trait ForwardWrap<M> {
+    fn forwarded(self) -> ForwardingMatcher<M>;
+}
+
+impl<M> ForwardWrap<M> for M {
+    fn forwarded(self) -> ForwardingMatcher<M> {
+        ForwardingMatcher { matcher: self }
+    }
+}
+
+/// ForwardingMatcher forwards a `Matcher<T>` to any value that dereferences to T.
+///
+/// It allows running a matcher written for T against `&T`, `Box<T>`, `Rc<T>`, `Arc<T>` and `Cow<'_, T>`
+/// (and other [`Deref`] types like `String`, `Vec<T>` and `PathBuf`) without a matcher implementation per pointer type.
+///
+/// Forwarding is opt-in, because a blanket implementation is ruled out by coherence:
+/// `impl<P: Deref, M: Matcher<P::Target>> Matcher<P> for M` conflicts with the generic matcher implementations
+/// (like `impl<T> Matcher<[T]> for ...`), and `impl<P: Deref> Should<P::Target> for P` conflicts with `impl<T> Should<T> for T`.
+/// Without `forwarded`, dereference the value explicitly, like `(*collection).should(&contain(2))`.
+///
+/// # Example
+///```
+/// use std::rc::Rc;
+/// use clearcheck::matchers::{ForwardWrap, Matcher};
+/// use clearcheck::matchers::collection::membership::contain;
+///
+/// let collection: Rc<[&str]> = Rc::from(vec!["clearcheck", "junit"]);
+/// let matcher = contain("junit").forwarded();
+///
+/// assert!(matcher.test(&collection).passed());
+/// ```
+pub struct ForwardingMatcher<M> {
+    matcher: M,
+}
+
+impl<P, M> Matcher<P> for ForwardingMatcher<M>
+    where
+        P: Deref + ?Sized,
+        M: Matcher<P::Target>,
+{
+    fn test(&self, value: &P) -> MatcherResult {
+        self.matcher.test(value.deref())
+    }
+}
+
 /// MatcherResult defines the result of a matcher execution.
+///
+/// An errored result (like the one for an unreadable file) fails both [`Should`] and [`ShouldNot`] assertions,
+/// since the matcher could not evaluate the value either way.
 pub struct MatcherResult {
     passed: bool,
+    errored: bool,
     failure_message: String,
     inverted_failure_message: String,
 }
@@ -120,13 +181,150 @@ impl MatcherResult {
     ) -> Self {
         MatcherResult {
             passed,
+            errored: false,
             failure_message,
             inverted_failure_message,
         }
     }
 
+    /// Creates a new instance of MatcherResult for a matcher that could not evaluate the value, using the error message
+    /// as both the failure_message and the inverted_failure_message.
+    pub fn errored(error_message: String) -> Self {
+        MatcherResult {
+            passed: false,
+            errored: true,
+            failure_message: error_message.clone(),
+            inverted_failure_message: error_message,
+        }
+    }
+
     /// Returns true if the result of a matcher execution was successful, false otherwise.
     pub fn passed(&self) -> bool {
         self.passed
     }
 }
+
+#[cfg(test)]
+mod unsized_tests {
+    use crate::matchers::{Should, ShouldNot};
+    use crate::matchers::collection::membership::contain;
+    use crate::matchers::string::boundary::begin_with;
+
+    #[test]
+    fn should_match_str() {
+        let value: &str = "clearcheck";
+        value.should(&begin_with("clear"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_match_str_but_did_not() {
+        let value: &str = "clearcheck";
+        value.should(&begin_with("junit"));
+    }
+
+    #[test]
+    fn should_not_match_slice() {
+        let collection: &[&str] = &["clearcheck", "junit"];
+        collection.should_not(&contain("testify"));
+    }
+}
+
+#[cfg(test)]
+mod forwarding_tests {
+    use std::borrow::Cow;
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::{ForwardWrap, Matcher, Should};
+    use crate::matchers::collection::membership::contain;
+    use crate::matchers::equal::be_equal;
+
+    #[test]
+    fn should_forward_to_reference() {
+        let value = 10;
+        let matcher = be_equal(10).forwarded();
+        matcher.test(&&value).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_forward_to_box() {
+        let collection: Box<[i32]> = Box::new([1, 2, 3]);
+        collection.should(&contain(2).forwarded());
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_forward_to_box_but_did_not_match() {
+        let collection: Box<[i32]> = Box::new([1, 2, 3]);
+        collection.should(&contain(5).forwarded());
+    }
+
+    #[test]
+    fn should_match_box_through_explicit_deref() {
+        let collection: Box<[i32]> = Box::new([1, 2, 3]);
+        (*collection).should(&contain(2));
+    }
+
+    #[test]
+    fn should_forward_to_rc() {
+        let value = Rc::new(10);
+        value.should(&be_equal(10).forwarded());
+    }
+
+    #[test]
+    fn should_forward_to_arc() {
+        let collection: Arc<[&str]> = Arc::from(vec!["clearcheck", "junit"]);
+        collection.should(&contain("junit").forwarded());
+    }
+
+    #[test]
+    fn should_forward_to_cow() {
+        let collection: Cow<[i32]> = Cow::Owned(vec![1, 2, 3]);
+        collection.should(&contain(3).forwarded());
+    }
+}
+
+#[cfg(test)]
+mod errored_tests {
+    use crate::matchers::{BoxWrap, Matcher, MatcherResult, Should, ShouldNot};
+    use crate::matchers::compose::MatchersBuilder;
+    use crate::matchers::string::boundary::begin_with;
+
+    struct UnreadableMatcher;
+
+    impl Matcher<str> for UnreadableMatcher {
+        fn test(&self, value: &str) -> MatcherResult {
+            MatcherResult::errored(format!("{:?} should be readable", value))
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "\"clearcheck\" should be readable")]
+    fn should_fail_given_errored_result() {
+        "clearcheck".should(&UnreadableMatcher);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"clearcheck\" should be readable")]
+    fn should_not_fail_given_errored_result() {
+        "clearcheck".should_not(&UnreadableMatcher);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"clearcheck\" should be readable")]
+    fn should_fail_given_inverted_errored_result() {
+        let matchers = MatchersBuilder::start_building_with_inverted(UnreadableMatcher.boxed()).combine_as_and();
+        "clearcheck".should(&matchers);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"clearcheck\" should be readable")]
+    fn should_fail_given_any_errored_result() {
+        let matchers = MatchersBuilder::start_building(begin_with("clear").boxed())
+            .push(UnreadableMatcher.boxed())
+            .combine_as_or();
+        "clearcheck".should(&matchers);
+    }
+}
@@ -24,15 +24,21 @@ pub enum OrderedMatcher<T: PartialOrd> {
 }
 
 impl<T: Debug + PartialOrd> Matcher<T> for OrderedMatcher<T> {
+    fn test(&self, value: &T) -> MatcherResult {
+        self.borrowed().test(value)
+    }
+}
+
+impl<T: Debug + PartialOrd + ?Sized> Matcher<T> for OrderedMatcher<&T> {
     fn test(&self, value: &T) -> MatcherResult {
         match self {
             OrderedMatcher::Gt(other) => MatcherResult::formatted(
-                value > other,
+                value > *other,
                 format!("{:?} should be greater than {:?}", value, other),
                 format!("{:?} should not be greater than {:?}", value, other),
             ),
             OrderedMatcher::Gte(other) => MatcherResult::formatted(
-                value >= other,
+                value >= *other,
                 format!("{:?} should be greater than equals to {:?}", value, other),
                 format!(
                     "{:?} should not be greater than equals to {:?}",
@@ -40,12 +46,12 @@ impl<T: Debug + PartialOrd> Matcher<T> for OrderedMatcher<T> {
                 ),
             ),
             OrderedMatcher::Lt(other) => MatcherResult::formatted(
-                value < other,
+                value < *other,
                 format!("{:?} should be less than {:?}", value, other),
                 format!("{:?} should not be less than {:?}", value, other),
             ),
             OrderedMatcher::Lte(other) => MatcherResult::formatted(
-                value <= other,
+                value <= *other,
                 format!("{:?} should be less than equals to {:?}", value, other),
                 format!("{:?} should not be less than equals to {:?}", value, other),
             ),
@@ -53,6 +59,17 @@ impl<T: Debug + PartialOrd> Matcher<T> for OrderedMatcher<T> {
     }
 }
 
+impl<T: PartialOrd> OrderedMatcher<T> {
+    fn borrowed(&self) -> OrderedMatcher<&T> {
+        match self {
+            OrderedMatcher::Gt(other) => OrderedMatcher::Gt(other),
+            OrderedMatcher::Gte(other) => OrderedMatcher::Gte(other),
+            OrderedMatcher::Lt(other) => OrderedMatcher::Lt(other),
+            OrderedMatcher::Lte(other) => OrderedMatcher::Lte(other),
+        }
+    }
+}
+
 /// Creates an OrderedMatcher that asserts whether a value is greater than the given value.
 pub fn be_greater_than<T: PartialOrd>(other: T) -> OrderedMatcher<T> {
     OrderedMatcher::Gt(other)
@@ -140,4 +157,17 @@ mod tests {
         let matcher = be_less_than_equal_to(90);
         matcher.test(&value).passed.should_be_true();
     }
+
+    #[test]
+    fn should_be_greater_than_for_unsized_value() {
+        let matcher = be_greater_than("clearcheck");
+        matcher.test("junit").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_greater_than_for_unsized_value_but_was_not() {
+        let matcher = be_greater_than("junit");
+        matcher.test("clearcheck").passed.should_be_true();
+    }
 }
@@ -0,0 +1,227 @@
+use std::fmt::Debug;
+
+use crate::matchers::{Matcher, MatcherResult};
+
+/// PatternMatcher offers a flexible way to assert whether a value matches a pattern, typically an enum variant.
+///
+/// PatternMatcher is created using the [`match_pattern`](crate::match_pattern) macro.
+/// The pattern may optionally be followed by guard matchers that run against the fields bound by the pattern.
+///
+/// Works with any data type that implements the Debug trait.
+///
+/// # Example
+///```
+/// use clearcheck::match_pattern;
+/// use clearcheck::matchers::Matcher;
+/// use clearcheck::matchers::ordered::be_greater_than;
+///
+/// #[derive(Debug)]
+/// enum Event {
+///     Created { id: i32 },
+///     Deleted { id: i32 },
+/// }
+///
+/// let matcher = match_pattern!(Event::Created { id } if id => be_greater_than(0));
+/// assert!(matcher.test(&Event::Created { id: 10 }).passed());
+/// assert!(!matcher.test(&Event::Deleted { id: 10 }).passed());
+/// ```
+pub struct PatternMatcher<F> {
+    pattern: &'static str,
+    matches: F,
+}
+
+impl<F> PatternMatcher<F> {
+    /// Creates a new instance of PatternMatcher.
+    ///
+    /// `matches` returns None if the value does not match the pattern, otherwise the results of running the guard matchers
+    /// against the bound fields, keyed by the field name.
+    pub fn new<T: ?Sized>(pattern: &'static str, matches: F) -> Self
+        where F: Fn(&T) -> Option<Vec<(&'static str, MatcherResult)>>
+    {
+        PatternMatcher { pattern, matches }
+    }
+}
+
+impl<T, F> Matcher<T> for PatternMatcher<F>
+    where
+        T: Debug + ?Sized,
+        F: Fn(&T) -> Option<Vec<(&'static str, MatcherResult)>>,
+{
+    fn test(&self, value: &T) -> MatcherResult {
+        match (self.matches)(value) {
+            None => MatcherResult::formatted(
+                false,
+                format!("{:?} should match the pattern {:?}", value, self.pattern),
+                format!("{:?} should not match the pattern {:?}", value, self.pattern),
+            ),
+            Some(field_results) => {
+                let errored_fields = field_results
+                    .iter()
+                    .filter(|(_, result)| result.errored)
+                    .map(|(field, result)| format!("field {:?}: {}", field, result.failure_message))
+                    .collect::<Vec<_>>();
+                if !errored_fields.is_empty() {
+                    return MatcherResult::errored(format!(
+                        "{:?} should match the pattern {:?} but {}",
+                        value, self.pattern, errored_fields.join(", ")
+                    ));
+                }
+
+                let failed_fields = field_results
+                    .iter()
+                    .filter(|(_, result)| !result.passed)
+                    .map(|(field, result)| format!("field {:?}: {}", field, result.failure_message))
+                    .collect::<Vec<_>>();
+
+                MatcherResult::formatted(
+                    failed_fields.is_empty(),
+                    format!(
+                        "{:?} should match the pattern {:?} but {}",
+                        value, self.pattern, failed_fields.join(", ")
+                    ),
+                    format!("{:?} should not match the pattern {:?}", value, self.pattern),
+                )
+            }
+        }
+    }
+}
+
+/// Creates a [`PatternMatcher`] that asserts whether a value matches the given pattern.
+///
+/// The pattern can be followed by `if` and a comma separated list of `field => matcher` guards, where each field is
+/// a binding introduced by the pattern. A value matches only if it matches the pattern and all the guard matchers pass.
+/// The guard matchers are evaluated every time the PatternMatcher runs.
+///
+/// # Example
+///```
+/// use clearcheck::match_pattern;
+/// use clearcheck::matchers::Matcher;
+/// use clearcheck::matchers::string::boundary::begin_with;
+///
+/// #[derive(Debug)]
+/// enum Response {
+///     Ok { body: String },
+///     NotFound { code: u16 },
+/// }
+///
+/// let matcher = match_pattern!(Response::NotFound { code: 404 });
+/// assert!(matcher.test(&Response::NotFound { code: 404 }).passed());
+///
+/// let matcher = match_pattern!(Response::Ok { body } if body => begin_with("{"));
+/// assert!(matcher.test(&Response::Ok { body: String::from("{}") }).passed());
+/// ```
+#[macro_export]
+macro_rules! match_pattern {
+    ($pattern:pat $(if $($field:ident => $matcher:expr),+ $(,)?)?) => {
+        $crate::matchers::pattern::PatternMatcher::new(
+            stringify!($pattern),
+            |value| match value {
+                $pattern => Some(vec![
+                    $($((stringify!($field), $crate::matchers::Matcher::test(&$matcher, $field))),+)?
+                ]),
+                #[allow(unreachable_patterns)]
+                _ => None,
+            },
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::matchers::{Matcher, MatcherResult};
+    use crate::matchers::ordered::{be_greater_than, be_less_than};
+    use crate::matchers::string::boundary::begin_with;
+
+    #[derive(Debug)]
+    enum Event {
+        Created { id: i32, name: &'static str },
+        Deleted(i32),
+    }
+
+    struct UnreadableMatcher;
+
+    impl<T: ?Sized> Matcher<T> for UnreadableMatcher {
+        fn test(&self, _: &T) -> MatcherResult {
+            MatcherResult::errored(String::from("value should be readable"))
+        }
+    }
+
+    #[test]
+    fn should_match_pattern() {
+        let matcher = match_pattern!(Event::Created { .. });
+        matcher.test(&Event::Created { id: 1, name: "junit" }).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_match_pattern_but_did_not() {
+        let matcher = match_pattern!(Event::Created { .. });
+        matcher.test(&Event::Deleted(1)).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_match_pattern_with_literal_field() {
+        let matcher = match_pattern!(Event::Deleted(404));
+        matcher.test(&Event::Deleted(404)).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_match_pattern_with_guard() {
+        let matcher = match_pattern!(Event::Created { id, .. } if id => be_greater_than(0));
+        matcher.test(&Event::Created { id: 1, name: "junit" }).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_match_pattern_with_guard_but_guard_did_not_pass() {
+        let matcher = match_pattern!(Event::Created { id, .. } if id => be_greater_than(0));
+        matcher.test(&Event::Created { id: -1, name: "junit" }).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_match_pattern_with_multiple_guards() {
+        let matcher = match_pattern!(
+            Event::Created { id, name } if id => be_less_than(10), name => begin_with("ju")
+        );
+        matcher.test(&Event::Created { id: 1, name: "junit" }).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_match_pattern_with_alternatives() {
+        let matcher = match_pattern!(Some(1..=5) | None);
+        matcher.test(&Some(3)).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_report_failing_field() {
+        let matcher = match_pattern!(
+            Event::Created { id, name } if id => be_less_than(10), name => begin_with("ju")
+        );
+        let result = matcher.test(&Event::Created { id: 1, name: "testify" });
+
+        result.passed.should_be_false();
+        result.failure_message.contains("field \"name\"").should_be_true();
+        result.failure_message.contains("field \"id\"").should_be_false();
+    }
+
+    #[test]
+    fn should_propagate_errored_field_result() {
+        let matcher = match_pattern!(
+            Event::Created { id, name } if id => be_less_than(10), name => UnreadableMatcher
+        );
+        let result = matcher.test(&Event::Created { id: 1, name: "junit" });
+
+        result.passed.should_be_false();
+        result.errored.should_be_true();
+        result.failure_message.contains("field \"name\": value should be readable").should_be_true();
+    }
+
+    #[test]
+    fn should_report_actual_value() {
+        let matcher = match_pattern!(Event::Created { .. });
+        let result = matcher.test(&Event::Deleted(1));
+
+        result.failure_message.contains("Deleted(1)").should_be_true();
+    }
+}
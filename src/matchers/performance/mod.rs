@@ -0,0 +1,374 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::matchers::{Matcher, MatcherResult};
+use crate::matchers::time::humanize;
+
+/// Runs describes how many times a closure is run to measure its timings, and how many of the runs are warm-up runs.
+///
+/// The warm-up runs are executed before the measured runs and their timings are discarded.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::performance::Runs;
+///
+/// let runs = Runs::new(20).with_warm_up(3);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Runs {
+    iterations: usize,
+    warm_up: usize,
+}
+
+impl Runs {
+    /// Creates a new instance of Runs with the given number of measured iterations and no warm-up.
+    ///
+    /// Panics if iterations is zero.
+    pub fn new(iterations: usize) -> Self {
+        assert!(iterations > 0, "Runs should have at least one iteration");
+        Runs {
+            iterations,
+            warm_up: 0,
+        }
+    }
+
+    /// Sets the number of warm-up runs.
+    pub fn with_warm_up(self, warm_up: usize) -> Self {
+        Runs { warm_up, ..self }
+    }
+
+    fn measure<F: Fn() -> R, R>(&self, closure: &F) -> Timings {
+        (0..self.warm_up).for_each(|_| {
+            black_box(closure());
+        });
+        let mut durations = (0..self.iterations)
+            .map(|_| time(closure))
+            .collect::<Vec<_>>();
+        durations.sort();
+        Timings { durations }
+    }
+
+    fn describe(&self) -> String {
+        format!("over {} runs (warm-up {})", self.iterations, self.warm_up)
+    }
+}
+
+/// Runs a closure 10 times after 2 warm-up runs.
+impl Default for Runs {
+    fn default() -> Self {
+        Runs::new(10).with_warm_up(2)
+    }
+}
+
+struct Timings {
+    durations: Vec<Duration>,
+}
+
+impl Timings {
+    fn median(&self) -> Duration {
+        self.percentile(50.0)
+    }
+
+    fn p95(&self) -> Duration {
+        self.percentile(95.0)
+    }
+
+    fn percentile(&self, percentile: f64) -> Duration {
+        let rank = (percentile / 100.0 * self.durations.len() as f64).ceil() as usize;
+        self.durations[rank.max(1) - 1]
+    }
+
+    fn summary(&self) -> String {
+        format!(
+            "median {}, p95 {}, max {}",
+            humanize(&self.median()),
+            humanize(&self.p95()),
+            humanize(&self.durations[self.durations.len() - 1])
+        )
+    }
+}
+
+fn time<F: Fn() -> R, R>(closure: &F) -> Duration {
+    let start = Instant::now();
+    black_box(closure());
+    start.elapsed()
+}
+
+/// PerformanceMatcher offers a flexible way to assert the run time of closures.
+///
+/// The failure messages carry the measured timings in a human-readable form.
+///
+/// # Example
+///```
+/// use std::time::Duration;
+/// use clearcheck::matchers::Matcher;
+/// use clearcheck::matchers::performance::complete_within;
+///
+/// let work = || (1..=100).sum::<i32>();
+/// let matcher = complete_within(Duration::from_secs(1));
+///
+/// assert!(matcher.test(&work).passed());
+/// ```
+pub enum PerformanceMatcher {
+    CompleteWithin(Duration),
+    MedianWithin(Runs, Duration),
+    P95Within(Runs, Duration),
+}
+
+impl<F: Fn() -> R, R> Matcher<F> for PerformanceMatcher {
+    fn test(&self, closure: &F) -> MatcherResult {
+        match self {
+            PerformanceMatcher::CompleteWithin(budget) => {
+                let elapsed = time(closure);
+                MatcherResult::formatted(
+                    elapsed <= *budget,
+                    format!(
+                        "Closure should complete within {} but took {}",
+                        humanize(budget),
+                        humanize(&elapsed)
+                    ),
+                    format!(
+                        "Closure should not complete within {} but took {}",
+                        humanize(budget),
+                        humanize(&elapsed)
+                    ),
+                )
+            }
+            PerformanceMatcher::MedianWithin(runs, budget) => {
+                let timings = runs.measure(closure);
+                MatcherResult::formatted(
+                    timings.median() <= *budget,
+                    format!(
+                        "Closure should have a median run time within {} {} but measured {}",
+                        humanize(budget),
+                        runs.describe(),
+                        timings.summary()
+                    ),
+                    format!(
+                        "Closure should not have a median run time within {} {} but measured {}",
+                        humanize(budget),
+                        runs.describe(),
+                        timings.summary()
+                    ),
+                )
+            }
+            PerformanceMatcher::P95Within(runs, budget) => {
+                let timings = runs.measure(closure);
+                MatcherResult::formatted(
+                    timings.p95() <= *budget,
+                    format!(
+                        "Closure should have a p95 run time within {} {} but measured {}",
+                        humanize(budget),
+                        runs.describe(),
+                        timings.summary()
+                    ),
+                    format!(
+                        "Closure should not have a p95 run time within {} {} but measured {}",
+                        humanize(budget),
+                        runs.describe(),
+                        timings.summary()
+                    ),
+                )
+            }
+        }
+    }
+}
+
+/// FasterThanMatcher offers a flexible way to assert that a closure is faster than the other closure by a factor.
+///
+/// Both the closures are measured with the same [`Runs`] and their median run times are compared;
+/// the closure is faster by the factor if its median multiplied by the factor does not exceed the median of the other.
+///
+/// # Example
+///```
+/// use std::thread;
+/// use std::time::Duration;
+/// use clearcheck::matchers::Matcher;
+/// use clearcheck::matchers::performance::be_faster_than;
+///
+/// let work = || (1..=100).sum::<i32>();
+/// let matcher = be_faster_than(|| thread::sleep(Duration::from_millis(20)), 2.0);
+///
+/// assert!(matcher.test(&work).passed());
+/// ```
+pub struct FasterThanMatcher<G> {
+    other: G,
+    factor: f64,
+    runs: Runs,
+}
+
+impl<F: Fn() -> R, R, G: Fn() -> S, S> Matcher<F> for FasterThanMatcher<G> {
+    fn test(&self, closure: &F) -> MatcherResult {
+        let timings = self.runs.measure(closure);
+        let other_timings = self.runs.measure(&self.other);
+        let passed = Duration::try_from_secs_f64(timings.median().as_secs_f64() * self.factor)
+            .is_ok_and(|scaled_median| scaled_median <= other_timings.median());
+
+        MatcherResult::formatted(
+            passed,
+            format!(
+                "Closure should be faster than the other closure by a factor of {} {} but measured {} against {}",
+                self.factor,
+                self.runs.describe(),
+                timings.summary(),
+                other_timings.summary()
+            ),
+            format!(
+                "Closure should not be faster than the other closure by a factor of {} {} but measured {} against {}",
+                self.factor,
+                self.runs.describe(),
+                timings.summary(),
+                other_timings.summary()
+            ),
+        )
+    }
+}
+
+/// Creates a PerformanceMatcher that asserts whether a single run of a closure completes within the budget.
+pub fn complete_within(budget: Duration) -> PerformanceMatcher {
+    PerformanceMatcher::CompleteWithin(budget)
+}
+
+/// Creates a PerformanceMatcher that asserts whether the median run time of a closure, over the given runs, is within the budget.
+pub fn have_median_within(runs: Runs, budget: Duration) -> PerformanceMatcher {
+    PerformanceMatcher::MedianWithin(runs, budget)
+}
+
+/// Creates a PerformanceMatcher that asserts whether the 95th percentile run time of a closure, over the given runs, is within the budget.
+pub fn have_p95_within(runs: Runs, budget: Duration) -> PerformanceMatcher {
+    PerformanceMatcher::P95Within(runs, budget)
+}
+
+/// Creates a FasterThanMatcher that asserts whether a closure is faster than the other by the factor, using the default [`Runs`].
+///
+/// Panics if the factor is negative, NaN or infinite.
+pub fn be_faster_than<G>(other: G, factor: f64) -> FasterThanMatcher<G> {
+    be_faster_than_over(other, factor, Runs::default())
+}
+
+/// Creates a FasterThanMatcher that asserts whether a closure is faster than the other by the factor, using the given [`Runs`].
+///
+/// Panics if the factor is negative, NaN or infinite.
+pub fn be_faster_than_over<G>(other: G, factor: f64, runs: Runs) -> FasterThanMatcher<G> {
+    assert!(
+        factor.is_finite() && factor >= 0.0,
+        "factor should be a finite, non-negative number but was {}",
+        factor
+    );
+    FasterThanMatcher {
+        other,
+        factor,
+        runs,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::equal::EqualityAssertion;
+    use crate::assertions::string::membership::MembershipAssertion;
+    use crate::matchers::Matcher;
+    use crate::matchers::performance::{
+        be_faster_than, complete_within, have_median_within, have_p95_within, Runs, Timings,
+    };
+
+    fn sleep(millis: u64) -> impl Fn() {
+        move || thread::sleep(Duration::from_millis(millis))
+    }
+
+    #[test]
+    fn should_complete_within() {
+        let matcher = complete_within(Duration::from_secs(10));
+        matcher.test(&sleep(1)).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_complete_within_but_did_not() {
+        let matcher = complete_within(Duration::from_millis(1));
+        matcher.test(&sleep(10)).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_report_measured_time() {
+        let matcher = complete_within(Duration::from_millis(1));
+        let result = matcher.test(&sleep(10));
+
+        result.failure_message.should_contain("Closure should complete within 1ms but took");
+    }
+
+    #[test]
+    fn should_have_median_within() {
+        let matcher = have_median_within(Runs::new(5).with_warm_up(1), Duration::from_secs(10));
+        matcher.test(&sleep(1)).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_median_within_but_did_not() {
+        let matcher = have_median_within(Runs::new(3), Duration::from_millis(1));
+        matcher.test(&sleep(5)).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_p95_within() {
+        let matcher = have_p95_within(Runs::new(5).with_warm_up(1), Duration::from_secs(10));
+        matcher.test(&sleep(1)).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_p95_within_but_did_not() {
+        let matcher = have_p95_within(Runs::new(3), Duration::from_millis(1));
+        matcher.test(&sleep(5)).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_faster_than() {
+        let matcher = be_faster_than(sleep(20), 2.0);
+        matcher.test(&|| ()).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_faster_than_given_the_scaled_median_overflows() {
+        let matcher = be_faster_than(|| (), f64::MAX);
+        matcher.test(&sleep(1)).passed.should_be_false();
+    }
+
+    #[test]
+    #[should_panic(expected = "factor should be a finite, non-negative number but was -1")]
+    fn should_not_create_faster_than_matcher_with_negative_factor() {
+        be_faster_than(|| (), -1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "factor should be a finite, non-negative number but was NaN")]
+    fn should_not_create_faster_than_matcher_with_nan_factor() {
+        be_faster_than(|| (), f64::NAN);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_faster_than_but_was_not() {
+        let matcher = be_faster_than(|| (), 1.0);
+        matcher.test(&sleep(2)).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_create_runs_without_iterations() {
+        Runs::new(0);
+    }
+
+    #[test]
+    fn should_find_percentiles() {
+        let timings = Timings {
+            durations: (1..=20).map(Duration::from_millis).collect(),
+        };
+        timings.median().should_equal(&Duration::from_millis(10));
+        timings.p95().should_equal(&Duration::from_millis(19));
+    }
+}
@@ -16,7 +16,7 @@ pub enum BoundaryMatcher {
 }
 
 impl<T> Matcher<T> for BoundaryMatcher
-    where T: AsRef<str>
+    where T: AsRef<str> + ?Sized
 {
     fn test(&self, value: &T) -> MatcherResult {
         match self {
@@ -56,6 +56,12 @@ mod tests {
         matcher.test(&"goselect").passed.should_be_true();
     }
 
+    #[test]
+    fn should_begin_with_for_str() {
+        let matcher = begin_with("go");
+        matcher.test("goselect").passed.should_be_true();
+    }
+
     #[test]
     #[should_panic]
     fn should_begin_with_but_did_not() {
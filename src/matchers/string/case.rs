@@ -1,6 +1,20 @@
 use crate::matchers::{Matcher, MatcherResult};
 
-/// CaseMatcher offers a flexible way to assert that a string is either lowercase or uppercase.
+/// CaseMatcher offers a flexible way to assert the case, or the naming convention, of a string.
+///
+/// - Lower and Upper: the string has a cased letter, and is unchanged by lowercasing (or uppercasing).
+///   A string without cased letters (like "" or "123") is neither lowercase nor uppercase.
+/// - Title: every whitespace-separated word starts with an uppercase letter (or a non-letter)
+///   and has no other uppercase letters, like "Getting Started".
+/// - Camel: a lowercase letter followed by letters and digits, without consecutive uppercase letters, like "parseRequest".
+/// - Pascal: an uppercase letter followed by letters and digits, without consecutive uppercase letters, like "ParseRequest".
+///   Acronyms are written as words ("HttpServer", not "HTTPServer"), so that "PARSE" is not pascal case.
+/// - Snake: lowercase letters and digits in words separated by single underscores, starting with a letter, like "parse_request".
+/// - ScreamingSnake: uppercase letters and digits in words separated by single underscores, starting with a letter, like "MAX_RETRIES".
+/// - Kebab: lowercase letters and digits in words separated by single hyphens, starting with a letter, like "parse-request".
+/// - FirstCharUppercase: the first character is an uppercase letter.
+///
+/// Failure messages of the naming conventions name the first offending character and its char index.
 ///
 /// # Example
 ///```
@@ -13,25 +27,138 @@ use crate::matchers::{Matcher, MatcherResult};
 pub enum CaseMatcher {
     Lower,
     Upper,
+    Title,
+    Camel,
+    Pascal,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    FirstCharUppercase,
 }
 
 impl<T> Matcher<T> for CaseMatcher
-where T: AsRef<str> + PartialEq
+where T: AsRef<str> + PartialEq + ?Sized
 {
     fn test(&self, value: &T) -> MatcherResult {
+        let value = value.as_ref();
         match self {
             CaseMatcher::Lower => MatcherResult::formatted(
-                value.as_ref() == value.as_ref().to_lowercase(),
-                format!("{:?} should be lowercase", value.as_ref()),
-                format!("{:?} should not be lowercase", value.as_ref()),
+                value.chars().any(|ch| ch.is_lowercase()) && value == value.to_lowercase(),
+                format!("{:?} should be lowercase", value),
+                format!("{:?} should not be lowercase", value),
             ),
             CaseMatcher::Upper => MatcherResult::formatted(
-                value.as_ref() == value.as_ref().to_uppercase(),
-                format!("{:?} should be uppercase", value.as_ref()),
-                format!("{:?} should not be uppercase", value.as_ref()),
+                value.chars().any(|ch| ch.is_uppercase()) && value == value.to_uppercase(),
+                format!("{:?} should be uppercase", value),
+                format!("{:?} should not be uppercase", value),
+            ),
+            CaseMatcher::Title => convention_result(value, "title case", validate_title_case(value)),
+            CaseMatcher::Camel => {
+                convention_result(value, "camel case", validate_identifier(value, |ch| ch.is_lowercase()))
+            }
+            CaseMatcher::Pascal => {
+                convention_result(value, "pascal case", validate_identifier(value, |ch| ch.is_uppercase()))
+            }
+            CaseMatcher::Snake => convention_result(
+                value,
+                "snake case",
+                validate_delimited(value, '_', |ch| ch.is_lowercase() || ch.is_numeric()),
+            ),
+            CaseMatcher::ScreamingSnake => convention_result(
+                value,
+                "screaming snake case",
+                validate_delimited(value, '_', |ch| ch.is_uppercase() || ch.is_numeric()),
             ),
+            CaseMatcher::Kebab => convention_result(
+                value,
+                "kebab case",
+                validate_delimited(value, '-', |ch| ch.is_lowercase() || ch.is_numeric()),
+            ),
+            CaseMatcher::FirstCharUppercase => {
+                let first = value.chars().next();
+                let found = match first {
+                    Some(ch) => format!("was {:?}", ch),
+                    None => "was empty".to_string(),
+                };
+                MatcherResult::formatted(
+                    first.is_some_and(|ch| ch.is_uppercase()),
+                    format!("{:?} should have the first character uppercase but {}", value, found),
+                    format!("{:?} should not have the first character uppercase", value),
+                )
+            }
+        }
+    }
+}
+
+fn convention_result(value: &str, convention: &str, validation: Result<(), String>) -> MatcherResult {
+    let reason = match &validation {
+        Ok(_) => String::new(),
+        Err(reason) => format!(" but {}", reason),
+    };
+    MatcherResult::formatted(
+        validation.is_ok(),
+        format!("{:?} should be {}{}", value, convention, reason),
+        format!("{:?} should not be {}", value, convention),
+    )
+}
+
+fn offending(ch: char, index: usize) -> String {
+    format!("found {:?} at index {}", ch, index)
+}
+
+fn validate_title_case(value: &str) -> Result<(), String> {
+    if !value.chars().any(|ch| ch.is_uppercase()) {
+        return Err("had no uppercase letter".to_string());
+    }
+    let mut word_start = true;
+    for (index, ch) in value.chars().enumerate() {
+        if (word_start && ch.is_lowercase()) || (!word_start && ch.is_uppercase()) {
+            return Err(offending(ch, index));
         }
+        word_start = ch.is_whitespace();
     }
+    Ok(())
+}
+
+fn validate_identifier<F: Fn(char) -> bool>(value: &str, first: F) -> Result<(), String> {
+    let mut previous_uppercase = false;
+    for (index, ch) in value.chars().enumerate() {
+        let valid = if index == 0 {
+            first(ch)
+        } else {
+            ch.is_alphanumeric() && !(previous_uppercase && ch.is_uppercase())
+        };
+        if !valid {
+            return Err(offending(ch, index));
+        }
+        previous_uppercase = ch.is_uppercase();
+    }
+    if value.is_empty() {
+        return Err("was empty".to_string());
+    }
+    Ok(())
+}
+
+fn validate_delimited<F: Fn(char) -> bool>(value: &str, separator: char, word_char: F) -> Result<(), String> {
+    let length = value.chars().count();
+    if length == 0 {
+        return Err("was empty".to_string());
+    }
+    let mut previous = separator;
+    for (index, ch) in value.chars().enumerate() {
+        let valid = if index == 0 {
+            ch.is_alphabetic() && word_char(ch)
+        } else if ch == separator {
+            previous != separator && index != length - 1
+        } else {
+            word_char(ch)
+        };
+        if !valid {
+            return Err(offending(ch, index));
+        }
+        previous = ch;
+    }
+    Ok(())
 }
 
 /// Creates a CaseMatcher that asserts whether a string value is composed of lowercase letters.
@@ -44,10 +171,49 @@ pub fn be_uppercase() -> CaseMatcher {
     CaseMatcher::Upper
 }
 
+/// Creates a CaseMatcher that asserts whether every word of a string value starts with an uppercase letter, like "Getting Started".
+pub fn be_title_case() -> CaseMatcher {
+    CaseMatcher::Title
+}
+
+/// Creates a CaseMatcher that asserts whether a string value is in camel case, like "parseRequest".
+pub fn be_camel_case() -> CaseMatcher {
+    CaseMatcher::Camel
+}
+
+/// Creates a CaseMatcher that asserts whether a string value is in pascal case, like "ParseRequest".
+pub fn be_pascal_case() -> CaseMatcher {
+    CaseMatcher::Pascal
+}
+
+/// Creates a CaseMatcher that asserts whether a string value is in snake case, like "parse_request".
+pub fn be_snake_case() -> CaseMatcher {
+    CaseMatcher::Snake
+}
+
+/// Creates a CaseMatcher that asserts whether a string value is in screaming snake case, like "MAX_RETRIES".
+pub fn be_screaming_snake_case() -> CaseMatcher {
+    CaseMatcher::ScreamingSnake
+}
+
+/// Creates a CaseMatcher that asserts whether a string value is in kebab case, like "parse-request".
+pub fn be_kebab_case() -> CaseMatcher {
+    CaseMatcher::Kebab
+}
+
+/// Creates a CaseMatcher that asserts whether the first character of a string value is an uppercase letter.
+pub fn have_first_char_uppercase() -> CaseMatcher {
+    CaseMatcher::FirstCharUppercase
+}
+
 #[cfg(test)]
 mod tests {
     use crate::assertions::bool::TrueFalseAssertion;
-    use crate::matchers::string::case::{be_lowercase, be_uppercase};
+    use crate::assertions::string::membership::MembershipAssertion;
+    use crate::matchers::string::case::{
+        be_camel_case, be_kebab_case, be_lowercase, be_pascal_case, be_screaming_snake_case, be_snake_case, be_title_case,
+        be_uppercase, have_first_char_uppercase,
+    };
     use crate::matchers::Matcher;
 
     #[test]
@@ -75,4 +241,163 @@ mod tests {
         let matcher = be_uppercase();
         matcher.test(&"GoSelect").passed.should_be_true();
     }
+
+    #[test]
+    fn should_be_neither_lowercase_nor_uppercase_given_only_digits() {
+        be_lowercase().test(&"2024").passed.should_be_false();
+        be_uppercase().test(&"2024").passed.should_be_false();
+    }
+
+    #[test]
+    fn should_be_lowercase_given_digits_and_lowercase_letters() {
+        let matcher = be_lowercase();
+        matcher.test(&"assert4j").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_title_case() {
+        let matcher = be_title_case();
+        matcher.test(&"Getting Started With 2 Matchers").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_title_case() {
+        let matcher = be_title_case();
+        let result = matcher.test(&"Getting started");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("\"Getting started\" should be title case but found 's' at index 8");
+    }
+
+    #[test]
+    fn should_not_be_title_case_given_uppercase_inside_a_word() {
+        let matcher = be_title_case();
+        matcher.test(&"GEtting Started").passed.should_be_false();
+    }
+
+    #[test]
+    fn should_be_camel_case() {
+        let matcher = be_camel_case();
+        matcher.test(&"parseHttpRequest2").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_camel_case() {
+        let matcher = be_camel_case();
+        let result = matcher.test(&"ParseRequest");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should be camel case but found 'P' at index 0");
+    }
+
+    #[test]
+    fn should_not_be_camel_case_given_consecutive_uppercase_letters() {
+        let matcher = be_camel_case();
+        let result = matcher.test(&"pARSE");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should be camel case but found 'R' at index 2");
+    }
+
+    #[test]
+    fn should_be_pascal_case() {
+        let matcher = be_pascal_case();
+        matcher.test(&"ParseRequest").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_pascal_case() {
+        let matcher = be_pascal_case();
+        let result = matcher.test(&"Parse_Request");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should be pascal case but found '_' at index 5");
+    }
+
+    #[test]
+    fn should_not_be_pascal_case_given_screaming_snake_case() {
+        let matcher = be_pascal_case();
+        let result = matcher.test(&"PARSE");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should be pascal case but found 'A' at index 1");
+    }
+
+    #[test]
+    fn should_not_be_pascal_case_given_empty_string() {
+        let matcher = be_pascal_case();
+        let result = matcher.test(&"");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should be pascal case but was empty");
+    }
+
+    #[test]
+    fn should_be_snake_case() {
+        let matcher = be_snake_case();
+        matcher.test(&"parse_http_request_2").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_snake_case_given_uppercase() {
+        let matcher = be_snake_case();
+        let result = matcher.test(&"parse_Http");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should be snake case but found 'H' at index 6");
+    }
+
+    #[test]
+    fn should_not_be_snake_case_given_double_underscore() {
+        let matcher = be_snake_case();
+        let result = matcher.test(&"parse__http");
+        result.failure_message.should_contain("found '_' at index 6");
+    }
+
+    #[test]
+    fn should_not_be_snake_case_given_trailing_underscore() {
+        let matcher = be_snake_case();
+        matcher.test(&"parse_").passed.should_be_false();
+    }
+
+    #[test]
+    fn should_not_be_snake_case_given_leading_digit() {
+        let matcher = be_snake_case();
+        matcher.test(&"2_parse").passed.should_be_false();
+    }
+
+    #[test]
+    fn should_be_screaming_snake_case() {
+        let matcher = be_screaming_snake_case();
+        matcher.test(&"MAX_RETRIES_2").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_screaming_snake_case() {
+        let matcher = be_screaming_snake_case();
+        let result = matcher.test(&"MAX_Retries");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should be screaming snake case but found 'e' at index 5");
+    }
+
+    #[test]
+    fn should_be_kebab_case() {
+        let matcher = be_kebab_case();
+        matcher.test(&"parse-http-request").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_kebab_case() {
+        let matcher = be_kebab_case();
+        let result = matcher.test(&"parse_http");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should be kebab case but found '_' at index 5");
+    }
+
+    #[test]
+    fn should_have_first_char_uppercase() {
+        let matcher = have_first_char_uppercase();
+        matcher.test(&"Clearcheck").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_have_first_char_uppercase() {
+        let matcher = have_first_char_uppercase();
+        let result = matcher.test(&"clearcheck");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should have the first character uppercase but was 'c'");
+    }
 }
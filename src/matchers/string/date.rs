@@ -0,0 +1,172 @@
+use chrono::{DateTime, NaiveDate};
+
+use crate::matchers::{Matcher, MatcherResult};
+
+/// DateFormatMatcher offers a flexible way to assert whether a string is a valid date or timestamp.
+///
+/// The failure messages carry the parse error.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::string::date::be_rfc3339;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = be_rfc3339();
+/// assert!(matcher.test(&"2024-01-10T10:20:30+05:30").passed());
+/// ```
+pub enum DateFormatMatcher {
+    Rfc3339,
+    Iso8601Date,
+    Format(&'static str),
+}
+
+impl<T: AsRef<str> + ?Sized> Matcher<T> for DateFormatMatcher {
+    fn test(&self, value: &T) -> MatcherResult {
+        let value = value.as_ref();
+        let (description, parse_result) = match self {
+            DateFormatMatcher::Rfc3339 => (
+                "an RFC 3339 timestamp".to_string(),
+                DateTime::parse_from_rfc3339(value).map(|_| ()).map_err(|error| error.to_string()),
+            ),
+            DateFormatMatcher::Iso8601Date => (
+                ISO8601_DATE.to_string(),
+                parse_iso8601_date(value).map(|_| ()),
+            ),
+            DateFormatMatcher::Format(format) => (
+                format!("a date with the format {:?}", format),
+                NaiveDate::parse_from_str(value, format).map(|_| ()).map_err(|error| error.to_string()),
+            ),
+        };
+        match parse_result {
+            Ok(_) => MatcherResult::formatted(
+                true,
+                format!("{:?} should be {}", value, description),
+                format!("{:?} should not be {}", value, description),
+            ),
+            Err(error) => MatcherResult::formatted(
+                false,
+                parse_failure_message(value, &description, &error),
+                format!("{:?} should not be {}", value, description),
+            ),
+        }
+    }
+}
+
+const ISO8601_DATE: &str = "an ISO 8601 date";
+
+// chrono's `FromStr` for NaiveDate is lenient (it accepts spaces, signs and single-digit months),
+// so the shape `YYYY-MM-DD` is checked before parsing.
+pub(crate) fn parse_iso8601_date(value: &str) -> Result<NaiveDate, String> {
+    let has_shape = value.len() == 10
+        && value.bytes().enumerate().all(|(index, byte)| match index {
+            4 | 7 => byte == b'-',
+            _ => byte.is_ascii_digit(),
+        });
+    if !has_shape {
+        return Err("expected the shape YYYY-MM-DD".to_string());
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|error| error.to_string())
+}
+
+pub(crate) fn iso8601_date_failure_message(value: &str, error: &str) -> String {
+    parse_failure_message(value, ISO8601_DATE, error)
+}
+
+fn parse_failure_message(value: &str, description: &str, error: &str) -> String {
+    format!("{:?} should be {} but failed to parse: {}", value, description, error)
+}
+
+/// Creates a DateFormatMatcher that asserts whether a string is an RFC 3339 timestamp, like `2024-01-10T10:20:30Z`.
+pub fn be_rfc3339() -> DateFormatMatcher {
+    DateFormatMatcher::Rfc3339
+}
+
+/// Creates a DateFormatMatcher that asserts whether a string is an ISO 8601 calendar date in the `YYYY-MM-DD` shape, like `2024-01-10`.
+pub fn be_iso8601_date() -> DateFormatMatcher {
+    DateFormatMatcher::Iso8601Date
+}
+
+/// Creates a DateFormatMatcher that asserts whether a string parses as a date with the given chrono format, like `%d/%m/%Y`.
+pub fn parse_as_date_with_format(format: &'static str) -> DateFormatMatcher {
+    DateFormatMatcher::Format(format)
+}
+
+#[cfg(all(test, feature = "date"))]
+mod tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::string::membership::MembershipAssertion;
+    use crate::matchers::Matcher;
+    use crate::matchers::string::date::{be_iso8601_date, be_rfc3339, parse_as_date_with_format};
+
+    #[test]
+    fn should_be_rfc3339() {
+        let matcher = be_rfc3339();
+        matcher.test(&"2024-01-10T10:20:30Z").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_rfc3339_but_was_not() {
+        let matcher = be_rfc3339();
+        matcher.test(&"2024-01-10 10:20:30").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_iso8601_date() {
+        let matcher = be_iso8601_date();
+        matcher.test(&"2024-02-29").passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_iso8601_date_but_was_not() {
+        let matcher = be_iso8601_date();
+        matcher.test(&"2023-02-29").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_iso8601_date_given_spaces_around_separators() {
+        let matcher = be_iso8601_date();
+        matcher.test(&"2024 - 01 - 10").passed.should_be_false();
+    }
+
+    #[test]
+    fn should_not_be_iso8601_date_given_leading_whitespace() {
+        let matcher = be_iso8601_date();
+        matcher.test(&" 2024-01-10").passed.should_be_false();
+    }
+
+    #[test]
+    fn should_not_be_iso8601_date_given_a_sign() {
+        let matcher = be_iso8601_date();
+        matcher.test(&"+2024-01-10").passed.should_be_false();
+    }
+
+    #[test]
+    fn should_not_be_iso8601_date_given_single_digit_month_and_day() {
+        let matcher = be_iso8601_date();
+        let result = matcher.test(&"2024-1-5");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("\"2024-1-5\" should be an ISO 8601 date but failed to parse: expected the shape YYYY-MM-DD");
+    }
+
+    #[test]
+    fn should_parse_as_date_with_format() {
+        let matcher = parse_as_date_with_format("%d/%m/%Y");
+        matcher.test(&String::from("10/01/2024")).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_parse_as_date_with_format_but_did_not() {
+        let matcher = parse_as_date_with_format("%d/%m/%Y");
+        matcher.test(&"2024-01-10").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_report_parse_error() {
+        let matcher = be_iso8601_date();
+        let result = matcher.test(&"2024-13-10");
+        result.failure_message.should_contain("\"2024-13-10\" should be an ISO 8601 date but failed to parse: input is out of range");
+    }
+}
@@ -16,7 +16,7 @@ pub enum StringEmptyMatcher {
 }
 
 impl<T> Matcher<T> for StringEmptyMatcher
-    where T: AsRef<str>
+    where T: AsRef<str> + ?Sized
 {
     fn test(&self, value: &T) -> MatcherResult {
         match self {
@@ -1,23 +1,228 @@
-//! provides [IgnoreCaseEqualityMatcher] for &str.
+//! provides [IgnoreCaseEqualityMatcher] for &str, and [LinesMatcher] and [LineMatcher] for multi-line strings.
 
+use crate::matchers::diff::unified_diff;
 use crate::matchers::equal::IgnoreCaseEqualityMatcher;
 use crate::matchers::{Matcher, MatcherResult};
+use crate::matchers::string::similarity::levenshtein;
 
 impl<T> Matcher<T> for IgnoreCaseEqualityMatcher<&str>
-where T: AsRef<str>
+where T: AsRef<str> + ?Sized
 {
     fn test(&self, value: &T) -> MatcherResult {
+        let passed = value.as_ref().eq_ignore_ascii_case(self.other);
+        let hint = if passed {
+            String::new()
+        } else {
+            let distance = levenshtein(
+                &value.as_ref().to_ascii_lowercase(),
+                &self.other.to_ascii_lowercase(),
+            );
+            format!(" but was {} edit(s) away ignoring case", distance)
+        };
         MatcherResult::formatted(
-            value.as_ref().eq_ignore_ascii_case(self.other),
-            format!("{:?} should equal {:?}", value.as_ref(), self.other),
+            passed,
+            format!("{:?} should equal {:?}{}", value.as_ref(), self.other, hint),
             format!("{:?} should not equal {:?}", value.as_ref(), self.other),
         )
     }
 }
 
+/// LinesMatcher offers a flexible way to compare multi-line strings line by line.
+///
+/// Failure messages contain a unified diff of the lines (expected as `---`, actual as `+++`) instead of one escaped string.
+///
+/// - EqualLines: the lines are equal, so `\n` and `\r\n` line endings and a trailing line ending do not matter.
+/// - NumberOfLines: the string has the given number of lines.
+/// - EqualIgnoringWhitespace: the strings are equal once every run of whitespace (including line breaks) is treated as a single space,
+///   and leading and trailing whitespace is ignored.
+/// - EqualIgnoringLineEndings: the strings are equal once `\r\n` and `\r` are replaced with `\n`.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::string::equal::equal_lines;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = equal_lines("fn main() {\n}\n");
+/// assert!(matcher.test(&"fn main() {\r\n}").passed());
+/// ```
+pub enum LinesMatcher {
+    EqualLines(String),
+    NumberOfLines(usize),
+    EqualIgnoringWhitespace(String),
+    EqualIgnoringLineEndings(String),
+}
+
+impl<T> Matcher<T> for LinesMatcher
+where T: AsRef<str> + ?Sized
+{
+    fn test(&self, value: &T) -> MatcherResult {
+        let value = value.as_ref();
+        match self {
+            LinesMatcher::EqualLines(expected) => MatcherResult::formatted(
+                value.lines().eq(expected.lines()),
+                format!(
+                    "{} should equal {} line by line\n{}",
+                    lines_of(value, "string"),
+                    lines_of(expected, "expected string"),
+                    unified_diff("expected", "actual", expected, value)
+                ),
+                format!(
+                    "{} should not equal the expected string line by line",
+                    lines_of(value, "string")
+                ),
+            ),
+            LinesMatcher::NumberOfLines(count) => {
+                let actual = value.lines().count();
+                MatcherResult::formatted(
+                    actual == *count,
+                    format!("{:?} should have {} lines but had {}", value, count, actual),
+                    format!("{:?} should not have {} lines", value, count),
+                )
+            }
+            LinesMatcher::EqualIgnoringWhitespace(expected) => MatcherResult::formatted(
+                value.split_whitespace().eq(expected.split_whitespace()),
+                format!(
+                    "{} should equal {} ignoring whitespace\n{}",
+                    lines_of(value, "string"),
+                    lines_of(expected, "expected string"),
+                    unified_diff("expected", "actual", expected, value)
+                ),
+                format!(
+                    "{} should not equal the expected string ignoring whitespace",
+                    lines_of(value, "string")
+                ),
+            ),
+            LinesMatcher::EqualIgnoringLineEndings(expected) => {
+                let mut diff = unified_diff("expected", "actual", expected, value);
+                if diff.is_empty() {
+                    diff = String::from("the lines are equal but one string has a trailing line ending");
+                }
+                MatcherResult::formatted(
+                    normalize_line_endings(value) == normalize_line_endings(expected),
+                    format!(
+                        "{} should equal {} ignoring line endings\n{}",
+                        lines_of(value, "string"),
+                        lines_of(expected, "expected string"),
+                        diff
+                    ),
+                    format!(
+                        "{} should not equal the expected string ignoring line endings",
+                        lines_of(value, "string")
+                    ),
+                )
+            }
+        }
+    }
+}
+
+/// LineMatcher offers a flexible way to assert that a specific line, or any line, of a multi-line string matches the given string matcher.
+///
+/// Line numbers start at 1.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::string::boundary::begin_with;
+/// use clearcheck::matchers::string::equal::have_line;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = have_line(2, begin_with("version"));
+/// assert!(matcher.test(&"[package]\nversion = \"0.0.2\"").passed());
+/// ```
+pub enum LineMatcher<M: Matcher<str>> {
+    Line(usize, M),
+    AnyLine(M),
+}
+
+impl<T, M> Matcher<T> for LineMatcher<M>
+where T: AsRef<str> + ?Sized,
+      M: Matcher<str>
+{
+    fn test(&self, value: &T) -> MatcherResult {
+        let value = value.as_ref();
+        match self {
+            LineMatcher::Line(line_number, matcher) => {
+                match line_number.checked_sub(1).and_then(|index| value.lines().nth(index)) {
+                    Some(line) => {
+                        let result = matcher.test(line);
+                        MatcherResult::formatted(
+                            result.passed,
+                            format!("line {} {:?} did not match: {}", line_number, line, result.failure_message),
+                            format!("line {} {:?} matched: {}", line_number, line, result.inverted_failure_message),
+                        )
+                    }
+                    None => {
+                        let message = format!(
+                            "{:?} should have line {} but had {} lines",
+                            value,
+                            line_number,
+                            value.lines().count()
+                        );
+                        MatcherResult::formatted(false, message.clone(), message)
+                    }
+                }
+            }
+            LineMatcher::AnyLine(matcher) => {
+                let matching = value
+                    .lines()
+                    .enumerate()
+                    .find(|(_, line)| matcher.test(*line).passed);
+                MatcherResult::formatted(
+                    matching.is_some(),
+                    format!("{:?} should contain a line matching the given matcher", value),
+                    format!(
+                        "{:?} should not contain a line matching the given matcher but line {} {:?} matched",
+                        value,
+                        matching.map(|(index, _)| index + 1).unwrap_or_default(),
+                        matching.map(|(_, line)| line).unwrap_or_default()
+                    ),
+                )
+            }
+        }
+    }
+}
+
+fn lines_of(value: &str, name: &str) -> String {
+    format!("{} of {} line(s)", name, value.lines().count())
+}
+
+fn normalize_line_endings(value: &str) -> String {
+    value.replace("\r\n", "\n").replace('\r', "\n")
+}
+
+/// Creates a LinesMatcher that asserts whether a string has the same lines as the given string.
+pub fn equal_lines(expected: &str) -> LinesMatcher {
+    LinesMatcher::EqualLines(expected.to_string())
+}
+
+/// Creates a LinesMatcher that asserts whether a string has the given number of lines.
+pub fn have_number_of_lines(count: usize) -> LinesMatcher {
+    LinesMatcher::NumberOfLines(count)
+}
+
+/// Creates a LinesMatcher that asserts whether a string equals the given string, treating every run of whitespace as a single space.
+pub fn equal_ignoring_whitespace(expected: &str) -> LinesMatcher {
+    LinesMatcher::EqualIgnoringWhitespace(expected.to_string())
+}
+
+/// Creates a LinesMatcher that asserts whether a string equals the given string, treating `\r\n`, `\r` and `\n` line endings as the same.
+pub fn equal_ignoring_line_endings(expected: &str) -> LinesMatcher {
+    LinesMatcher::EqualIgnoringLineEndings(expected.to_string())
+}
+
+/// Creates a LineMatcher that asserts whether the given line (starting at 1) of a string matches the given string matcher.
+pub fn have_line<M: Matcher<str>>(line_number: usize, matcher: M) -> LineMatcher<M> {
+    LineMatcher::Line(line_number, matcher)
+}
+
+/// Creates a LineMatcher that asserts whether any line of a string matches the given string matcher.
+pub fn contain_line_matching<M: Matcher<str>>(matcher: M) -> LineMatcher<M> {
+    LineMatcher::AnyLine(matcher)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::string::membership::MembershipAssertion;
     use crate::matchers::equal::be_equal_ignoring_case;
     use crate::matchers::Matcher;
 
@@ -33,4 +238,127 @@ mod tests {
         let matcher = be_equal_ignoring_case("assert");
         matcher.test(&"assert4J").passed.should_be_true();
     }
+
+    #[test]
+    fn should_report_the_edit_distance_given_not_equal() {
+        let matcher = be_equal_ignoring_case("ASSERT");
+        let result = matcher.test(&"assert4J");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("\"assert4J\" should equal \"ASSERT\" but was 2 edit(s) away ignoring case");
+    }
+}
+
+#[cfg(test)]
+mod lines_tests {
+    use crate::assertions::equal::EqualityAssertion;
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::string::membership::MembershipAssertion;
+    use crate::matchers::Matcher;
+    use crate::matchers::string::boundary::begin_with;
+    use crate::matchers::string::equal::{
+        contain_line_matching, equal_ignoring_line_endings, equal_ignoring_whitespace, equal_lines,
+        have_line, have_number_of_lines,
+    };
+
+    #[test]
+    fn should_equal_lines_with_different_line_endings() {
+        let matcher = equal_lines("one\ntwo\n");
+        matcher.test(&"one\r\ntwo").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_equal_lines_with_a_diff() {
+        let matcher = equal_lines("one\ntwo\nthree");
+        let result = matcher.test(&"one\n2\nthree");
+        result.passed.should_be_false();
+        result.failure_message.should_equal(
+            &"string of 3 line(s) should equal expected string of 3 line(s) line by line\n--- expected\n+++ actual\n@@ -1,3 +1,3 @@\n one\n-two\n+2\n three\n".to_string()
+        );
+    }
+
+    #[test]
+    fn should_not_equal_ignoring_line_endings_given_a_trailing_line_ending() {
+        let matcher = equal_ignoring_line_endings("one\ntwo\n");
+        let result = matcher.test(&"one\r\ntwo");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("ignoring line endings\nthe lines are equal but one string has a trailing line ending");
+    }
+
+    #[test]
+    fn should_have_number_of_lines() {
+        let matcher = have_number_of_lines(2);
+        matcher.test(&"one\ntwo\n").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_have_number_of_lines() {
+        let matcher = have_number_of_lines(3);
+        let result = matcher.test(&"one\ntwo\n");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should have 3 lines but had 2");
+    }
+
+    #[test]
+    fn should_equal_ignoring_whitespace() {
+        let matcher = equal_ignoring_whitespace("fn main() { }");
+        matcher.test(&"  fn  main()\n{\n}\n").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_equal_ignoring_whitespace() {
+        let matcher = equal_ignoring_whitespace("fn main() {}");
+        matcher.test(&"fn main() { }").passed.should_be_false();
+    }
+
+    #[test]
+    fn should_equal_ignoring_line_endings() {
+        let matcher = equal_ignoring_line_endings("one\ntwo\n");
+        matcher.test(&"one\r\ntwo\r\n").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_equal_ignoring_line_endings_given_a_missing_trailing_line_ending() {
+        let matcher = equal_ignoring_line_endings("one\ntwo\n");
+        matcher.test(&"one\r\ntwo").passed.should_be_false();
+    }
+
+    #[test]
+    fn should_have_line() {
+        let matcher = have_line(2, begin_with("version"));
+        matcher.test(&"[package]\nversion = 1").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_have_line_with_the_line_in_the_message() {
+        let matcher = have_line(1, begin_with("version"));
+        let result = matcher.test(&"[package]\nversion = 1");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("line 1 \"[package]\" did not match");
+    }
+
+    #[test]
+    fn should_not_have_line_beyond_the_last_line() {
+        let matcher = have_line(3, begin_with("version"));
+        let result = matcher.test(&"[package]\nversion = 1");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should have line 3 but had 2 lines");
+    }
+
+    #[test]
+    fn should_not_have_line_zero() {
+        let matcher = have_line(0, begin_with("version"));
+        matcher.test(&"version = 1").passed.should_be_false();
+    }
+
+    #[test]
+    fn should_contain_line_matching() {
+        let matcher = contain_line_matching(begin_with("version"));
+        matcher.test(&"[package]\nversion = 1").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_contain_line_matching() {
+        let matcher = contain_line_matching(begin_with("edition"));
+        matcher.test(&"[package]\nversion = 1").passed.should_be_false();
+    }
 }
@@ -0,0 +1,446 @@
+//! provides [StringFormatMatcher] to assert that a string is in a well-known format, like a uuid, an ip address or an email.
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::matchers::{Matcher, MatcherResult};
+
+/// StringFormatMatcher offers a flexible way to assert that a string is in a well-known format.
+///
+/// - Uuid: a hyphenated uuid (8-4-4-4-12 hex digits, in any case), like `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+/// - Ipv4 and Ipv6: an ip address, as parsed by [std::net::Ipv4Addr] and [std::net::Ipv6Addr].
+/// - Semver: a version as defined by [semantic versioning 2.0.0](https://semver.org), like `1.2.3-rc.1+build.5`.
+/// - Base64: standard base64 (with the `+` and `/` alphabet) with `=` padding.
+/// - Email: a pragmatic email address check: a dot-atom local part (without quoting), an `@`
+///   and a domain of at least two dot-separated labels.
+/// - Url and UrlScheme (behind the url feature): an absolute url, as parsed by the [url](https://docs.rs/url) crate,
+///   optionally with the given scheme.
+///
+/// Failure messages name the reason the string is not in the format.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::string::formats::be_uuid;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = be_uuid();
+/// assert!(matcher.test(&"67e55044-10b1-426f-9247-bb680e5fe0c8").passed());
+/// ```
+#[non_exhaustive]
+pub enum StringFormatMatcher {
+    Uuid,
+    Ipv4,
+    Ipv6,
+    Semver,
+    Base64,
+    Email,
+    #[cfg(feature = "url")]
+    Url,
+    #[cfg(feature = "url")]
+    UrlScheme(String),
+}
+
+impl<T> Matcher<T> for StringFormatMatcher
+where T: AsRef<str> + ?Sized
+{
+    fn test(&self, value: &T) -> MatcherResult {
+        let value = value.as_ref();
+        match self {
+            StringFormatMatcher::Uuid => format_result(value, "a valid uuid", validate_uuid(value)),
+            StringFormatMatcher::Ipv4 => format_result(
+                value,
+                "a valid ipv4 address",
+                value.parse::<Ipv4Addr>().map(|_| ()).map_err(|err| format!("failed with: {}", err)),
+            ),
+            StringFormatMatcher::Ipv6 => format_result(
+                value,
+                "a valid ipv6 address",
+                value.parse::<Ipv6Addr>().map(|_| ()).map_err(|err| format!("failed with: {}", err)),
+            ),
+            StringFormatMatcher::Semver => format_result(value, "a valid semver", validate_semver(value)),
+            StringFormatMatcher::Base64 => format_result(value, "valid base64", validate_base64(value)),
+            StringFormatMatcher::Email => format_result(value, "a valid email", validate_email(value)),
+            #[cfg(feature = "url")]
+            StringFormatMatcher::Url => format_result(
+                value,
+                "a valid url",
+                url::Url::parse(value).map(|_| ()).map_err(|err| format!("failed with: {}", err)),
+            ),
+            #[cfg(feature = "url")]
+            StringFormatMatcher::UrlScheme(scheme) => match url::Url::parse(value) {
+                Ok(url) => MatcherResult::formatted(
+                    url.scheme().eq_ignore_ascii_case(scheme),
+                    format!("{:?} should have url scheme {:?} but was {:?}", value, scheme, url.scheme()),
+                    format!("{:?} should not have url scheme {:?}", value, scheme),
+                ),
+                Err(err) => {
+                    let message = format!(
+                        "{:?} should have url scheme {:?} but was not a valid url: {}",
+                        value, scheme, err
+                    );
+                    MatcherResult::formatted(false, message.clone(), message)
+                }
+            },
+        }
+    }
+}
+
+/// Creates a StringFormatMatcher that asserts whether a string is a hyphenated uuid.
+pub fn be_uuid() -> StringFormatMatcher {
+    StringFormatMatcher::Uuid
+}
+
+/// Creates a StringFormatMatcher that asserts whether a string is an ipv4 address.
+pub fn be_ipv4() -> StringFormatMatcher {
+    StringFormatMatcher::Ipv4
+}
+
+/// Creates a StringFormatMatcher that asserts whether a string is an ipv6 address.
+pub fn be_ipv6() -> StringFormatMatcher {
+    StringFormatMatcher::Ipv6
+}
+
+/// Creates a StringFormatMatcher that asserts whether a string is a semantic version.
+pub fn be_semver() -> StringFormatMatcher {
+    StringFormatMatcher::Semver
+}
+
+/// Creates a StringFormatMatcher that asserts whether a string is standard, padded base64.
+pub fn be_base64() -> StringFormatMatcher {
+    StringFormatMatcher::Base64
+}
+
+/// Creates a StringFormatMatcher that asserts whether a string is an email address.
+pub fn be_email() -> StringFormatMatcher {
+    StringFormatMatcher::Email
+}
+
+/// Creates a StringFormatMatcher that asserts whether a string is an absolute url.
+#[cfg(feature = "url")]
+pub fn be_url() -> StringFormatMatcher {
+    StringFormatMatcher::Url
+}
+
+/// Creates a StringFormatMatcher that asserts whether a string is an absolute url with the given scheme (ignoring case).
+#[cfg(feature = "url")]
+pub fn have_url_scheme(scheme: &str) -> StringFormatMatcher {
+    StringFormatMatcher::UrlScheme(scheme.to_string())
+}
+
+fn format_result(value: &str, format: &str, validation: Result<(), String>) -> MatcherResult {
+    match validation {
+        Ok(_) => MatcherResult::formatted(
+            true,
+            format!("{:?} should be {}", value, format),
+            format!("{:?} should not be {}", value, format),
+        ),
+        Err(reason) => MatcherResult::formatted(
+            false,
+            format!("{:?} should be {} but {}", value, format, reason),
+            format!("{:?} should not be {}", value, format),
+        ),
+    }
+}
+
+fn validate_uuid(value: &str) -> Result<(), String> {
+    let length = value.chars().count();
+    if length != 36 {
+        return Err(format!("had {} characters instead of 36", length));
+    }
+    for (index, ch) in value.chars().enumerate() {
+        let hyphen_expected = matches!(index, 8 | 13 | 18 | 23);
+        if hyphen_expected && ch != '-' {
+            return Err(format!("found {:?} at index {} instead of a hyphen", ch, index));
+        }
+        if !hyphen_expected && !ch.is_ascii_hexdigit() {
+            return Err(format!("found {:?} at index {} instead of a hex digit", ch, index));
+        }
+    }
+    Ok(())
+}
+
+fn validate_semver(value: &str) -> Result<(), String> {
+    let (version, build) = match value.split_once('+') {
+        Some((version, build)) => (version, Some(build)),
+        None => (value, None),
+    };
+    let (core, pre_release) = match version.split_once('-') {
+        Some((core, pre_release)) => (core, Some(pre_release)),
+        None => (version, None),
+    };
+
+    let numbers: Vec<&str> = core.split('.').collect();
+    if numbers.len() != 3 {
+        return Err(format!("had {} version numbers instead of major.minor.patch", numbers.len()));
+    }
+    for (number, name) in numbers.iter().zip(["major", "minor", "patch"]) {
+        if !is_numeric_identifier(number) {
+            return Err(format!("had {} version {:?} which is not a number without leading zeros", name, number));
+        }
+    }
+    if let Some(pre_release) = pre_release {
+        for identifier in pre_release.split('.') {
+            if !is_alphanumeric_identifier(identifier) {
+                return Err(format!("had an invalid pre-release identifier {:?}", identifier));
+            }
+            if identifier.chars().all(|ch| ch.is_ascii_digit()) && !is_numeric_identifier(identifier) {
+                return Err(format!("had a numeric pre-release identifier {:?} with a leading zero", identifier));
+            }
+        }
+    }
+    if let Some(build) = build {
+        if let Some(identifier) = build.split('.').find(|identifier| !is_alphanumeric_identifier(identifier)) {
+            return Err(format!("had an invalid build identifier {:?}", identifier));
+        }
+    }
+    Ok(())
+}
+
+fn is_numeric_identifier(identifier: &str) -> bool {
+    !identifier.is_empty()
+        && identifier.chars().all(|ch| ch.is_ascii_digit())
+        && (identifier == "0" || !identifier.starts_with('0'))
+}
+
+fn is_alphanumeric_identifier(identifier: &str) -> bool {
+    !identifier.is_empty() && identifier.chars().all(|ch| ch.is_ascii_alphanumeric() || ch == '-')
+}
+
+// usize::is_multiple_of needs Rust 1.87.
+#[allow(clippy::manual_is_multiple_of)]
+fn validate_base64(value: &str) -> Result<(), String> {
+    if value.len() % 4 != 0 {
+        return Err(format!("had length {} which is not a multiple of 4", value.len()));
+    }
+    let data = value.trim_end_matches('=');
+    let padding = value.len() - data.len();
+    if padding > 2 {
+        return Err(format!("had {} padding characters instead of at most 2", padding));
+    }
+    if let Some((index, ch)) = data
+        .chars()
+        .enumerate()
+        .find(|(_, ch)| !(ch.is_ascii_alphanumeric() || *ch == '+' || *ch == '/'))
+    {
+        return Err(format!("found {:?} at index {}", ch, index));
+    }
+    Ok(())
+}
+
+fn validate_email(value: &str) -> Result<(), String> {
+    let (local, domain) = match value.rsplit_once('@') {
+        Some(parts) => parts,
+        None => return Err("had no '@'".to_string()),
+    };
+    if local.is_empty() || local.len() > 64 {
+        return Err(format!("had a local part of length {} instead of 1 to 64", local.len()));
+    }
+    if let Some(ch) = local
+        .chars()
+        .find(|ch| !(ch.is_ascii_alphanumeric() || "!#$%&'*+/=?^_`{|}~-.".contains(*ch)))
+    {
+        return Err(format!("had {:?} in the local part", ch));
+    }
+    if local.starts_with('.') || local.ends_with('.') || local.contains("..") {
+        return Err(format!("had a misplaced '.' in the local part {:?}", local));
+    }
+
+    let labels: Vec<&str> = domain.split('.').collect();
+    if labels.len() < 2 {
+        return Err(format!("had the domain {:?} without a '.'", domain));
+    }
+    if let Some(label) = labels.iter().find(|label| {
+        label.is_empty()
+            || label.len() > 63
+            || label.starts_with('-')
+            || label.ends_with('-')
+            || !label.chars().all(|ch| ch.is_ascii_alphanumeric() || ch == '-')
+    }) {
+        return Err(format!("had an invalid domain label {:?}", label));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::string::membership::MembershipAssertion;
+    use crate::matchers::Matcher;
+    use crate::matchers::string::formats::{be_base64, be_email, be_ipv4, be_ipv6, be_semver, be_uuid};
+
+    #[test]
+    fn should_be_uuid() {
+        let matcher = be_uuid();
+        matcher.test(&"67e55044-10b1-426f-9247-bb680e5fe0c8").passed.should_be_true();
+        matcher.test(&"67E55044-10B1-426F-9247-BB680E5FE0C8").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_uuid() {
+        let matcher = be_uuid();
+        let result = matcher.test(&"67e55044-10b1-426f-9247-bb680e5fe0cg");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should be a valid uuid but found 'g' at index 35 instead of a hex digit");
+    }
+
+    #[test]
+    fn should_not_be_uuid_given_no_hyphens() {
+        let matcher = be_uuid();
+        let result = matcher.test(&"67e5504410b1426f9247bb680e5fe0c8");
+        result.failure_message.should_contain("had 32 characters instead of 36");
+    }
+
+    #[test]
+    fn should_be_ipv4() {
+        let matcher = be_ipv4();
+        matcher.test(&"192.168.0.1").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_ipv4() {
+        let matcher = be_ipv4();
+        let result = matcher.test(&"300.168.0.1");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should be a valid ipv4 address but failed with: invalid IPv4 address syntax");
+    }
+
+    #[test]
+    fn should_be_ipv6() {
+        let matcher = be_ipv6();
+        matcher.test(&"2001:db8::1").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_ipv6() {
+        let matcher = be_ipv6();
+        matcher.test(&"192.168.0.1").passed.should_be_false();
+    }
+
+    #[test]
+    fn should_be_semver() {
+        let matcher = be_semver();
+        matcher.test(&"1.2.3").passed.should_be_true();
+        matcher.test(&"1.0.0-alpha.1").passed.should_be_true();
+        matcher.test(&"1.0.0-rc.1+build.5").passed.should_be_true();
+        matcher.test(&"0.0.0+20231102").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_semver_given_missing_patch() {
+        let matcher = be_semver();
+        let result = matcher.test(&"1.2");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("had 2 version numbers instead of major.minor.patch");
+    }
+
+    #[test]
+    fn should_not_be_semver_given_leading_zero() {
+        let matcher = be_semver();
+        let result = matcher.test(&"1.02.3");
+        result.failure_message.should_contain("had minor version \"02\" which is not a number without leading zeros");
+    }
+
+    #[test]
+    fn should_not_be_semver_given_leading_zero_in_numeric_pre_release() {
+        let matcher = be_semver();
+        matcher.test(&"1.0.0-alpha.01").passed.should_be_false();
+    }
+
+    #[test]
+    fn should_not_be_semver_given_empty_build() {
+        let matcher = be_semver();
+        matcher.test(&"1.0.0+").passed.should_be_false();
+    }
+
+    #[test]
+    fn should_be_base64() {
+        let matcher = be_base64();
+        matcher.test(&"Y2xlYXJjaGVjaw==").passed.should_be_true();
+        matcher.test(&"YWJj").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_base64_given_invalid_character() {
+        let matcher = be_base64();
+        let result = matcher.test(&"Y2xl_WJj");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should be valid base64 but found '_' at index 4");
+    }
+
+    #[test]
+    fn should_not_be_base64_given_unpadded_value() {
+        let matcher = be_base64();
+        let result = matcher.test(&"Y2xlYXJjaGVjaw");
+        result.failure_message.should_contain("had length 14 which is not a multiple of 4");
+    }
+
+    #[test]
+    fn should_be_email() {
+        let matcher = be_email();
+        matcher.test(&"john.doe+tests@mail.example.com").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_email_given_no_at() {
+        let matcher = be_email();
+        let result = matcher.test(&"john.example.com");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should be a valid email but had no '@'");
+    }
+
+    #[test]
+    fn should_not_be_email_given_domain_without_dot() {
+        let matcher = be_email();
+        let result = matcher.test(&"john@localhost");
+        result.failure_message.should_contain("had the domain \"localhost\" without a '.'");
+    }
+
+    #[test]
+    fn should_not_be_email_given_consecutive_dots() {
+        let matcher = be_email();
+        matcher.test(&"john..doe@example.com").passed.should_be_false();
+    }
+}
+
+#[cfg(all(test, feature = "url"))]
+mod url_tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::string::membership::MembershipAssertion;
+    use crate::matchers::Matcher;
+    use crate::matchers::string::formats::{be_url, have_url_scheme};
+
+    #[test]
+    fn should_be_url() {
+        let matcher = be_url();
+        matcher.test(&"https://docs.rs/clearcheck?search=x#top").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_url() {
+        let matcher = be_url();
+        let result = matcher.test(&"docs.rs/clearcheck");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should be a valid url but failed with: relative URL without a base");
+    }
+
+    #[test]
+    fn should_have_url_scheme() {
+        let matcher = have_url_scheme("https");
+        matcher.test(&"https://docs.rs").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_have_url_scheme() {
+        let matcher = have_url_scheme("https");
+        let result = matcher.test(&"http://docs.rs");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should have url scheme \"https\" but was \"http\"");
+    }
+
+    #[test]
+    fn should_not_have_url_scheme_given_invalid_url() {
+        let matcher = have_url_scheme("https");
+        let result = matcher.test(&"docs.rs");
+        result.passed.should_be_false();
+        result.inverted_failure_message.should_contain("but was not a valid url");
+    }
+}
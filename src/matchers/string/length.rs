@@ -1,3 +1,10 @@
+use std::ops::{Range, RangeInclusive};
+
+#[cfg(feature = "unicode")]
+use unicode_segmentation::UnicodeSegmentation;
+#[cfg(feature = "unicode")]
+use unicode_width::UnicodeWidthStr;
+
 use crate::matchers::{Matcher, MatcherResult};
 
 /// StringLengthMatcher offers a flexible way to assert various length properties of string.
@@ -17,47 +24,158 @@ pub enum StringLengthMatcher {
 }
 
 impl<T> Matcher<T> for StringLengthMatcher
-    where T: AsRef<str>
+    where T: AsRef<str> + ?Sized
 {
     fn test(&self, value: &T) -> MatcherResult {
         match self {
             StringLengthMatcher::Same(input_length) => MatcherResult::formatted(
                 value.as_ref().len() == *input_length,
                 format!(
-                    "{:?} length {:?} should be {:?}",
-                    value.as_ref(), input_length, input_length,
+                    "{:?} length should be {:?} but was {:?}",
+                    value.as_ref(), input_length, value.as_ref().len(),
                 ),
                 format!(
-                    "{:?} length {:?} should not be {:?}",
-                    value.as_ref(), input_length, input_length,
+                    "{:?} length should not be {:?} but was {:?}",
+                    value.as_ref(), input_length, value.as_ref().len(),
                 ),
             ),
             StringLengthMatcher::Atleast(input_length) => MatcherResult::formatted(
                 value.as_ref().len() >= *input_length,
                 format!(
-                    "{:?} length {:?} should be atleast {:?}",
-                    value.as_ref(), input_length, input_length,
+                    "{:?} length should be atleast {:?} but was {:?}",
+                    value.as_ref(), input_length, value.as_ref().len(),
                 ),
                 format!(
-                    "{:?} length {:?} should not be atleast {:?}",
-                    value.as_ref(), input_length, input_length,
+                    "{:?} length should not be atleast {:?} but was {:?}",
+                    value.as_ref(), input_length, value.as_ref().len(),
                 ),
             ),
             StringLengthMatcher::Atmost(input_length) => MatcherResult::formatted(
                 value.as_ref().len() <= *input_length,
                 format!(
-                    "{:?} length {:?} should be atmost {:?}",
-                    value.as_ref(), input_length, input_length,
+                    "{:?} length should be atmost {:?} but was {:?}",
+                    value.as_ref(), input_length, value.as_ref().len(),
                 ),
                 format!(
-                    "{:?} length {:?} should not be atmost {:?}",
-                    value.as_ref(), input_length, input_length,
+                    "{:?} length should not be atmost {:?} but was {:?}",
+                    value.as_ref(), input_length, value.as_ref().len(),
                 ),
             ),
         }
     }
 }
 
+/// LengthUnit defines how the length of a string is measured.
+///
+/// - Bytes: the number of UTF-8 bytes, same as `str::len`.
+/// - Chars: the number of Unicode scalar values, same as `str::chars().count()`.
+/// - Graphemes: the number of extended grapheme clusters, that is, user-perceived characters (requires the `unicode` feature).
+/// - DisplayWidth: the number of columns the string occupies in a terminal (requires the `unicode` feature).
+///
+/// LengthUnit is non-exhaustive since the available units depend on the enabled features.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LengthUnit {
+    Bytes,
+    Chars,
+    #[cfg(feature = "unicode")]
+    Graphemes,
+    #[cfg(feature = "unicode")]
+    DisplayWidth,
+}
+
+impl LengthUnit {
+    fn measure(&self, value: &str) -> usize {
+        match self {
+            LengthUnit::Bytes => value.len(),
+            LengthUnit::Chars => value.chars().count(),
+            #[cfg(feature = "unicode")]
+            LengthUnit::Graphemes => value.graphemes(true).count(),
+            #[cfg(feature = "unicode")]
+            LengthUnit::DisplayWidth => value.width(),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            LengthUnit::Bytes => "byte count",
+            LengthUnit::Chars => "char count",
+            #[cfg(feature = "unicode")]
+            LengthUnit::Graphemes => "grapheme count",
+            #[cfg(feature = "unicode")]
+            LengthUnit::DisplayWidth => "display width",
+        }
+    }
+}
+
+/// UnitLengthMatcher offers a flexible way to assert the length of string measured in the given [`LengthUnit`].
+///
+/// # Example
+///```
+/// use clearcheck::matchers::string::length::have_char_count;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = have_char_count(5);
+/// assert!(matcher.test(&"héllo").passed());
+/// ```
+pub enum UnitLengthMatcher {
+    Same(LengthUnit, usize),
+    Atleast(LengthUnit, usize),
+    Atmost(LengthUnit, usize),
+    InInclusiveRange(LengthUnit, RangeInclusive<usize>),
+    InExclusiveRange(LengthUnit, Range<usize>),
+}
+
+impl<T> Matcher<T> for UnitLengthMatcher
+    where T: AsRef<str> + ?Sized
+{
+    fn test(&self, value: &T) -> MatcherResult {
+        let value = value.as_ref();
+        match self {
+            UnitLengthMatcher::Same(unit, length) => {
+                let actual = unit.measure(value);
+                MatcherResult::formatted(
+                    actual == *length,
+                    format!("{:?} {} should be {} but was {}", value, unit.name(), length, actual),
+                    format!("{:?} {} should not be {} but was {}", value, unit.name(), length, actual),
+                )
+            }
+            UnitLengthMatcher::Atleast(unit, length) => {
+                let actual = unit.measure(value);
+                MatcherResult::formatted(
+                    actual >= *length,
+                    format!("{:?} {} should be at least {} but was {}", value, unit.name(), length, actual),
+                    format!("{:?} {} should not be at least {} but was {}", value, unit.name(), length, actual),
+                )
+            }
+            UnitLengthMatcher::Atmost(unit, length) => {
+                let actual = unit.measure(value);
+                MatcherResult::formatted(
+                    actual <= *length,
+                    format!("{:?} {} should be at most {} but was {}", value, unit.name(), length, actual),
+                    format!("{:?} {} should not be at most {} but was {}", value, unit.name(), length, actual),
+                )
+            }
+            UnitLengthMatcher::InInclusiveRange(unit, range) => {
+                let actual = unit.measure(value);
+                MatcherResult::formatted(
+                    range.contains(&actual),
+                    format!("{:?} {} should be in the range {:?} but was {}", value, unit.name(), range, actual),
+                    format!("{:?} {} should not be in the range {:?} but was {}", value, unit.name(), range, actual),
+                )
+            }
+            UnitLengthMatcher::InExclusiveRange(unit, range) => {
+                let actual = unit.measure(value);
+                MatcherResult::formatted(
+                    range.contains(&actual),
+                    format!("{:?} {} should be in the range {:?} but was {}", value, unit.name(), range, actual),
+                    format!("{:?} {} should not be in the range {:?} but was {}", value, unit.name(), range, actual),
+                )
+            }
+        }
+    }
+}
+
 /// Creates a StringLengthMatcher that asserts whether the length of a string is same as the given length.
 pub fn have_same_length(length: usize) -> StringLengthMatcher {
     StringLengthMatcher::Same(length)
@@ -73,6 +191,48 @@ pub fn have_atmost_same_length(length: usize) -> StringLengthMatcher {
     StringLengthMatcher::Atmost(length)
 }
 
+/// Creates a UnitLengthMatcher that asserts whether the length of a string, measured in the given unit, is same as the given length.
+pub fn have_unit_length(unit: LengthUnit, length: usize) -> UnitLengthMatcher {
+    UnitLengthMatcher::Same(unit, length)
+}
+
+/// Creates a UnitLengthMatcher that asserts whether the length of a string, measured in the given unit, is greater than or equal to the given length.
+pub fn have_atleast_unit_length(unit: LengthUnit, length: usize) -> UnitLengthMatcher {
+    UnitLengthMatcher::Atleast(unit, length)
+}
+
+/// Creates a UnitLengthMatcher that asserts whether the length of a string, measured in the given unit, is less than or equal to the given length.
+pub fn have_atmost_unit_length(unit: LengthUnit, length: usize) -> UnitLengthMatcher {
+    UnitLengthMatcher::Atmost(unit, length)
+}
+
+/// Creates a UnitLengthMatcher that asserts whether the length of a string, measured in the given unit, falls within the given inclusive range.
+pub fn have_unit_length_in_inclusive_range(unit: LengthUnit, range: RangeInclusive<usize>) -> UnitLengthMatcher {
+    UnitLengthMatcher::InInclusiveRange(unit, range)
+}
+
+/// Creates a UnitLengthMatcher that asserts whether the length of a string, measured in the given unit, falls within the given exclusive range.
+pub fn have_unit_length_in_exclusive_range(unit: LengthUnit, range: Range<usize>) -> UnitLengthMatcher {
+    UnitLengthMatcher::InExclusiveRange(unit, range)
+}
+
+/// Creates a UnitLengthMatcher that asserts whether the number of Unicode scalar values (chars) in a string is the given count.
+pub fn have_char_count(count: usize) -> UnitLengthMatcher {
+    have_unit_length(LengthUnit::Chars, count)
+}
+
+/// Creates a UnitLengthMatcher that asserts whether the number of extended grapheme clusters in a string is the given count.
+#[cfg(feature = "unicode")]
+pub fn have_grapheme_count(count: usize) -> UnitLengthMatcher {
+    have_unit_length(LengthUnit::Graphemes, count)
+}
+
+/// Creates a UnitLengthMatcher that asserts whether the terminal display width of a string is the given width.
+#[cfg(feature = "unicode")]
+pub fn have_display_width(width: usize) -> UnitLengthMatcher {
+    have_unit_length(LengthUnit::DisplayWidth, width)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::assertions::bool::TrueFalseAssertion;
@@ -118,3 +278,115 @@ mod tests {
         matcher.test(&"junit").passed.should_be_true();
     }
 }
+
+#[cfg(test)]
+mod unit_length_tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::string::membership::MembershipAssertion;
+    use crate::matchers::Matcher;
+    use crate::matchers::string::length::{
+        have_atleast_unit_length, have_atmost_unit_length, have_char_count, have_same_length,
+        have_unit_length, have_unit_length_in_exclusive_range, have_unit_length_in_inclusive_range,
+        LengthUnit,
+    };
+
+    #[test]
+    fn should_state_the_actual_byte_length() {
+        let matcher = have_same_length(5);
+        let result = matcher.test(&"héllo");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("length should be 5 but was 6");
+    }
+
+    #[test]
+    fn should_have_byte_count() {
+        let matcher = have_unit_length(LengthUnit::Bytes, 6);
+        matcher.test(&"héllo").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_char_count() {
+        let matcher = have_char_count(5);
+        matcher.test(&"héllo").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_have_char_count() {
+        let matcher = have_char_count(6);
+        let result = matcher.test(&"héllo");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("char count should be 6 but was 5");
+    }
+
+    #[test]
+    fn should_have_atleast_char_count() {
+        let matcher = have_atleast_unit_length(LengthUnit::Chars, 5);
+        matcher.test(&"héllo").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_atmost_char_count() {
+        let matcher = have_atmost_unit_length(LengthUnit::Chars, 5);
+        matcher.test(&"héllo").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_char_count_in_inclusive_range() {
+        let matcher = have_unit_length_in_inclusive_range(LengthUnit::Chars, 1..=5);
+        matcher.test(&"héllo").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_have_char_count_in_exclusive_range() {
+        let matcher = have_unit_length_in_exclusive_range(LengthUnit::Chars, 1..5);
+        let result = matcher.test(&"héllo");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("char count should be in the range 1..5 but was 5");
+    }
+}
+
+#[cfg(all(test, feature = "unicode"))]
+mod unicode_length_tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::string::membership::MembershipAssertion;
+    use crate::matchers::Matcher;
+    use crate::matchers::string::length::{
+        have_atmost_unit_length, have_char_count, have_display_width, have_grapheme_count,
+        LengthUnit,
+    };
+
+    #[test]
+    fn should_have_grapheme_count_for_combining_characters() {
+        let value = "he\u{301}llo";
+        have_char_count(6).test(value).passed.should_be_true();
+        have_grapheme_count(5).test(value).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_grapheme_count_for_emoji_sequence() {
+        let matcher = have_grapheme_count(1);
+        matcher.test(&"👨‍👩‍👧").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_have_grapheme_count() {
+        let matcher = have_grapheme_count(2);
+        let result = matcher.test(&"👨‍👩‍👧");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("grapheme count should be 2 but was 1");
+    }
+
+    #[test]
+    fn should_have_display_width_for_wide_characters() {
+        let matcher = have_display_width(4);
+        matcher.test(&"日本").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_have_atmost_display_width() {
+        let matcher = have_atmost_unit_length(LengthUnit::DisplayWidth, 3);
+        let result = matcher.test(&"日本");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("display width should be at most 3 but was 4");
+    }
+}
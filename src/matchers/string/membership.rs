@@ -1,7 +1,15 @@
 use crate::matchers::{Matcher, MatcherResult};
+use crate::matchers::string::similarity::{closest_substring, closest_substring_ignoring_case};
 
 /// MembershipMatcher offers a flexible way to assert the presence or absence of characters in a string.
 ///
+/// - Alphabetic, Alphanumeric, Ascii, AsciiPrintable, Hex and Blank: every character of the string belongs to the character class
+///   (an empty string belongs to all of them). AsciiPrintable covers ' ' through '~', Hex covers ASCII hex digits
+///   and Blank covers whitespace.
+/// - Whitespace and ControlChars: the string contains a character of the class.
+///
+/// Failure messages of the character-class variants name the first offending character and its char index.
+///
 /// # Example
 ///```
 /// use clearcheck::matchers::string::membership::contain_all_characters;
@@ -17,9 +25,46 @@ pub enum MembershipMatcher {
     Char(char),
     AllChars(Vec<char>),
     AnyChars(Vec<char>),
+    Alphabetic,
+    Alphanumeric,
+    Ascii,
+    AsciiPrintable,
+    Hex,
+    Blank,
+    Whitespace,
+    ControlChars,
+}
+
+/// CharPredicateMatcher offers a flexible way to assert that every character in a string satisfies the given predicate.
+///
+/// The failure message names the first character that does not satisfy the predicate, and its char index.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::string::membership::only_contain_chars_matching;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = only_contain_chars_matching(|ch| ch.is_ascii_lowercase() || ch == '-');
+/// assert!(matcher.test(&"clear-check").passed());
+/// ```
+pub struct CharPredicateMatcher<F>
+    where F: Fn(char) -> bool
+{
+    predicate: F,
 }
 
-/// SubstringMatcher offers a flexible way to assert whether a string contains a substring.
+/// SubstringMatcher offers a flexible way to assert whether a string contains a substring, how many times, where, and in which order.
+///
+/// - Substr: the string contains the substring.
+/// - SubstrIgnoringCase: the string contains the substring, ignoring case differences.
+/// - Times: the string contains the given number of non-overlapping occurrences of the substring.
+/// - AtIndex: the substring occurs at the given char index of the string.
+/// - InOrder: the substrings occur one after the other (without overlapping), in the given order.
+/// - AllOf: the string contains all the substrings.
+/// - AnyOf: the string contains any of the substrings.
+///
+/// When the substring is not found, the failure message points at the closest matching substring and its char offset.
+/// Other failure messages report the char indices where the substrings were found.
 ///
 /// # Example
 ///```
@@ -30,12 +75,17 @@ pub enum MembershipMatcher {
 /// assert!(matcher.test(&"clearcheck").passed());
 /// ```
 pub enum SubstringMatcher {
-    Substr(&'static str),
-    SubstrIgnoringCase(&'static str),
+    Substr(String),
+    SubstrIgnoringCase(String),
+    Times(String, usize),
+    AtIndex(String, usize),
+    InOrder(Vec<String>),
+    AllOf(Vec<String>),
+    AnyOf(Vec<String>),
 }
 
 impl<T> Matcher<T> for MembershipMatcher
-    where T: AsRef<str>
+    where T: AsRef<str> + ?Sized
 {
     fn test(&self, value: &T) -> MatcherResult {
         match self {
@@ -69,35 +119,235 @@ impl<T> Matcher<T> for MembershipMatcher
                 format!("{:?} should contain any of the characters {:?}", value.as_ref(), chars),
                 format!("{:?} should not contain any of the characters {:?}", value.as_ref(), chars),
             ),
+            MembershipMatcher::Alphabetic => only(value.as_ref(), "be alphabetic", |ch| ch.is_alphabetic()),
+            MembershipMatcher::Alphanumeric => only(value.as_ref(), "be alphanumeric", |ch| ch.is_alphanumeric()),
+            MembershipMatcher::Ascii => only(value.as_ref(), "be ascii", |ch| ch.is_ascii()),
+            MembershipMatcher::AsciiPrintable => {
+                only(value.as_ref(), "be ascii printable", |ch| ch.is_ascii_graphic() || ch == ' ')
+            }
+            MembershipMatcher::Hex => only(value.as_ref(), "be hex", |ch| ch.is_ascii_hexdigit()),
+            MembershipMatcher::Blank => only(value.as_ref(), "be blank", |ch| ch.is_whitespace()),
+            MembershipMatcher::Whitespace => any(value.as_ref(), "contain whitespace", |ch| ch.is_whitespace()),
+            MembershipMatcher::ControlChars => {
+                any(value.as_ref(), "contain control characters", |ch| ch.is_control())
+            }
         }
     }
 }
 
+impl<T, F> Matcher<T> for CharPredicateMatcher<F>
+    where T: AsRef<str> + ?Sized,
+          F: Fn(char) -> bool
+{
+    fn test(&self, value: &T) -> MatcherResult {
+        only(value.as_ref(), "only contain characters matching the given predicate", &self.predicate)
+    }
+}
+
+fn only<P: Fn(char) -> bool>(value: &str, expectation: &str, predicate: P) -> MatcherResult {
+    let offending = value.chars().enumerate().find(|(_, ch)| !predicate(*ch));
+    let found = offending
+        .map(|(index, ch)| format!(" but found {:?} at index {}", ch, index))
+        .unwrap_or_default();
+    MatcherResult::formatted(
+        offending.is_none(),
+        format!("{:?} should {}{}", value, expectation, found),
+        format!("{:?} should not {}", value, expectation),
+    )
+}
+
+fn any<P: Fn(char) -> bool>(value: &str, expectation: &str, predicate: P) -> MatcherResult {
+    let matching = value.chars().enumerate().find(|(_, ch)| predicate(*ch));
+    let found = matching
+        .map(|(index, ch)| format!(" but found {:?} at index {}", ch, index))
+        .unwrap_or_default();
+    MatcherResult::formatted(
+        matching.is_some(),
+        format!("{:?} should {}", value, expectation),
+        format!("{:?} should not {}{}", value, expectation, found),
+    )
+}
+
 impl<T> Matcher<T> for SubstringMatcher
-    where T: AsRef<str>
+    where T: AsRef<str> + ?Sized
 {
     fn test(&self, value: &T) -> MatcherResult {
         match self {
-            SubstringMatcher::Substr(substr) => MatcherResult::formatted(
-                value.as_ref().contains(substr),
-                format!("{:?} should contain the substring {:?}", value.as_ref(), substr),
-                format!("{:?} should not contain the substring {:?}", value.as_ref(), substr),
-            ),
-            SubstringMatcher::SubstrIgnoringCase(substr) => MatcherResult::formatted(
-                value.as_ref().to_lowercase().contains(&substr.to_lowercase()),
-                format!(
-                    "{:?} should contain the substring ignoring case {:?}",
-                    value.as_ref(), substr
-                ),
-                format!(
-                    "{:?} should not contain the substring ignoring case {:?}",
-                    value.as_ref(), substr
-                ),
-            )
+            SubstringMatcher::Substr(substr) => {
+                let passed = value.as_ref().contains(substr);
+                MatcherResult::formatted(
+                    passed,
+                    format!(
+                        "{:?} should contain the substring {:?}{}",
+                        value.as_ref(), substr, closest_match_hint(passed, value.as_ref(), substr)
+                    ),
+                    format!("{:?} should not contain the substring {:?}", value.as_ref(), substr),
+                )
+            }
+            SubstringMatcher::SubstrIgnoringCase(substr) => {
+                let value_lowercase = value.as_ref().to_lowercase();
+                let substr_lowercase = substr.to_lowercase();
+                let passed = value_lowercase.contains(&substr_lowercase);
+                MatcherResult::formatted(
+                    passed,
+                    format!(
+                        "{:?} should contain the substring ignoring case {:?}{}",
+                        value.as_ref(),
+                        substr,
+                        closest_match_hint_ignoring_case(passed, value.as_ref(), substr)
+                    ),
+                    format!(
+                        "{:?} should not contain the substring ignoring case {:?}",
+                        value.as_ref(), substr
+                    ),
+                )
+            }
+            SubstringMatcher::Times(substr, times) => {
+                let positions = positions_of(value.as_ref(), substr);
+                MatcherResult::formatted(
+                    positions.len() == *times,
+                    format!(
+                        "{:?} should contain the substring {:?} {} time(s) but found it {} time(s) at {:?}",
+                        value.as_ref(), substr, times, positions.len(), positions
+                    ),
+                    format!(
+                        "{:?} should not contain the substring {:?} {} time(s)",
+                        value.as_ref(), substr, times
+                    ),
+                )
+            }
+            SubstringMatcher::AtIndex(substr, index) => {
+                let positions = positions_of(value.as_ref(), substr);
+                MatcherResult::formatted(
+                    byte_index(value.as_ref(), *index)
+                        .is_some_and(|byte_index| value.as_ref()[byte_index..].starts_with(substr.as_str())),
+                    format!(
+                        "{:?} should contain the substring {:?} at index {} but found it at {:?}",
+                        value.as_ref(), substr, index, positions
+                    ),
+                    format!(
+                        "{:?} should not contain the substring {:?} at index {}",
+                        value.as_ref(), substr, index
+                    ),
+                )
+            }
+            SubstringMatcher::InOrder(substrs) => {
+                let mut found = Vec::new();
+                let mut from = 0;
+                let mut all_found = true;
+                for substr in substrs {
+                    match value.as_ref()[from..].find(substr.as_str()) {
+                        Some(position) => {
+                            found.push(format!("{:?} at {}", substr, char_index(value.as_ref(), from + position)));
+                            from += position + substr.len();
+                        }
+                        None => {
+                            found.push(format!("{:?} not found after {}", substr, char_index(value.as_ref(), from)));
+                            all_found = false;
+                            break;
+                        }
+                    }
+                }
+                MatcherResult::formatted(
+                    all_found,
+                    format!(
+                        "{:?} should contain the substrings {:?} in order but found {}",
+                        value.as_ref(), substrs, found.join(", ")
+                    ),
+                    format!(
+                        "{:?} should not contain the substrings {:?} in order but found {}",
+                        value.as_ref(), substrs, found.join(", ")
+                    ),
+                )
+            }
+            SubstringMatcher::AllOf(substrs) => {
+                let missing: Vec<&String> = substrs
+                    .iter()
+                    .filter(|substr| !value.as_ref().contains(substr.as_str()))
+                    .collect();
+                MatcherResult::formatted(
+                    missing.is_empty(),
+                    format!(
+                        "{:?} should contain all of the substrings {:?} but was missing {:?}",
+                        value.as_ref(), substrs, missing
+                    ),
+                    format!(
+                        "{:?} should not contain all of the substrings {:?}",
+                        value.as_ref(), substrs
+                    ),
+                )
+            }
+            SubstringMatcher::AnyOf(substrs) => {
+                let found: Vec<String> = substrs
+                    .iter()
+                    .filter_map(|substr| {
+                        value.as_ref().find(substr.as_str()).map(|position| {
+                            format!("{:?} at {}", substr, char_index(value.as_ref(), position))
+                        })
+                    })
+                    .collect();
+                MatcherResult::formatted(
+                    !found.is_empty(),
+                    format!(
+                        "{:?} should contain any of the substrings {:?}",
+                        value.as_ref(), substrs
+                    ),
+                    format!(
+                        "{:?} should not contain any of the substrings {:?} but found {}",
+                        value.as_ref(), substrs, found.join(", ")
+                    ),
+                )
+            }
         }
     }
 }
 
+/// Returns the char indices of the non-overlapping occurrences of the substring in the source.
+fn positions_of(source: &str, substr: &str) -> Vec<usize> {
+    if substr.is_empty() {
+        return Vec::new();
+    }
+    let (mut last_byte_index, mut last_char_index) = (0, 0);
+    source
+        .match_indices(substr)
+        .map(|(position, _)| {
+            last_char_index += source[last_byte_index..position].chars().count();
+            last_byte_index = position;
+            last_char_index
+        })
+        .collect()
+}
+
+/// Returns the char index of the given byte index of the source.
+fn char_index(source: &str, byte_index: usize) -> usize {
+    source[..byte_index].chars().count()
+}
+
+/// Returns the byte index of the given char index of the source, where the char count is the index of the end of the source.
+fn byte_index(source: &str, char_index: usize) -> Option<usize> {
+    source.char_indices().map(|(byte_index, _)| byte_index).chain(std::iter::once(source.len())).nth(char_index)
+}
+
+fn closest_match_hint(passed: bool, source: &str, substr: &str) -> String {
+    if passed {
+        return String::new();
+    }
+    format_closest_match(closest_substring(source, substr))
+}
+
+fn closest_match_hint_ignoring_case(passed: bool, source: &str, substr: &str) -> String {
+    if passed {
+        return String::new();
+    }
+    format_closest_match(closest_substring_ignoring_case(source, substr))
+}
+
+fn format_closest_match(closest: Option<(String, usize)>) -> String {
+    match closest {
+        Some((closest, offset)) => format!(", closest match was {:?} at offset {}", closest, offset),
+        None => String::new(),
+    }
+}
 
 /// Creates a MembershipMatcher that asserts whether a string contains only digits.
 pub fn contain_only_digits() -> MembershipMatcher {
@@ -129,21 +379,106 @@ pub fn contain_any_of_characters(chars: Vec<char>) -> MembershipMatcher {
     MembershipMatcher::AnyChars(chars)
 }
 
+/// Creates a MembershipMatcher that asserts whether a string contains only alphabetic characters.
+pub fn be_alphabetic() -> MembershipMatcher {
+    MembershipMatcher::Alphabetic
+}
+
+/// Creates a MembershipMatcher that asserts whether a string contains only alphabetic or numeric characters.
+pub fn be_alphanumeric() -> MembershipMatcher {
+    MembershipMatcher::Alphanumeric
+}
+
+/// Creates a MembershipMatcher that asserts whether a string contains only ASCII characters.
+pub fn be_ascii() -> MembershipMatcher {
+    MembershipMatcher::Ascii
+}
+
+/// Creates a MembershipMatcher that asserts whether a string contains only printable ASCII characters (' ' through '~').
+pub fn be_ascii_printable() -> MembershipMatcher {
+    MembershipMatcher::AsciiPrintable
+}
+
+/// Creates a MembershipMatcher that asserts whether a string contains only ASCII hex digits (case-insensitive).
+pub fn be_hex() -> MembershipMatcher {
+    MembershipMatcher::Hex
+}
+
+/// Creates a MembershipMatcher that asserts whether a string is empty or contains only whitespace.
+pub fn be_blank() -> MembershipMatcher {
+    MembershipMatcher::Blank
+}
+
+/// Creates a MembershipMatcher that asserts whether a string contains a whitespace character.
+pub fn contain_whitespace() -> MembershipMatcher {
+    MembershipMatcher::Whitespace
+}
+
+/// Creates a MembershipMatcher that asserts whether a string contains a control character.
+pub fn contain_control_characters() -> MembershipMatcher {
+    MembershipMatcher::ControlChars
+}
+
+/// Creates a CharPredicateMatcher that asserts whether every character in a string satisfies the given predicate.
+pub fn only_contain_chars_matching<F>(predicate: F) -> CharPredicateMatcher<F>
+    where F: Fn(char) -> bool
+{
+    CharPredicateMatcher { predicate }
+}
+
 /// Creates a SubstringMatcher that asserts whether a string contains the given substring.
-pub fn contain(substr: &'static str) -> SubstringMatcher {
-    SubstringMatcher::Substr(substr)
+pub fn contain<S: Into<String>>(substr: S) -> SubstringMatcher {
+    SubstringMatcher::Substr(substr.into())
 }
 
 /// Creates a SubstringMatcher that asserts whether a string contains the given substring, ignoring the case differences.
-pub fn contain_ignoring_case(substr: &'static str) -> SubstringMatcher {
-    SubstringMatcher::SubstrIgnoringCase(substr)
+pub fn contain_ignoring_case<S: Into<String>>(substr: S) -> SubstringMatcher {
+    SubstringMatcher::SubstrIgnoringCase(substr.into())
+}
+
+/// Creates a SubstringMatcher that asserts whether a string contains the given number of non-overlapping occurrences of the substring.
+pub fn contain_times<S: Into<String>>(substr: S, times: usize) -> SubstringMatcher {
+    SubstringMatcher::Times(substr.into(), times)
+}
+
+/// Creates a SubstringMatcher that asserts whether a string contains exactly one occurrence of the substring.
+pub fn contain_only_once<S: Into<String>>(substr: S) -> SubstringMatcher {
+    SubstringMatcher::Times(substr.into(), 1)
+}
+
+/// Creates a SubstringMatcher that asserts whether the substring occurs at the given char index of a string.
+pub fn contain_at_index<S: Into<String>>(substr: S, index: usize) -> SubstringMatcher {
+    SubstringMatcher::AtIndex(substr.into(), index)
+}
+
+/// Creates a SubstringMatcher that asserts whether a string contains the given substrings one after the other, in the given order.
+pub fn contain_in_order<I, S>(substrs: I) -> SubstringMatcher
+    where I: IntoIterator<Item = S>, S: Into<String>
+{
+    SubstringMatcher::InOrder(substrs.into_iter().map(Into::into).collect())
+}
+
+/// Creates a SubstringMatcher that asserts whether a string contains all the given substrings.
+pub fn contain_all_of<I, S>(substrs: I) -> SubstringMatcher
+    where I: IntoIterator<Item = S>, S: Into<String>
+{
+    SubstringMatcher::AllOf(substrs.into_iter().map(Into::into).collect())
+}
+
+/// Creates a SubstringMatcher that asserts whether a string contains any of the given substrings.
+pub fn contain_any_of<I, S>(substrs: I) -> SubstringMatcher
+    where I: IntoIterator<Item = S>, S: Into<String>
+{
+    SubstringMatcher::AnyOf(substrs.into_iter().map(Into::into).collect())
 }
 
 #[cfg(test)]
 mod tests {
     use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::string::membership::MembershipAssertion;
     use crate::matchers::Matcher;
-    use crate::matchers::string::membership::{contain, contain_all_characters, contain_any_of_characters, contain_character, contain_ignoring_case, contain_only_digits, not_contain_digits};
+    use crate::matchers::string::membership::{be_alphabetic, be_alphanumeric, be_ascii, be_ascii_printable, be_blank, be_hex, contain_control_characters, contain_whitespace, only_contain_chars_matching};
+    use crate::matchers::string::membership::{contain, contain_all_characters, contain_all_of, contain_any_of, contain_any_of_characters, contain_at_index, contain_character, contain_ignoring_case, contain_in_order, contain_only_digits, contain_only_once, contain_times, not_contain_digits};
 
     #[test]
     fn should_contains_only_digits() {
@@ -248,4 +583,257 @@ mod tests {
         let matcher = contain_ignoring_case("ETCD");
         matcher.test(&"goselect").passed.should_be_true();
     }
+
+    #[test]
+    fn should_point_at_the_closest_substring_given_the_substring_is_missing() {
+        let matcher = contain("selcet");
+        let result = matcher.test(&"goselect");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("closest match was \"select\" at offset 2");
+    }
+
+    #[test]
+    fn should_point_at_the_closest_substring_ignoring_case_given_the_substring_is_missing() {
+        let matcher = contain_ignoring_case("SELCET");
+        let result = matcher.test(&"GoSelect");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("closest match was \"Select\" at offset 2");
+    }
+
+    #[test]
+    fn should_contain_substring_given_an_owned_string() {
+        let substr = String::from("select");
+        let matcher = contain(&substr);
+        matcher.test(&"goselect").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_contain_substring_times() {
+        let matcher = contain_times("ab", 2);
+        matcher.test(&"abcab").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_contain_substring_times() {
+        let matcher = contain_times("ab", 2);
+        let result = matcher.test(&"abcabab");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should contain the substring \"ab\" 2 time(s) but found it 3 time(s) at [0, 3, 5]");
+    }
+
+    #[test]
+    fn should_count_non_overlapping_occurrences() {
+        let matcher = contain_times("aa", 2);
+        matcher.test(&"aaaa").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_contain_substring_only_once() {
+        let matcher = contain_only_once(String::from("END"));
+        matcher.test(&"BEGIN body END").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_contain_substring_only_once() {
+        let matcher = contain_only_once("END");
+        matcher.test(&"END body END").passed.should_be_false();
+    }
+
+    #[test]
+    fn should_contain_substring_at_index() {
+        let matcher = contain_at_index("body", 6);
+        matcher.test(&"BEGIN body END").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_contain_substring_at_index() {
+        let matcher = contain_at_index("body", 5);
+        let result = matcher.test(&"BEGIN body END");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should contain the substring \"body\" at index 5 but found it at [6]");
+    }
+
+    #[test]
+    fn should_contain_substring_at_char_index() {
+        let matcher = contain_at_index("body", 6);
+        matcher.test(&"héllo body").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_report_char_indices_of_substring_occurrences() {
+        let matcher = contain_times("body", 1);
+        let result = matcher.test(&"héllo body, body");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("found it 2 time(s) at [6, 12]");
+    }
+
+    #[test]
+    fn should_not_contain_substring_at_an_index_past_the_end() {
+        let matcher = contain_at_index("body", 50);
+        matcher.test(&"BEGIN body END").passed.should_be_false();
+    }
+
+    #[test]
+    fn should_contain_substrings_in_order() {
+        let matcher = contain_in_order(["BEGIN", "body", "END"]);
+        matcher.test(&"BEGIN body END").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_contain_substrings_in_order() {
+        let matcher = contain_in_order(vec![String::from("BEGIN"), String::from("END"), String::from("body")]);
+        let result = matcher.test(&"BEGIN body END");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("but found \"BEGIN\" at 0, \"END\" at 11, \"body\" not found after 14");
+    }
+
+    #[test]
+    fn should_contain_all_of_substrings() {
+        let matcher = contain_all_of(["BEGIN", "END"]);
+        matcher.test(&"BEGIN body END").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_contain_all_of_substrings() {
+        let matcher = contain_all_of(["BEGIN", "COMMIT", "END", "ROLLBACK"]);
+        let result = matcher.test(&"BEGIN body END");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("but was missing [\"COMMIT\", \"ROLLBACK\"]");
+    }
+
+    #[test]
+    fn should_contain_any_of_substrings() {
+        let matcher = contain_any_of(["COMMIT", "END"]);
+        matcher.test(&"BEGIN body END").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_contain_any_of_substrings() {
+        let matcher = contain_any_of(["COMMIT", "ROLLBACK"]);
+        matcher.test(&"BEGIN body END").passed.should_be_false();
+    }
+
+    #[test]
+    fn should_be_alphabetic() {
+        let matcher = be_alphabetic();
+        matcher.test(&"clearcheck").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_alphabetic() {
+        let matcher = be_alphabetic();
+        let result = matcher.test(&"assert4j");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("\"assert4j\" should be alphabetic but found '4' at index 6");
+    }
+
+    #[test]
+    fn should_be_alphanumeric() {
+        let matcher = be_alphanumeric();
+        matcher.test(&"assert4j").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_alphanumeric() {
+        let matcher = be_alphanumeric();
+        let result = matcher.test(&"assert-4j");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("but found '-' at index 6");
+    }
+
+    #[test]
+    fn should_be_ascii() {
+        let matcher = be_ascii();
+        matcher.test(&"clear check!").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_ascii() {
+        let matcher = be_ascii();
+        let result = matcher.test(&"café");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("but found 'é' at index 3");
+    }
+
+    #[test]
+    fn should_be_ascii_printable() {
+        let matcher = be_ascii_printable();
+        matcher.test(&"clear check!").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_ascii_printable() {
+        let matcher = be_ascii_printable();
+        let result = matcher.test(&"clear\tcheck");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("but found '\\t' at index 5");
+    }
+
+    #[test]
+    fn should_be_hex() {
+        let matcher = be_hex();
+        matcher.test(&"deadBEEF01").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_hex() {
+        let matcher = be_hex();
+        let result = matcher.test(&"0xff");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("but found 'x' at index 1");
+    }
+
+    #[test]
+    fn should_be_blank() {
+        let matcher = be_blank();
+        matcher.test(&" \t\n").passed.should_be_true();
+        matcher.test(&"").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_blank() {
+        let matcher = be_blank();
+        let result = matcher.test(&"  x ");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should be blank but found 'x' at index 2");
+    }
+
+    #[test]
+    fn should_contain_whitespace() {
+        let matcher = contain_whitespace();
+        matcher.test(&"clear check").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_contain_whitespace() {
+        let matcher = contain_whitespace();
+        let result = matcher.test(&"clear check");
+        result.inverted_failure_message.should_contain("should not contain whitespace but found ' ' at index 5");
+    }
+
+    #[test]
+    fn should_contain_control_characters() {
+        let matcher = contain_control_characters();
+        matcher.test(&"clear\u{7}check").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_contain_control_characters() {
+        let matcher = contain_control_characters();
+        matcher.test(&"clear check").passed.should_be_false();
+    }
+
+    #[test]
+    fn should_only_contain_chars_matching() {
+        let matcher = only_contain_chars_matching(|ch| ch.is_ascii_lowercase() || ch == '-');
+        matcher.test(&"clear-check").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_only_contain_chars_matching() {
+        let matcher = only_contain_chars_matching(|ch| ch.is_ascii_lowercase() || ch == '-');
+        let result = matcher.test(&"clear_check");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should only contain characters matching the given predicate but found '_' at index 5");
+    }
 }
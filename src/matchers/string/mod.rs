@@ -1,9 +1,13 @@
 pub mod boundary;
 pub mod case;
+#[cfg(feature = "date")]
+pub mod date;
 pub mod empty;
 pub mod equal;
+pub mod formats;
 pub mod length;
 pub mod membership;
 pub mod numeric;
+pub mod similarity;
 #[cfg(feature = "regex")]
 pub mod regex;
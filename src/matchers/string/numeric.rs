@@ -1,4 +1,6 @@
+use std::fmt::{Debug, Display};
 use std::marker::PhantomData;
+use std::ops::{Range, RangeInclusive};
 use std::str::FromStr;
 
 use crate::matchers::{Matcher, MatcherResult};
@@ -17,7 +19,7 @@ pub struct NumericMatcher<M: FromStr> {
     _inner: PhantomData<M>,
 }
 
-impl<T: AsRef<str>, M: FromStr> Matcher<T> for NumericMatcher<M> {
+impl<T: AsRef<str> + ?Sized, M: FromStr> Matcher<T> for NumericMatcher<M> {
     fn test(&self, value: &T) -> MatcherResult {
         let parse_result = value.as_ref().parse::<M>();
         MatcherResult::formatted(
@@ -28,6 +30,93 @@ impl<T: AsRef<str>, M: FromStr> Matcher<T> for NumericMatcher<M> {
     }
 }
 
+/// ParseMatcher offers a flexible way to assert whether a string parses to a number, reporting why it did not.
+///
+/// Unlike [`NumericMatcher`], it requires the parse error to implement [`Display`], and the failure message contains the parse error.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::string::numeric::parse_as;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = parse_as::<i32>();
+/// assert!(matcher.test(&"12345").passed());
+/// ```
+pub struct ParseMatcher<M: FromStr> {
+    _inner: PhantomData<M>,
+}
+
+impl<T: AsRef<str> + ?Sized, M: FromStr> Matcher<T> for ParseMatcher<M>
+    where M::Err: Display
+{
+    fn test(&self, value: &T) -> MatcherResult {
+        let parse_result = value.as_ref().parse::<M>();
+        let failure = match &parse_result {
+            Ok(_) => format!("{:?} should be numeric", value.as_ref()),
+            Err(err) => parse_failure_message(value.as_ref(), err),
+        };
+        MatcherResult::formatted(
+            parse_result.is_ok(),
+            failure,
+            format!("{:?} should not be numeric", value.as_ref()),
+        )
+    }
+}
+
+pub(crate) fn parse_failure_message<E: Display>(value: &str, err: &E) -> String {
+    format!("{:?} should be numeric but failed with: {}", value, err)
+}
+
+/// NumericRangeMatcher offers a flexible way to assert whether a string parses to a number that falls within a range.
+///
+/// The failure message contains the parsed number, or the parse error if the string is not numeric.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::string::numeric::be_numeric_in_inclusive_range;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = be_numeric_in_inclusive_range(0.0..=1.0);
+/// assert!(matcher.test(&"0.75").passed());
+/// ```
+pub enum NumericRangeMatcher<M: FromStr + PartialOrd> {
+    InclusiveRange(RangeInclusive<M>),
+    ExclusiveRange(Range<M>),
+}
+
+impl<T, M> Matcher<T> for NumericRangeMatcher<M>
+    where T: AsRef<str> + ?Sized,
+          M: FromStr + PartialOrd + Debug,
+          M::Err: Display
+{
+    fn test(&self, value: &T) -> MatcherResult {
+        let range = match self {
+            NumericRangeMatcher::InclusiveRange(range) => format!("{:?}", range),
+            NumericRangeMatcher::ExclusiveRange(range) => format!("{:?}", range),
+        };
+        match value.as_ref().parse::<M>() {
+            Ok(number) => {
+                let passed = match self {
+                    NumericRangeMatcher::InclusiveRange(range) => range.contains(&number),
+                    NumericRangeMatcher::ExclusiveRange(range) => range.contains(&number),
+                };
+                MatcherResult::formatted(
+                    passed,
+                    format!("{:?} should be numeric in the range {} but was {:?}", value.as_ref(), range, number),
+                    format!("{:?} should not be numeric in the range {} but was {:?}", value.as_ref(), range, number),
+                )
+            }
+            Err(err) => {
+                let message = format!(
+                    "{:?} should be numeric in the range {} but failed with: {}",
+                    value.as_ref(), range, err
+                );
+                MatcherResult::formatted(false, message.clone(), message)
+            }
+        }
+    }
+}
+
 /// Creates a NumericMatcher that asserts whether a string is numeric.
 pub fn be_numeric<M: FromStr>() -> NumericMatcher<M> {
     NumericMatcher {
@@ -35,11 +124,29 @@ pub fn be_numeric<M: FromStr>() -> NumericMatcher<M> {
     }
 }
 
+/// Creates a ParseMatcher that asserts whether a string parses to a number, with the parse error in the failure message.
+pub fn parse_as<M: FromStr>() -> ParseMatcher<M> {
+    ParseMatcher {
+        _inner: PhantomData,
+    }
+}
+
+/// Creates a NumericRangeMatcher that asserts whether a string parses to a number within the given inclusive range.
+pub fn be_numeric_in_inclusive_range<M: FromStr + PartialOrd>(range: RangeInclusive<M>) -> NumericRangeMatcher<M> {
+    NumericRangeMatcher::InclusiveRange(range)
+}
+
+/// Creates a NumericRangeMatcher that asserts whether a string parses to a number within the given exclusive range.
+pub fn be_numeric_in_exclusive_range<M: FromStr + PartialOrd>(range: Range<M>) -> NumericRangeMatcher<M> {
+    NumericRangeMatcher::ExclusiveRange(range)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::string::membership::MembershipAssertion;
     use crate::matchers::Matcher;
-    use crate::matchers::string::numeric::be_numeric;
+    use crate::matchers::string::numeric::{be_numeric, be_numeric_in_exclusive_range, be_numeric_in_inclusive_range, parse_as};
 
     #[test]
     fn should_be_numeric_i32() {
@@ -70,4 +177,51 @@ mod tests {
         let value = "123.45a";
         matcher.test(&value).passed.should_be_true();
     }
+
+    #[test]
+    fn should_parse_as_i32() {
+        let matcher = parse_as::<i32>();
+        matcher.test(&"123").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_contain_the_parse_error_given_not_numeric() {
+        let matcher = parse_as::<i32>();
+        let result = matcher.test(&"123a");
+        result.failure_message.should_contain("\"123a\" should be numeric but failed with: invalid digit found in string");
+    }
+
+    #[test]
+    fn should_be_numeric_in_inclusive_range() {
+        let matcher = be_numeric_in_inclusive_range(0.0..=1.0);
+        matcher.test(&"1.0").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_numeric_in_inclusive_range() {
+        let matcher = be_numeric_in_inclusive_range(0.0..=1.0);
+        let result = matcher.test(&"1.5");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("\"1.5\" should be numeric in the range 0.0..=1.0 but was 1.5");
+    }
+
+    #[test]
+    fn should_not_be_numeric_in_inclusive_range_given_not_numeric() {
+        let matcher = be_numeric_in_inclusive_range(0..=10);
+        let result = matcher.test(&"ten");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("but failed with: invalid digit found in string");
+    }
+
+    #[test]
+    fn should_be_numeric_in_exclusive_range() {
+        let matcher = be_numeric_in_exclusive_range(0..10);
+        matcher.test(&"9").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_numeric_in_exclusive_range() {
+        let matcher = be_numeric_in_exclusive_range(0..10);
+        matcher.test(&"10").passed.should_be_false();
+    }
 }
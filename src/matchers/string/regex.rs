@@ -1,9 +1,14 @@
+use std::fmt::{Display, Formatter};
+
 use regex::Regex;
 
 use crate::matchers::{Matcher, MatcherResult};
 
 /// RegexMatcher offers a flexible way to assert whether a string matches a regular expression.
 ///
+/// The string can be matched anywhere ([match_with]), as a whole ([match_fully_with]),
+/// or the number of non-overlapping matches can be asserted ([have_match_count]).
+///
 /// # Example
 ///```
 /// use regex::Regex;
@@ -15,21 +20,185 @@ use crate::matchers::{Matcher, MatcherResult};
 /// ```
 pub struct RegexMatcher {
     regexp: Regex,
+    kind: RegexMatcherKind,
+}
+
+enum RegexMatcherKind {
+    Match,
+    FullMatch(Regex),
+    MatchCount(usize),
 }
 
-impl<T: AsRef<str>> Matcher<T> for RegexMatcher {
+impl<T: AsRef<str> + ?Sized> Matcher<T> for RegexMatcher {
     fn test(&self, value: &T) -> MatcherResult {
-        MatcherResult::formatted(
-            self.regexp.is_match(value.as_ref()),
-            format!(
-                "{:?} should match the regular expression {:?}",
-                value.as_ref(), self.regexp
+        match &self.kind {
+            RegexMatcherKind::Match => MatcherResult::formatted(
+                self.regexp.is_match(value.as_ref()),
+                format!(
+                    "{:?} should match the regular expression {:?}",
+                    value.as_ref(), self.regexp
+                ),
+                format!(
+                    "{:?} should not match the regular expression {:?}",
+                    value.as_ref(), self.regexp
+                ),
             ),
-            format!(
-                "{:?} should not match the regular expression {:?}",
-                value.as_ref(), self.regexp
+            RegexMatcherKind::FullMatch(anchored) => MatcherResult::formatted(
+                anchored.is_match(value.as_ref()),
+                format!(
+                    "{:?} should fully match the regular expression {:?}",
+                    value.as_ref(), self.regexp
+                ),
+                format!(
+                    "{:?} should not fully match the regular expression {:?}",
+                    value.as_ref(), self.regexp
+                ),
             ),
-        )
+            RegexMatcherKind::MatchCount(count) => {
+                let matches: Vec<&str> = self.regexp.find_iter(value.as_ref()).map(|found| found.as_str()).collect();
+                MatcherResult::formatted(
+                    matches.len() == *count,
+                    format!(
+                        "{:?} should have {} matches of the regular expression {:?} but had {} {:?}",
+                        value.as_ref(), count, self.regexp, matches.len(), matches
+                    ),
+                    format!(
+                        "{:?} should not have {} matches of the regular expression {:?}",
+                        value.as_ref(), count, self.regexp
+                    ),
+                )
+            }
+        }
+    }
+}
+
+/// CaptureGroup identifies a capture group of a regular expression, either by its index (0 is the whole match) or by its name.
+///
+/// Converts from usize (index), and from &str or String (name).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum CaptureGroup {
+    Index(usize),
+    Name(String),
+}
+
+impl From<usize> for CaptureGroup {
+    fn from(index: usize) -> Self {
+        CaptureGroup::Index(index)
+    }
+}
+
+impl From<&str> for CaptureGroup {
+    fn from(name: &str) -> Self {
+        CaptureGroup::Name(name.to_string())
+    }
+}
+
+impl From<String> for CaptureGroup {
+    fn from(name: String) -> Self {
+        CaptureGroup::Name(name)
+    }
+}
+
+impl Display for CaptureGroup {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CaptureGroup::Index(index) => write!(f, "group {}", index),
+            CaptureGroup::Name(name) => write!(f, "group {:?}", name),
+        }
+    }
+}
+
+/// RegexCaptureMatcher offers a flexible way to assert that the text captured by a regular expression matches the given string matcher.
+///
+/// - Capture: the given capture group of the first match satisfies the matcher.
+/// - AllMatches: there is at least one match, and every (non-overlapping) match satisfies the matcher.
+///
+/// Failure messages name the capture group (or the match) that failed, along with its captured text.
+///
+/// # Example
+///```
+/// use regex::Regex;
+/// use clearcheck::matchers::string::boundary::begin_with;
+/// use clearcheck::matchers::string::regex::have_capture;
+/// use clearcheck::matchers::Matcher;
+///
+/// let regex = Regex::new(r"(?<year>\d{4})-(\d{2})-(\d{2})").unwrap();
+/// let matcher = have_capture(regex, "year", begin_with("20"));
+/// assert!(matcher.test(&"Started clearcheck on 2024-01-02.").passed());
+/// ```
+pub enum RegexCaptureMatcher<M: Matcher<str>> {
+    Capture(Regex, CaptureGroup, M),
+    AllMatches(Regex, M),
+}
+
+impl<T, M> Matcher<T> for RegexCaptureMatcher<M>
+    where T: AsRef<str> + ?Sized,
+          M: Matcher<str>
+{
+    fn test(&self, value: &T) -> MatcherResult {
+        let value = value.as_ref();
+        match self {
+            RegexCaptureMatcher::Capture(regexp, group, matcher) => {
+                let captured = regexp.captures(value).map(|captures| match group {
+                    CaptureGroup::Index(index) => captures.get(*index),
+                    CaptureGroup::Name(name) => captures.name(name),
+                });
+                match captured {
+                    Some(Some(captured)) => {
+                        let result = matcher.test(captured.as_str());
+                        MatcherResult::formatted(
+                            result.passed,
+                            format!(
+                                "{} of {:?} captured {:?} which did not match: {}",
+                                group, regexp, captured.as_str(), result.failure_message
+                            ),
+                            format!(
+                                "{} of {:?} captured {:?} which matched: {}",
+                                group, regexp, captured.as_str(), result.inverted_failure_message
+                            ),
+                        )
+                    }
+                    Some(None) => {
+                        let message = format!("{:?} should have {} of {:?} but it captured nothing", value, group, regexp);
+                        MatcherResult::formatted(false, message.clone(), message)
+                    }
+                    None => {
+                        let message = format!("{:?} should match the regular expression {:?} to capture {}", value, regexp, group);
+                        MatcherResult::formatted(false, message.clone(), message)
+                    }
+                }
+            }
+            RegexCaptureMatcher::AllMatches(regexp, matcher) => {
+                let matches: Vec<&str> = regexp.find_iter(value).map(|found| found.as_str()).collect();
+                if matches.is_empty() {
+                    let message = format!("{:?} should have matches of the regular expression {:?} but had none", value, regexp);
+                    return MatcherResult::formatted(false, message.clone(), message);
+                }
+                let failing = matches
+                    .iter()
+                    .enumerate()
+                    .map(|(index, found)| (index, found, matcher.test(*found)))
+                    .find(|(_, _, result)| !result.passed);
+                match failing {
+                    Some((index, found, result)) => MatcherResult::formatted(
+                        false,
+                        format!(
+                            "{:?} should have all matches of {:?} satisfying the matcher but match {} {:?} did not: {}",
+                            value, regexp, index, found, result.failure_message
+                        ),
+                        format!("{:?} should not have all matches of {:?} satisfying the matcher", value, regexp),
+                    ),
+                    None => MatcherResult::formatted(
+                        true,
+                        format!("{:?} should have all matches of {:?} satisfying the matcher", value, regexp),
+                        format!(
+                            "{:?} should not have all matches of {:?} satisfying the matcher but all of {:?} did",
+                            value, regexp, matches
+                        ),
+                    ),
+                }
+            }
+        }
     }
 }
 
@@ -37,13 +206,52 @@ impl<T: AsRef<str>> Matcher<T> for RegexMatcher {
 pub fn match_with(regular_expression: Regex) -> RegexMatcher {
     RegexMatcher {
         regexp: regular_expression,
+        kind: RegexMatcherKind::Match,
     }
 }
 
+/// Creates a RegexMatcher that asserts whether the whole string (not just a part of it) matches the given regular expression.
+///
+/// The regular expression is matched as `\A(?:pattern)\z`, built from its pattern, so any alternative or repetition that covers
+/// the whole string is considered. Options set on a [regex::RegexBuilder] (like case insensitivity) are not carried over,
+/// write them as inline flags instead, like `(?i)clearcheck`.
+pub fn match_fully_with(regular_expression: Regex) -> RegexMatcher {
+    let anchored = Regex::new(&format!(r"\A(?:{})\z", regular_expression.as_str()))
+        .expect("anchoring a valid regular expression should produce a valid regular expression");
+    RegexMatcher {
+        regexp: regular_expression,
+        kind: RegexMatcherKind::FullMatch(anchored),
+    }
+}
+
+/// Creates a RegexMatcher that asserts whether a string has the given number of non-overlapping matches of the regular expression.
+pub fn have_match_count(regular_expression: Regex, count: usize) -> RegexMatcher {
+    RegexMatcher {
+        regexp: regular_expression,
+        kind: RegexMatcherKind::MatchCount(count),
+    }
+}
+
+/// Creates a RegexCaptureMatcher that asserts whether the given capture group (index or name) of the first match satisfies the matcher.
+pub fn have_capture<G, M>(regular_expression: Regex, group: G, matcher: M) -> RegexCaptureMatcher<M>
+    where G: Into<CaptureGroup>,
+          M: Matcher<str>
+{
+    RegexCaptureMatcher::Capture(regular_expression, group.into(), matcher)
+}
+
+/// Creates a RegexCaptureMatcher that asserts whether every match of the regular expression satisfies the matcher.
+pub fn have_all_matches_satisfying<M: Matcher<str>>(regular_expression: Regex, matcher: M) -> RegexCaptureMatcher<M> {
+    RegexCaptureMatcher::AllMatches(regular_expression, matcher)
+}
+
 #[cfg(all(test, feature = "regex"))]
 mod tests {
     use crate::assertions::bool::TrueFalseAssertion;
-    use crate::matchers::string::regex::match_with;
+    use crate::assertions::string::membership::MembershipAssertion;
+    use crate::matchers::string::boundary::begin_with;
+    use crate::matchers::string::length::have_atleast_same_length;
+    use crate::matchers::string::regex::{have_all_matches_satisfying, have_capture, have_match_count, match_fully_with, match_with};
     use crate::matchers::Matcher;
     use regex::Regex;
 
@@ -65,4 +273,121 @@ mod tests {
         let matcher = match_with(regex);
         matcher.test(&str).passed.should_be_true();
     }
+
+    #[test]
+    fn should_match_regular_expression_fully() {
+        let regex = Regex::new(r"\d{4}-\d{2}-\d{2}").unwrap();
+        let matcher = match_fully_with(regex);
+        matcher.test(&"2024-01-02").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_match_regular_expression_fully() {
+        let regex = Regex::new(r"\d{4}-\d{2}-\d{2}").unwrap();
+        let matcher = match_fully_with(regex);
+        let result = matcher.test(&"On 2024-01-02");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should fully match the regular expression");
+    }
+
+    #[test]
+    fn should_match_regular_expression_fully_with_inline_flags() {
+        let regex = Regex::new("(?i)clearcheck").unwrap();
+        let matcher = match_fully_with(regex);
+        matcher.test(&"ClearCheck").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_match_alternation_fully_given_the_later_alternative_spans_the_string() {
+        let regex = Regex::new(r"a|ab").unwrap();
+        let matcher = match_fully_with(regex);
+        matcher.test(&"ab").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_match_lazy_repetition_fully() {
+        let regex = Regex::new(r"\d+?").unwrap();
+        let matcher = match_fully_with(regex);
+        matcher.test(&"123").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_match_alternation_fully_given_only_one_side_anchored() {
+        let regex = Regex::new(r"a|b").unwrap();
+        let matcher = match_fully_with(regex);
+        matcher.test(&"ab").passed.should_be_false();
+    }
+
+    #[test]
+    fn should_have_match_count() {
+        let regex = Regex::new(r"\d+").unwrap();
+        let matcher = have_match_count(regex, 3);
+        matcher.test(&"1, 22 and 333").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_have_match_count() {
+        let regex = Regex::new(r"\d+").unwrap();
+        let matcher = have_match_count(regex, 2);
+        let result = matcher.test(&"1, 22 and 333");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("but had 3 [\"1\", \"22\", \"333\"]");
+    }
+
+    #[test]
+    fn should_have_named_capture() {
+        let regex = Regex::new(r"(?<year>\d{4})-(\d{2})").unwrap();
+        let matcher = have_capture(regex, "year", begin_with("20"));
+        matcher.test(&"On 2024-01").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_have_indexed_capture() {
+        let regex = Regex::new(r"(?<year>\d{4})-(\d{2})").unwrap();
+        let matcher = have_capture(regex, 2, begin_with("1"));
+        let result = matcher.test(&"On 2024-01");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("group 2 of Regex(\"(?<year>\\\\d{4})-(\\\\d{2})\") captured \"01\" which did not match");
+    }
+
+    #[test]
+    fn should_not_have_capture_given_group_did_not_participate() {
+        let regex = Regex::new(r"(\d{4})(-\d{2})?").unwrap();
+        let matcher = have_capture(regex, 2, begin_with("-"));
+        let result = matcher.test(&"On 2024");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should have group 2 of");
+    }
+
+    #[test]
+    fn should_not_have_capture_given_no_match() {
+        let regex = Regex::new(r"(\d{4})").unwrap();
+        let matcher = have_capture(regex, 1, begin_with("20"));
+        let result = matcher.test(&"On January");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("to capture group 1");
+    }
+
+    #[test]
+    fn should_have_all_matches_satisfying() {
+        let regex = Regex::new(r"\d+").unwrap();
+        let matcher = have_all_matches_satisfying(regex, have_atleast_same_length(2));
+        matcher.test(&"10, 22 and 333").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_have_all_matches_satisfying() {
+        let regex = Regex::new(r"\d+").unwrap();
+        let matcher = have_all_matches_satisfying(regex, have_atleast_same_length(2));
+        let result = matcher.test(&"10, 2 and 333");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("but match 1 \"2\" did not");
+    }
+
+    #[test]
+    fn should_not_have_all_matches_satisfying_given_no_match() {
+        let regex = Regex::new(r"\d+").unwrap();
+        let matcher = have_all_matches_satisfying(regex, have_atleast_same_length(2));
+        matcher.test(&"none").passed.should_be_false();
+    }
 }
@@ -0,0 +1,283 @@
+//! provides [SimilarityMatcher] to assert how close a string is to an expected string, along with the edit distance helpers
+//! used for "closest match" hints in other string matchers.
+
+use crate::matchers::{Matcher, MatcherResult};
+
+/// SimilarityMatcher offers a flexible way to assert that a string is close to (but not necessarily equal to) an expected string.
+///
+/// - EditDistance: the Levenshtein distance (number of single character insertions, deletions or substitutions)
+///   between the strings is at most the given distance.
+/// - Ratio: the Jaro-Winkler similarity between the strings, a value between 0.0 (nothing in common) and 1.0 (equal),
+///   is at least the given ratio.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::string::similarity::be_similar_to;
+/// use clearcheck::matchers::Matcher;
+///
+/// let matcher = be_similar_to("clearcheck", 2);
+/// assert!(matcher.test(&"clearchek").passed());
+/// ```
+pub enum SimilarityMatcher {
+    EditDistance(String, usize),
+    Ratio(String, f64),
+}
+
+impl<T> Matcher<T> for SimilarityMatcher
+where T: AsRef<str> + ?Sized
+{
+    fn test(&self, value: &T) -> MatcherResult {
+        let value = value.as_ref();
+        match self {
+            SimilarityMatcher::EditDistance(expected, max_distance) => {
+                let distance = levenshtein(value, expected);
+                MatcherResult::formatted(
+                    distance <= *max_distance,
+                    format!(
+                        "{:?} should be within edit distance {} of {:?} but was {}",
+                        value, max_distance, expected, distance
+                    ),
+                    format!(
+                        "{:?} should not be within edit distance {} of {:?} but was {}",
+                        value, max_distance, expected, distance
+                    ),
+                )
+            }
+            SimilarityMatcher::Ratio(expected, ratio) => {
+                let similarity = jaro_winkler(value, expected);
+                MatcherResult::formatted(
+                    similarity >= *ratio,
+                    format!(
+                        "{:?} should have similarity at least {:.2} with {:?} but was {:.2}",
+                        value, ratio, expected, similarity
+                    ),
+                    format!(
+                        "{:?} should not have similarity at least {:.2} with {:?} but was {:.2}",
+                        value, ratio, expected, similarity
+                    ),
+                )
+            }
+        }
+    }
+}
+
+/// Creates a SimilarityMatcher that asserts whether a string is within the given Levenshtein edit distance of the expected string.
+pub fn be_similar_to(expected: &str, max_edit_distance: usize) -> SimilarityMatcher {
+    SimilarityMatcher::EditDistance(expected.to_string(), max_edit_distance)
+}
+
+/// Creates a SimilarityMatcher that asserts whether a string has a Jaro-Winkler similarity of at least the given ratio
+/// (between 0.0 and 1.0) with the expected string.
+pub fn have_similarity_at_least(expected: &str, ratio: f64) -> SimilarityMatcher {
+    SimilarityMatcher::Ratio(expected.to_string(), ratio)
+}
+
+/// Returns the Levenshtein distance between the two strings, counted in chars.
+pub(crate) fn levenshtein(left: &str, right: &str) -> usize {
+    let right: Vec<char> = right.chars().collect();
+    let mut previous: Vec<usize> = (0..=right.len()).collect();
+    let mut current = vec![0; right.len() + 1];
+
+    for (row, left_char) in left.chars().enumerate() {
+        current[0] = row + 1;
+        for (column, right_char) in right.iter().enumerate() {
+            let substitution = previous[column] + usize::from(left_char != *right_char);
+            current[column + 1] = substitution
+                .min(previous[column + 1] + 1)
+                .min(current[column] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[right.len()]
+}
+
+/// Returns the Jaro-Winkler similarity between the two strings, from 0.0 (nothing in common) to 1.0 (equal).
+pub(crate) fn jaro_winkler(left: &str, right: &str) -> f64 {
+    let left: Vec<char> = left.chars().collect();
+    let right: Vec<char> = right.chars().collect();
+    if left.is_empty() && right.is_empty() {
+        return 1.0;
+    }
+    if left.is_empty() || right.is_empty() {
+        return 0.0;
+    }
+
+    let window = (left.len().max(right.len()) / 2).saturating_sub(1);
+    let mut left_matched = vec![false; left.len()];
+    let mut right_matched = vec![false; right.len()];
+    let mut matches = 0;
+
+    for (index, left_char) in left.iter().enumerate() {
+        let start = index.saturating_sub(window);
+        let end = (index + window + 1).min(right.len());
+        for other in start..end {
+            if !right_matched[other] && right[other] == *left_char {
+                left_matched[index] = true;
+                right_matched[other] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let left_in_order = left.iter().zip(&left_matched).filter(|(_, matched)| **matched);
+    let right_in_order = right.iter().zip(&right_matched).filter(|(_, matched)| **matched);
+    let transpositions = left_in_order
+        .zip(right_in_order)
+        .filter(|((left_char, _), (right_char, _))| left_char != right_char)
+        .count() / 2;
+
+    let matches = matches as f64;
+    let jaro = (matches / left.len() as f64
+        + matches / right.len() as f64
+        + (matches - transpositions as f64) / matches) / 3.0;
+
+    let common_prefix = left
+        .iter()
+        .zip(right.iter())
+        .take(4)
+        .take_while(|(left_char, right_char)| left_char == right_char)
+        .count();
+    jaro + common_prefix as f64 * 0.1 * (1.0 - jaro)
+}
+
+/// Returns the substring of the source closest (by Levenshtein distance) to the target, along with its char offset in the source.
+///
+/// Returns None if either string is empty.
+pub(crate) fn closest_substring(source: &str, target: &str) -> Option<(String, usize)> {
+    let source: Vec<char> = source.chars().collect();
+    let target: Vec<char> = target.chars().collect();
+    closest_window(&source, &target).map(|(start, end)| (source[start..end].iter().collect(), start))
+}
+
+/// Returns the substring of the source closest (by Levenshtein distance) to the target ignoring case, along with its char offset in the source.
+///
+/// The match is computed on the lowercased strings, but the substring and its offset are reported from the original source,
+/// since lowercasing can change the number of chars (for example, 'İ' lowercases to two chars).
+/// Returns None if either string is empty.
+pub(crate) fn closest_substring_ignoring_case(source: &str, target: &str) -> Option<(String, usize)> {
+    let source: Vec<char> = source.chars().collect();
+    let mut lowercase = Vec::with_capacity(source.len());
+    let mut origins = Vec::with_capacity(source.len());
+    for (index, ch) in source.iter().enumerate() {
+        for lower in ch.to_lowercase() {
+            lowercase.push(lower);
+            origins.push(index);
+        }
+    }
+    let target: Vec<char> = target.chars().flat_map(char::to_lowercase).collect();
+    closest_window(&lowercase, &target).map(|(start, end)| {
+        let (start, end) = (origins[start], origins[end - 1] + 1);
+        (source[start..end].iter().collect(), start)
+    })
+}
+
+/// Returns the char range of the source closest (by Levenshtein distance) to the target.
+///
+/// Uses Sellers' approximate substring search: a single Levenshtein pass in O(source * target) time and O(target) space,
+/// where a match may start at any position of the source for free.
+/// Ties go to the window whose length is closest to the target's, and then to the earliest window.
+fn closest_window(source: &[char], target: &[char]) -> Option<(usize, usize)> {
+    if source.is_empty() || target.is_empty() {
+        return None;
+    }
+
+    // (distance, start) of the closest alignment of target[..row] with a substring of the source ending at the current column.
+    let mut previous: Vec<(usize, usize)> = (0..=target.len()).map(|row| (row, 0)).collect();
+    let mut current = previous.clone();
+    let mut closest = ((usize::MAX, usize::MAX), 0, 0);
+
+    for (column, source_char) in source.iter().enumerate() {
+        current[0] = (0, column + 1);
+        for (row, target_char) in target.iter().enumerate() {
+            let substitution = (previous[row].0 + usize::from(source_char != target_char), previous[row].1);
+            let insertion = (previous[row + 1].0 + 1, previous[row + 1].1);
+            let deletion = (current[row].0 + 1, current[row].1);
+            current[row + 1] = substitution.min(insertion).min(deletion);
+        }
+        let (distance, start) = current[target.len()];
+        let rank = (distance, (column + 1 - start).abs_diff(target.len()));
+        if rank < closest.0 {
+            closest = (rank, start, column + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    Some((closest.1, closest.2))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::string::membership::MembershipAssertion;
+    use crate::matchers::Matcher;
+    use crate::matchers::string::similarity::{be_similar_to, closest_substring, closest_substring_ignoring_case, have_similarity_at_least, jaro_winkler, levenshtein};
+
+    #[test]
+    fn should_compute_levenshtein_distance() {
+        (levenshtein("kitten", "sitting") == 3).should_be_true();
+        (levenshtein("", "abc") == 3).should_be_true();
+        (levenshtein("héllo", "hello") == 1).should_be_true();
+    }
+
+    #[test]
+    fn should_compute_jaro_winkler_similarity() {
+        ((jaro_winkler("martha", "marhta") - 0.961).abs() < 0.001).should_be_true();
+        ((jaro_winkler("clearcheck", "clearcheck") - 1.0).abs() < f64::EPSILON).should_be_true();
+        (jaro_winkler("abc", "xyz") == 0.0).should_be_true();
+    }
+
+    #[test]
+    fn should_find_the_closest_substring() {
+        let closest = closest_substring("cargo test --workspace", "wrkspace");
+        (closest == Some(("workspace".to_string(), 13))).should_be_true();
+    }
+
+    #[test]
+    fn should_find_the_closest_substring_in_a_large_string() {
+        let source = format!("{}needle in a haystack", "hay ".repeat(25_000));
+        let closest = closest_substring(&source, "neddle in a haystack");
+        (closest == Some(("needle in a haystack".to_string(), 100_000))).should_be_true();
+    }
+
+    #[test]
+    fn should_find_the_closest_substring_ignoring_case_in_the_original_string() {
+        let closest = closest_substring_ignoring_case("Visit İstanbul", "ISTANBOL");
+        (closest == Some(("İstanbul".to_string(), 6))).should_be_true();
+    }
+
+    #[test]
+    fn should_not_find_the_closest_substring_in_an_empty_string() {
+        closest_substring("", "select").is_none().should_be_true();
+    }
+
+    #[test]
+    fn should_be_similar() {
+        let matcher = be_similar_to("clearcheck", 2);
+        matcher.test(&"clearchek").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_be_similar() {
+        let matcher = be_similar_to("clearcheck", 1);
+        let result = matcher.test(&"clearchk");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should be within edit distance 1 of \"clearcheck\" but was 2");
+    }
+
+    #[test]
+    fn should_have_similarity_at_least() {
+        let matcher = have_similarity_at_least("martha", 0.95);
+        matcher.test(&"marhta").passed.should_be_true();
+    }
+
+    #[test]
+    fn should_not_have_similarity_at_least() {
+        let matcher = have_similarity_at_least("martha", 0.99);
+        let result = matcher.test(&"marhta");
+        result.passed.should_be_false();
+        result.failure_message.should_contain("should have similarity at least 0.99 with \"martha\" but was 0.96");
+    }
+}
@@ -0,0 +1,136 @@
+use std::time::Duration;
+
+use crate::matchers::{Matcher, MatcherResult};
+use crate::matchers::time::humanize;
+
+/// DurationMatcher offers a flexible way to make assertions about [`Duration`] values.
+///
+/// The failure messages carry the durations in a human-readable form, like `1s 250ms`.
+///
+/// # Example
+///```
+/// use std::time::Duration;
+/// use clearcheck::matchers::Matcher;
+/// use clearcheck::matchers::time::duration::be_close_to;
+///
+/// let duration = Duration::from_millis(1005);
+/// let matcher = be_close_to(Duration::from_secs(1), Duration::from_millis(10));
+///
+/// assert!(matcher.test(&duration).passed());
+/// ```
+pub enum DurationMatcher {
+    CloseTo(Duration, Duration),
+    AtMost(Duration),
+    Zero,
+}
+
+impl Matcher<Duration> for DurationMatcher {
+    fn test(&self, value: &Duration) -> MatcherResult {
+        match self {
+            DurationMatcher::CloseTo(expected, tolerance) => {
+                let difference = value.max(expected).saturating_sub(*value.min(expected));
+                MatcherResult::formatted(
+                    difference <= *tolerance,
+                    format!(
+                        "{} should be close to {} within {} but differed by {}",
+                        humanize(value),
+                        humanize(expected),
+                        humanize(tolerance),
+                        humanize(&difference)
+                    ),
+                    format!(
+                        "{} should not be close to {} within {} but differed by {}",
+                        humanize(value),
+                        humanize(expected),
+                        humanize(tolerance),
+                        humanize(&difference)
+                    ),
+                )
+            }
+            DurationMatcher::AtMost(limit) => MatcherResult::formatted(
+                value <= limit,
+                format!("{} should be at most {}", humanize(value), humanize(limit)),
+                format!("{} should not be at most {}", humanize(value), humanize(limit)),
+            ),
+            DurationMatcher::Zero => MatcherResult::formatted(
+                value.is_zero(),
+                format!("{} should be a zero duration", humanize(value)),
+                format!("{} should not be a zero duration", humanize(value)),
+            ),
+        }
+    }
+}
+
+/// Creates a DurationMatcher that asserts whether a duration is within the tolerance of the expected duration.
+pub fn be_close_to(expected: Duration, tolerance: Duration) -> DurationMatcher {
+    DurationMatcher::CloseTo(expected, tolerance)
+}
+
+/// Creates a DurationMatcher that asserts whether a duration is less than or equal to the given limit.
+pub fn be_at_most(limit: Duration) -> DurationMatcher {
+    DurationMatcher::AtMost(limit)
+}
+
+/// Creates a DurationMatcher that asserts whether a duration is zero.
+pub fn be_zero_duration() -> DurationMatcher {
+    DurationMatcher::Zero
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::equal::EqualityAssertion;
+    use crate::matchers::Matcher;
+    use crate::matchers::time::duration::{be_at_most, be_close_to, be_zero_duration};
+
+    #[test]
+    fn should_be_close_to() {
+        let matcher = be_close_to(Duration::from_secs(1), Duration::from_millis(10));
+        matcher.test(&Duration::from_millis(995)).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_close_to_but_was_not() {
+        let matcher = be_close_to(Duration::from_secs(1), Duration::from_millis(10));
+        matcher.test(&Duration::from_millis(1020)).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_report_human_readable_difference() {
+        let matcher = be_close_to(Duration::from_secs(1), Duration::from_millis(10));
+        let result = matcher.test(&Duration::from_millis(1250));
+
+        result.failure_message.should_equal(
+            "1s 250ms should be close to 1s within 10ms but differed by 250ms",
+        );
+    }
+
+    #[test]
+    fn should_be_at_most() {
+        let matcher = be_at_most(Duration::from_millis(50));
+        matcher.test(&Duration::from_millis(50)).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_at_most_but_was_not() {
+        let matcher = be_at_most(Duration::from_millis(50));
+        matcher.test(&Duration::from_millis(51)).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_zero_duration() {
+        let matcher = be_zero_duration();
+        matcher.test(&Duration::ZERO).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_zero_duration_but_was_not() {
+        let matcher = be_zero_duration();
+        matcher.test(&Duration::from_nanos(1)).passed.should_be_true();
+    }
+}
@@ -0,0 +1,149 @@
+use std::fmt::Debug;
+use std::time::{Duration, Instant, SystemTime};
+
+pub mod duration;
+pub mod moment;
+
+/// Moment represents a point in time, implemented for [`SystemTime`] and [`Instant`].
+pub trait Moment: Copy + Ord + Debug {
+    /// Returns the current point in time.
+    fn now() -> Self;
+
+    /// Returns the absolute distance between self and the other point in time.
+    fn distance(&self, other: &Self) -> Duration;
+}
+
+impl Moment for SystemTime {
+    fn now() -> Self {
+        SystemTime::now()
+    }
+
+    fn distance(&self, other: &Self) -> Duration {
+        self.duration_since(*other)
+            .unwrap_or_else(|error| error.duration())
+    }
+}
+
+impl Moment for Instant {
+    fn now() -> Self {
+        Instant::now()
+    }
+
+    fn distance(&self, other: &Self) -> Duration {
+        if self >= other {
+            *self - *other
+        } else {
+            *other - *self
+        }
+    }
+}
+
+/// Clock provides the current point in time to the assertions that are relative to "now".
+///
+/// It allows the tests to inject a fixed clock for determinism.
+pub trait Clock<T: Moment> {
+    fn now(&self) -> T;
+}
+
+/// SystemClock is a [`Clock`] that returns the current point in time of the system.
+pub struct SystemClock;
+
+/// FixedClock is a [`Clock`] that always returns the point in time it was created with.
+///
+/// # Example
+///```
+/// use std::time::{Duration, SystemTime};
+/// use clearcheck::matchers::time::{Clock, FixedClock};
+///
+/// let now = SystemTime::UNIX_EPOCH + Duration::from_secs(100);
+/// let clock = FixedClock::new(now);
+///
+/// assert_eq!(now, clock.now());
+/// ```
+pub struct FixedClock<T: Moment> {
+    now: T,
+}
+
+impl<T: Moment> FixedClock<T> {
+    /// Creates a new instance of FixedClock which always returns now.
+    pub fn new(now: T) -> Self {
+        FixedClock { now }
+    }
+}
+
+impl<T: Moment> Clock<T> for SystemClock {
+    fn now(&self) -> T {
+        T::now()
+    }
+}
+
+impl<T: Moment> Clock<T> for FixedClock<T> {
+    fn now(&self) -> T {
+        self.now
+    }
+}
+
+/// Formats the duration in a human-readable form, like `1h 2m 3s 500ms`.
+pub(crate) fn humanize(duration: &Duration) -> String {
+    if duration.is_zero() {
+        return "0s".to_string();
+    }
+    let seconds = duration.as_secs();
+    let nanos = duration.subsec_nanos();
+    let units = [
+        (seconds / 3600, "h"),
+        ((seconds % 3600) / 60, "m"),
+        (seconds % 60, "s"),
+        ((nanos / 1_000_000) as u64, "ms"),
+        (((nanos / 1_000) % 1_000) as u64, "µs"),
+        ((nanos % 1_000) as u64, "ns"),
+    ];
+    units
+        .iter()
+        .filter(|(value, _)| *value > 0)
+        .map(|(value, unit)| format!("{}{}", value, unit))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant, SystemTime};
+
+    use crate::assertions::equal::EqualityAssertion;
+    use crate::matchers::time::{humanize, Moment};
+
+    #[test]
+    fn should_humanize_zero_duration() {
+        humanize(&Duration::ZERO).should_equal("0s");
+    }
+
+    #[test]
+    fn should_humanize_duration() {
+        let duration = Duration::from_secs(3723) + Duration::from_millis(500);
+        humanize(&duration).should_equal("1h 2m 3s 500ms");
+    }
+
+    #[test]
+    fn should_humanize_sub_millisecond_duration() {
+        humanize(&Duration::from_nanos(15_020)).should_equal("15µs 20ns");
+    }
+
+    #[test]
+    fn should_find_distance_between_system_times() {
+        let earlier = SystemTime::UNIX_EPOCH;
+        let later = earlier + Duration::from_secs(5);
+
+        earlier.distance(&later).should_equal(&Duration::from_secs(5));
+        later.distance(&earlier).should_equal(&Duration::from_secs(5));
+    }
+
+    #[test]
+    fn should_find_distance_between_instants() {
+        let earlier = Instant::now();
+        let later = earlier + Duration::from_secs(5);
+
+        earlier.distance(&later).should_equal(&Duration::from_secs(5));
+        later.distance(&earlier).should_equal(&Duration::from_secs(5));
+    }
+}
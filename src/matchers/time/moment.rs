@@ -0,0 +1,239 @@
+use std::time::Duration;
+
+use crate::matchers::{Matcher, MatcherResult};
+use crate::matchers::time::{humanize, Moment};
+
+/// MomentMatcher offers a flexible way to make assertions about points in time, [`std::time::SystemTime`] and [`std::time::Instant`].
+///
+/// The failure messages carry the distance between the points in time in a human-readable form.
+///
+/// # Example
+///```
+/// use std::time::{Duration, SystemTime};
+/// use clearcheck::matchers::Matcher;
+/// use clearcheck::matchers::time::moment::be_before;
+///
+/// let now = SystemTime::now();
+/// let matcher = be_before(now + Duration::from_secs(5));
+///
+/// assert!(matcher.test(&now).passed());
+/// ```
+pub enum MomentMatcher<T: Moment> {
+    Before(T),
+    After(T),
+    Within(Duration, T),
+    InThePast(T),
+    InTheFuture(T),
+}
+
+impl<T: Moment> Matcher<T> for MomentMatcher<T> {
+    fn test(&self, value: &T) -> MatcherResult {
+        match self {
+            MomentMatcher::Before(other) => MatcherResult::formatted(
+                value < other,
+                format!(
+                    "{:?} should be before {:?} but was {}",
+                    value,
+                    other,
+                    relative_position(value, other)
+                ),
+                format!(
+                    "{:?} should not be before {:?} but was {}",
+                    value,
+                    other,
+                    relative_position(value, other)
+                ),
+            ),
+            MomentMatcher::After(other) => MatcherResult::formatted(
+                value > other,
+                format!(
+                    "{:?} should be after {:?} but was {}",
+                    value,
+                    other,
+                    relative_position(value, other)
+                ),
+                format!(
+                    "{:?} should not be after {:?} but was {}",
+                    value,
+                    other,
+                    relative_position(value, other)
+                ),
+            ),
+            MomentMatcher::Within(duration, other) => MatcherResult::formatted(
+                value.distance(other) <= *duration,
+                format!(
+                    "{:?} should be within {} of {:?} but was {}",
+                    value,
+                    humanize(duration),
+                    other,
+                    relative_position(value, other)
+                ),
+                format!(
+                    "{:?} should not be within {} of {:?} but was {}",
+                    value,
+                    humanize(duration),
+                    other,
+                    relative_position(value, other)
+                ),
+            ),
+            MomentMatcher::InThePast(now) => MatcherResult::formatted(
+                value < now,
+                format!(
+                    "{:?} should be in the past but was {} now",
+                    value,
+                    relative_position(value, now)
+                ),
+                format!(
+                    "{:?} should not be in the past but was {} now",
+                    value,
+                    relative_position(value, now)
+                ),
+            ),
+            MomentMatcher::InTheFuture(now) => MatcherResult::formatted(
+                value > now,
+                format!(
+                    "{:?} should be in the future but was {} now",
+                    value,
+                    relative_position(value, now)
+                ),
+                format!(
+                    "{:?} should not be in the future but was {} now",
+                    value,
+                    relative_position(value, now)
+                ),
+            ),
+        }
+    }
+}
+
+fn relative_position<T: Moment>(value: &T, other: &T) -> String {
+    let distance = humanize(&value.distance(other));
+    if value < other {
+        format!("{} before", distance)
+    } else if value > other {
+        format!("{} after", distance)
+    } else {
+        "the same as".to_string()
+    }
+}
+
+/// Creates a MomentMatcher that asserts whether a point in time is before the other.
+pub fn be_before<T: Moment>(other: T) -> MomentMatcher<T> {
+    MomentMatcher::Before(other)
+}
+
+/// Creates a MomentMatcher that asserts whether a point in time is after the other.
+pub fn be_after<T: Moment>(other: T) -> MomentMatcher<T> {
+    MomentMatcher::After(other)
+}
+
+/// Creates a MomentMatcher that asserts whether a point in time is within the given duration (on either side) of the other.
+pub fn be_within<T: Moment>(duration: Duration, other: T) -> MomentMatcher<T> {
+    MomentMatcher::Within(duration, other)
+}
+
+/// Creates a MomentMatcher that asserts whether a point in time is before now.
+pub fn be_in_the_past<T: Moment>(now: T) -> MomentMatcher<T> {
+    MomentMatcher::InThePast(now)
+}
+
+/// Creates a MomentMatcher that asserts whether a point in time is after now.
+pub fn be_in_the_future<T: Moment>(now: T) -> MomentMatcher<T> {
+    MomentMatcher::InTheFuture(now)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant, SystemTime};
+
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::string::membership::MembershipAssertion;
+    use crate::matchers::Matcher;
+    use crate::matchers::time::moment::{
+        be_after, be_before, be_in_the_future, be_in_the_past, be_within,
+    };
+
+    #[test]
+    fn should_be_before() {
+        let now = SystemTime::UNIX_EPOCH;
+        let matcher = be_before(now + Duration::from_secs(1));
+        matcher.test(&now).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_before_but_was_not() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1);
+        let matcher = be_before(SystemTime::UNIX_EPOCH);
+        matcher.test(&now).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_report_distance_in_before() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(90);
+        let matcher = be_before(SystemTime::UNIX_EPOCH);
+        let result = matcher.test(&now);
+
+        result.failure_message.should_contain("but was 1m 30s after");
+    }
+
+    #[test]
+    fn should_be_after() {
+        let now = Instant::now();
+        let matcher = be_after(now);
+        matcher.test(&(now + Duration::from_millis(1))).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_after_but_was_not() {
+        let now = Instant::now();
+        let matcher = be_after(now);
+        matcher.test(&now).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_within() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(10);
+        let matcher = be_within(Duration::from_secs(2), SystemTime::UNIX_EPOCH + Duration::from_secs(12));
+        matcher.test(&now).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_within_but_was_not() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(10);
+        let matcher = be_within(Duration::from_secs(2), SystemTime::UNIX_EPOCH + Duration::from_secs(13));
+        matcher.test(&now).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_in_the_past() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(10);
+        let matcher = be_in_the_past(now);
+        matcher.test(&SystemTime::UNIX_EPOCH).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_in_the_past_but_was_not() {
+        let now = SystemTime::UNIX_EPOCH;
+        let matcher = be_in_the_past(now);
+        matcher.test(&(now + Duration::from_secs(1))).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_be_in_the_future() {
+        let now = SystemTime::UNIX_EPOCH;
+        let matcher = be_in_the_future(now);
+        matcher.test(&(now + Duration::from_secs(1))).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_be_in_the_future_but_was_not() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(10);
+        let matcher = be_in_the_future(now);
+        matcher.test(&SystemTime::UNIX_EPOCH).passed.should_be_true();
+    }
+}
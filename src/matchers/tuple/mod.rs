@@ -0,0 +1,279 @@
+use crate::matchers::{Matcher, MatcherResult};
+
+/// FirstElementMatcher offers a flexible way to assert that the first element of a tuple matches the given matcher.
+///
+/// clearcheck implements FirstElementMatcher for tuples of arity 1 to 8.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::Matcher;
+/// use clearcheck::matchers::string::boundary::begin_with;
+/// use clearcheck::matchers::tuple::have_first;
+///
+/// let matcher = have_first(begin_with("clear"));
+/// assert!(matcher.test(&("clearcheck", 10)).passed());
+/// ```
+pub struct FirstElementMatcher<M> {
+    matcher: M,
+}
+
+/// SecondElementMatcher offers a flexible way to assert that the second element of a tuple matches the given matcher.
+///
+/// clearcheck implements SecondElementMatcher for tuples of arity 2 to 8.
+///
+/// # Example
+///```
+/// use clearcheck::matchers::Matcher;
+/// use clearcheck::matchers::ordered::be_greater_than;
+/// use clearcheck::matchers::tuple::have_second;
+///
+/// let matcher = have_second(be_greater_than(5));
+/// assert!(matcher.test(&("clearcheck", 10)).passed());
+/// ```
+pub struct SecondElementMatcher<M> {
+    matcher: M,
+}
+
+// A tuple of matchers (up to arity 8) is a matcher of the tuple of the corresponding values,
+// where each matcher runs against the value at the same position.
+macro_rules! tuple_matcher {
+    ($($matcher:ident $value:ident $position:tt),+) => {
+        impl<$($matcher, $value),+> Matcher<($($value,)+)> for ($($matcher,)+)
+            where
+                $($matcher: Matcher<$value>),+
+        {
+            fn test(&self, value: &($($value,)+)) -> MatcherResult {
+                combine(vec![$(($position, self.$position.test(&value.$position))),+])
+            }
+        }
+    };
+}
+
+macro_rules! first_element_matcher {
+    ($first:ident $(, $rest:ident)*) => {
+        impl<M, $first $(, $rest)*> Matcher<($first, $($rest,)*)> for FirstElementMatcher<M>
+            where
+                M: Matcher<$first>
+        {
+            fn test(&self, value: &($first, $($rest,)*)) -> MatcherResult {
+                element_result(0, self.matcher.test(&value.0))
+            }
+        }
+    };
+}
+
+macro_rules! second_element_matcher {
+    ($first:ident, $second:ident $(, $rest:ident)*) => {
+        impl<M, $first, $second $(, $rest)*> Matcher<($first, $second, $($rest,)*)> for SecondElementMatcher<M>
+            where
+                M: Matcher<$second>
+        {
+            fn test(&self, value: &($first, $second, $($rest,)*)) -> MatcherResult {
+                element_result(1, self.matcher.test(&value.1))
+            }
+        }
+    };
+}
+
+tuple_matcher!(M0 T0 0);
+tuple_matcher!(M0 T0 0, M1 T1 1);
+tuple_matcher!(M0 T0 0, M1 T1 1, M2 T2 2);
+tuple_matcher!(M0 T0 0, M1 T1 1, M2 T2 2, M3 T3 3);
+tuple_matcher!(M0 T0 0, M1 T1 1, M2 T2 2, M3 T3 3, M4 T4 4);
+tuple_matcher!(M0 T0 0, M1 T1 1, M2 T2 2, M3 T3 3, M4 T4 4, M5 T5 5);
+tuple_matcher!(M0 T0 0, M1 T1 1, M2 T2 2, M3 T3 3, M4 T4 4, M5 T5 5, M6 T6 6);
+tuple_matcher!(M0 T0 0, M1 T1 1, M2 T2 2, M3 T3 3, M4 T4 4, M5 T5 5, M6 T6 6, M7 T7 7);
+
+first_element_matcher!(T0);
+first_element_matcher!(T0, T1);
+first_element_matcher!(T0, T1, T2);
+first_element_matcher!(T0, T1, T2, T3);
+first_element_matcher!(T0, T1, T2, T3, T4);
+first_element_matcher!(T0, T1, T2, T3, T4, T5);
+first_element_matcher!(T0, T1, T2, T3, T4, T5, T6);
+first_element_matcher!(T0, T1, T2, T3, T4, T5, T6, T7);
+
+second_element_matcher!(T0, T1);
+second_element_matcher!(T0, T1, T2);
+second_element_matcher!(T0, T1, T2, T3);
+second_element_matcher!(T0, T1, T2, T3, T4);
+second_element_matcher!(T0, T1, T2, T3, T4, T5);
+second_element_matcher!(T0, T1, T2, T3, T4, T5, T6);
+second_element_matcher!(T0, T1, T2, T3, T4, T5, T6, T7);
+
+fn combine(results: Vec<(usize, MatcherResult)>) -> MatcherResult {
+    let errored_positions = results
+        .iter()
+        .filter(|(_, result)| result.errored)
+        .map(|(position, result)| format!("position {}: {}", position, result.failure_message))
+        .collect::<Vec<_>>();
+    if !errored_positions.is_empty() {
+        return MatcherResult::errored(format!(
+            "Tuple should match at all positions but failed at {}",
+            errored_positions.join(", ")
+        ));
+    }
+
+    let failed_positions = results
+        .iter()
+        .filter(|(_, result)| !result.passed)
+        .map(|(position, result)| format!("position {}: {}", position, result.failure_message))
+        .collect::<Vec<_>>();
+
+    let matched_positions = results
+        .iter()
+        .map(|(position, result)| {
+            format!("position {}: {}", position, result.inverted_failure_message)
+        })
+        .collect::<Vec<_>>();
+
+    MatcherResult::formatted(
+        failed_positions.is_empty(),
+        format!(
+            "Tuple should match at all positions but failed at {}",
+            failed_positions.join(", ")
+        ),
+        format!(
+            "Tuple should not match at all positions but matched at {}",
+            matched_positions.join(", ")
+        ),
+    )
+}
+
+fn element_result(position: usize, result: MatcherResult) -> MatcherResult {
+    if result.errored {
+        return MatcherResult::errored(format!(
+            "Tuple element at position {} could not be matched: {}",
+            position, result.failure_message
+        ));
+    }
+    MatcherResult::formatted(
+        result.passed,
+        format!("Tuple element at position {} did not match: {}", position, result.failure_message),
+        format!("Tuple element at position {} unexpectedly matched: {}", position, result.inverted_failure_message),
+    )
+}
+
+/// Creates a FirstElementMatcher that asserts whether the first element of a tuple matches the given matcher.
+pub fn have_first<M>(matcher: M) -> FirstElementMatcher<M> {
+    FirstElementMatcher { matcher }
+}
+
+/// Creates a SecondElementMatcher that asserts whether the second element of a tuple matches the given matcher.
+pub fn have_second<M>(matcher: M) -> SecondElementMatcher<M> {
+    SecondElementMatcher { matcher }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assertions::bool::TrueFalseAssertion;
+    use crate::assertions::string::boundary::BoundaryAssertion;
+    use crate::matchers::equal::be_equal;
+    use crate::matchers::{Matcher, MatcherResult};
+    use crate::matchers::ordered::{be_greater_than, be_less_than};
+    use crate::matchers::string::boundary::begin_with;
+    use crate::matchers::tuple::{have_first, have_second};
+
+    struct UnreadableMatcher;
+
+    impl<T: ?Sized> Matcher<T> for UnreadableMatcher {
+        fn test(&self, _: &T) -> MatcherResult {
+            MatcherResult::errored(String::from("value should be readable"))
+        }
+    }
+
+    #[test]
+    fn should_match_pair() {
+        let matcher = (be_greater_than(0), begin_with("clear"));
+        matcher.test(&(10, "clearcheck")).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_match_pair_but_did_not() {
+        let matcher = (be_greater_than(0), begin_with("clear"));
+        matcher.test(&(10, "junit")).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_match_single_element_tuple() {
+        let matcher = (be_equal(10),);
+        matcher.test(&(10,)).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_match_tuple_of_arity_8() {
+        let matcher = (
+            be_equal(1), be_equal(2), be_equal(3), be_equal(4),
+            be_equal(5), be_equal(6), be_equal(7), be_less_than(10),
+        );
+        matcher.test(&(1, 2, 3, 4, 5, 6, 7, 8)).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_report_failed_position() {
+        let matcher = (be_greater_than(0), begin_with("clear"), be_less_than(5));
+        let result = matcher.test(&(10, "junit", 1));
+
+        result.passed.should_be_false();
+        result.failure_message.contains("position 1").should_be_true();
+        result.failure_message.contains("position 0").should_be_false();
+        result.failure_message.contains("position 2").should_be_false();
+    }
+
+    #[test]
+    fn should_propagate_errored_element_result() {
+        let matcher = (be_greater_than(0), UnreadableMatcher);
+        let result = matcher.test(&(10, "clearcheck"));
+
+        result.passed.should_be_false();
+        result.errored.should_be_true();
+        result.failure_message.contains("position 1: value should be readable").should_be_true();
+    }
+
+    #[test]
+    fn should_propagate_errored_first_element_result() {
+        let matcher = have_first(UnreadableMatcher);
+        let result = matcher.test(&("clearcheck", 10));
+
+        result.passed.should_be_false();
+        result.errored.should_be_true();
+    }
+
+    #[test]
+    fn should_have_first() {
+        let matcher = have_first(begin_with("clear"));
+        matcher.test(&("clearcheck", 10)).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_first_but_did_not() {
+        let matcher = have_first(begin_with("clear"));
+        matcher.test(&("junit", 10, 20)).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_have_second() {
+        let matcher = have_second(be_greater_than(5));
+        matcher.test(&("clearcheck", 10)).passed.should_be_true();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_have_second_but_did_not() {
+        let matcher = have_second(be_greater_than(50));
+        matcher.test(&("clearcheck", 10, "junit")).passed.should_be_true();
+    }
+
+    #[test]
+    fn should_report_element_position_in_messages() {
+        let matcher = have_first(begin_with("clear"));
+
+        let result = matcher.test(&("junit", 10));
+        result.failure_message.should_begin_with("Tuple element at position 0 did not match: ");
+
+        let result = matcher.test(&("clearcheck", 10));
+        result.inverted_failure_message.should_begin_with("Tuple element at position 0 unexpectedly matched: ");
+    }
+}